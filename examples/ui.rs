@@ -71,7 +71,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                         .column()
                         .justify_content_center()
                         .align_items_center()
-                        .size(Size::new(Val::Px(200.0), Val::Percent(100.0)))
+                        .size((Val::Px(200.0), Val::Percent(100.0)))
                         .background_color(Color::rgb(0.15, 0.15, 0.15)),
                 )
                 .with_children(|parent| {
@@ -85,14 +85,14 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 color: Color::WHITE,
                             },
                         )
-                        .with_style(style().size(Size::new(Val::Undefined, Val::Px(25.)))),
+                        .with_style(style().size((Val::Undefined, Val::Px(25.)))),
                     );
                     // List with hidden overflow
                     parent
                         .spawn(
                             node()
                                 .column()
-                                .size(Size::new(Val::Percent(100.0), Val::Percent(50.0)))
+                                .size((Val::Percent(100.0), Val::Percent(50.0)))
                                 .hide_overflow()
                                 .background_color(Color::rgb(0.10, 0.10, 0.10)),
                         )
@@ -127,7 +127,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
             parent
                 .spawn(
                     node()
-                        .size(Size::new(Val::Px(200.0), Val::Px(200.0)))
+                        .size((Val::Px(200.0), Val::Px(200.0)))
                         .absolute()
                         .left(Val::Px(210.0))
                         .bottom(Val::Px(10.0))
@@ -137,7 +137,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 .with_children(|parent| {
                     parent.spawn(
                         node()
-                            .size(Size::new(Val::Percent(100.0), Val::Percent(100.0)))
+                            .size((Val::Percent(100.0), Val::Percent(100.0)))
                             .background_color(Color::rgb(0.8, 0.8, 1.0)),
                     );
                 });
@@ -197,7 +197,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     parent
                         .spawn(
                             node()
-                                .size(Size::new(Val::Percent(100.0), Val::Percent(100.0)))
+                                .size((Val::Percent(100.0), Val::Percent(100.0)))
                                 .absolute()
                                 .justify_content_center()
                                 .align_items_start(),
@@ -211,7 +211,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         .into(),
                                     ..default()
                                 }
-                                .size(Size::new(Val::Px(500.0), Val::Auto)),
+                                .size((Val::Px(500.0), Val::Auto)),
                             );
                         });
                 });
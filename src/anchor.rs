@@ -0,0 +1,178 @@
+use crate::fields::StyleFields;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// A point on a node's parent that an [`Anchored`] node is attached to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect, FromReflect)]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+    /// A point given as fractions of the parent's width and height, from its top left corner.
+    Custom(Vec2),
+}
+
+impl Anchor {
+    /// The anchor point as fractions of the parent's size, from its top left corner.
+    pub fn fraction(self) -> Vec2 {
+        match self {
+            Anchor::TopLeft => Vec2::new(0., 0.),
+            Anchor::TopCenter => Vec2::new(0.5, 0.),
+            Anchor::TopRight => Vec2::new(1., 0.),
+            Anchor::CenterLeft => Vec2::new(0., 0.5),
+            Anchor::Center => Vec2::new(0.5, 0.5),
+            Anchor::CenterRight => Vec2::new(1., 0.5),
+            Anchor::BottomLeft => Vec2::new(0., 1.),
+            Anchor::BottomCenter => Vec2::new(0.5, 1.),
+            Anchor::BottomRight => Vec2::new(1., 1.),
+            Anchor::Custom(fraction) => fraction,
+        }
+    }
+}
+
+/// Positions a node absolutely by attaching its pivot to an anchor point of its parent.
+///
+/// The position is recomputed from the sizes of the node and its parent after each layout,
+/// so a change in either size is applied on the following frame.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Anchored {
+    pub anchor: Anchor,
+    /// The point of the node placed on the anchor, as fractions of the node's size.
+    pub pivot: Vec2,
+    /// An offset in pixels from the anchor, with y increasing downwards.
+    pub offset: Vec2,
+}
+
+impl Anchored {
+    /// Attach the node to `anchor` by the same point of the node, so that it stays inside its parent.
+    pub fn new(anchor: Anchor) -> Self {
+        Self {
+            anchor,
+            pivot: anchor.fraction(),
+            offset: Vec2::ZERO,
+        }
+    }
+
+    /// The position of the node's top left corner within its parent.
+    pub fn position(&self, parent_size: Vec2, size: Vec2) -> Vec2 {
+        self.anchor.fraction() * parent_size - self.pivot * size + self.offset
+    }
+}
+
+pub trait AnchorExt: Bundle + Sized {
+    /// Position the node at `anchor` of its parent.
+    fn anchor(self, anchor: Anchor) -> (Self, Anchored) {
+        (self, Anchored::new(anchor))
+    }
+}
+
+impl<T: Bundle> AnchorExt for T {}
+
+pub trait AnchoredExt {
+    /// Set the point of the node placed on its anchor, as fractions of the node's size.
+    fn pivot(self, x: f32, y: f32) -> Self;
+
+    /// Move the node by `x` and `y` pixels from its anchor.
+    fn offset(self, x: f32, y: f32) -> Self;
+}
+
+impl<T: Bundle> AnchoredExt for (T, Anchored) {
+    fn pivot(mut self, x: f32, y: f32) -> Self {
+        self.1.pivot = Vec2::new(x, y);
+        self
+    }
+
+    fn offset(mut self, x: f32, y: f32) -> Self {
+        self.1.offset = Vec2::new(x, y);
+        self
+    }
+}
+
+/// Sets the absolute position of each [`Anchored`] node from its size and its parent's size.
+pub fn anchor_system(
+    mut anchored: Query<(&Anchored, &Node, &Parent, &mut Style)>,
+    parents: Query<&Node>,
+) {
+    for (anchored, node, parent, mut style) in anchored.iter_mut() {
+        let Ok(parent_node) = parents.get(parent.get()) else {
+            continue;
+        };
+        let position = anchored.position(parent_node.size(), node.size());
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if style.position_type != PositionType::Absolute
+            || style.left_val() != left
+            || style.top_val() != top
+        {
+            style.position_type = PositionType::Absolute;
+            *style.left_mut() = left;
+            *style.top_mut() = top;
+            *style.right_mut() = Val::Undefined;
+            *style.bottom_mut() = Val::Undefined;
+        }
+    }
+}
+
+/// Adds the system that positions [`Anchored`] nodes.
+pub struct StyleAnchorPlugin;
+
+impl Plugin for StyleAnchorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(CoreStage::PostUpdate, anchor_system.before(UiSystem::Flex));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pivot_defaults_to_anchor() {
+        let anchored = Anchored::new(Anchor::BottomRight);
+        assert_eq!(
+            anchored.position(Vec2::new(200., 100.), Vec2::new(20., 10.)),
+            Vec2::new(180., 90.)
+        );
+    }
+
+    #[test]
+    fn pivot_and_offset() {
+        let (_, anchored) = NodeBundle::default()
+            .anchor(Anchor::TopRight)
+            .pivot(0.5, 0.5)
+            .offset(-4., 8.);
+        assert_eq!(
+            anchored.position(Vec2::new(200., 100.), Vec2::new(20., 10.)),
+            Vec2::new(186., 3.)
+        );
+        let center = Anchored::new(Anchor::Custom(Vec2::new(0.25, 0.5)));
+        assert_eq!(
+            center.position(Vec2::new(200., 100.), Vec2::new(20., 10.)),
+            Vec2::new(45., 45.)
+        );
+    }
+
+    #[test]
+    fn system_positions_absolutely() {
+        let mut app = App::new();
+        app.add_system(anchor_system);
+        let parent = app.world.spawn(NodeBundle::default()).id();
+        let child = app
+            .world
+            .spawn(NodeBundle::default().anchor(Anchor::Center).offset(5., 6.))
+            .id();
+        app.world.entity_mut(parent).push_children(&[child]);
+        app.update();
+        let style = app.world.get::<Style>(child).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.position.left, Val::Px(5.));
+        assert_eq!(style.position.top, Val::Px(6.));
+    }
+}
@@ -0,0 +1,719 @@
+use crate::easing::Ease;
+use crate::fields::StyleFields;
+use crate::fields::ValField;
+use crate::lerp::lerp_styles;
+use crate::restyle::StylePatch;
+use crate::spring::spring_position_system;
+use crate::spring::Spring;
+use crate::transition::style_transition_system;
+use crate::transition::TransitionTiming;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use std::time::Duration;
+
+/// How a [`StyleTimeline`] behaves once it reaches its last keyframe.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimelineRepeat {
+    /// Stop at the last keyframe and send a [`TimelineFinished`] event.
+    #[default]
+    Once,
+    /// Jump back to the start.
+    Loop,
+    /// Play backwards to the start, then forwards again.
+    PingPong,
+}
+
+/// A point on a [`StyleTimeline`].
+#[derive(Clone, Debug)]
+pub struct Keyframe {
+    /// Time from the start of the timeline in seconds.
+    pub time: f32,
+    /// Applied to the node's base style to produce the style at this keyframe.
+    pub patch: StylePatch,
+    /// Easing used for the segment ending at this keyframe.
+    pub ease: Ease,
+}
+
+/// Animates a node's [`Style`] through a list of keyframes.
+///
+/// Each keyframe's patch is applied to the node's style as it was when the timeline
+/// started, and the styles in between are interpolated with [`lerp_styles`].
+/// If the first keyframe is after time zero, the timeline starts from the unpatched style.
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleTimeline {
+    keyframes: Vec<Keyframe>,
+    pub repeat: TimelineRepeat,
+    pub elapsed: f32,
+    base: Option<Style>,
+    finished: bool,
+}
+
+impl StyleTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a keyframe reached with linear interpolation.
+    pub fn keyframe(self, time: f32, patch: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        self.keyframe_eased(time, Ease::Linear, patch)
+    }
+
+    /// Add a keyframe reached using the easing function `ease`.
+    pub fn keyframe_eased(
+        mut self,
+        time: f32,
+        ease: Ease,
+        patch: impl Fn(&mut Style) + Send + Sync + 'static,
+    ) -> Self {
+        let keyframe = Keyframe {
+            time,
+            patch: StylePatch::new(patch),
+            ease,
+        };
+        let index = self.keyframes.partition_point(|other| other.time <= time);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    pub fn repeat(mut self, repeat: TimelineRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// The time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0., |keyframe| keyframe.time)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Restart the timeline from the beginning.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.;
+        self.finished = false;
+    }
+
+    /// The time within a single play-through that `elapsed` maps to, after repeating.
+    pub fn local_time(&self, elapsed: f32) -> f32 {
+        let duration = self.duration();
+        if duration <= 0. {
+            return 0.;
+        }
+        match self.repeat {
+            TimelineRepeat::Once => elapsed.clamp(0., duration),
+            TimelineRepeat::Loop => elapsed.rem_euclid(duration),
+            TimelineRepeat::PingPong => {
+                let t = elapsed.rem_euclid(2. * duration);
+                if t <= duration {
+                    t
+                } else {
+                    2. * duration - t
+                }
+            }
+        }
+    }
+
+    /// The style at `time` seconds into a single play-through.
+    pub fn sample(&self, base: &Style, time: f32, context_size: Vec2) -> Style {
+        let patched = |keyframe: &Keyframe| {
+            let mut style = base.clone();
+            keyframe.patch.apply(&mut style);
+            style
+        };
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        if next == self.keyframes.len() {
+            return self.keyframes.last().map_or_else(|| base.clone(), patched);
+        }
+        let end = &self.keyframes[next];
+        let (start_time, start) = match next.checked_sub(1) {
+            Some(previous) => {
+                let keyframe = &self.keyframes[previous];
+                (keyframe.time, patched(keyframe))
+            }
+            None => (0., base.clone()),
+        };
+        let span = end.time - start_time;
+        let t = if span <= 0. {
+            1.
+        } else {
+            (time - start_time) / span
+        };
+        lerp_styles(&start, &patched(end), end.ease.sample(t), context_size)
+    }
+}
+
+/// Sent when a [`StyleTimeline`] with [`TimelineRepeat::Once`] reaches its end.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimelineFinished {
+    pub entity: Entity,
+}
+
+/// The size that percentage values of a node are evaluated against:
+/// its parent's size, or the primary window's size for root nodes.
+pub(crate) fn context_size(
+    parent: Option<&Parent>,
+    nodes: &Query<&Node>,
+    windows: &Option<Res<Windows>>,
+) -> Vec2 {
+    match parent {
+        Some(parent) => nodes
+            .get(parent.get())
+            .map_or(Vec2::ZERO, |node| node.size()),
+        None => windows
+            .as_ref()
+            .and_then(|windows| windows.get_primary())
+            .map_or(Vec2::ZERO, |window| {
+                Vec2::new(window.width(), window.height())
+            }),
+    }
+}
+
+/// Advances every [`StyleTimeline`] and writes the sampled style.
+pub fn style_timeline_system(
+    time: Res<Time>,
+    windows: Option<Res<Windows>>,
+    mut finished_events: EventWriter<TimelineFinished>,
+    mut timelines: Query<(Entity, &mut StyleTimeline, &mut Style, Option<&Parent>)>,
+    nodes: Query<&Node>,
+) {
+    for (entity, mut timeline, mut style, parent) in timelines.iter_mut() {
+        if timeline.finished {
+            continue;
+        }
+        let base = timeline.base.get_or_insert_with(|| style.clone()).clone();
+        timeline.elapsed += time.delta_seconds();
+        let local_time = timeline.local_time(timeline.elapsed);
+        let context_size = context_size(parent, &nodes, &windows);
+        let sampled = timeline.sample(&base, local_time, context_size);
+        if *style != sampled {
+            *style = sampled;
+        }
+        if timeline.repeat == TimelineRepeat::Once && timeline.duration() <= timeline.elapsed {
+            timeline.finished = true;
+            finished_events.send(TimelineFinished { entity });
+        }
+    }
+}
+
+/// An enter or exit animation for a UI node.
+///
+/// When played as an exit animation the motion is reversed, so
+/// `SlideFromLeft` slides the node out towards the left.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Anim {
+    /// Fade the alpha of the node's [`BackgroundColor`].
+    Fade,
+    /// Slide in from the given distance in pixels to the left.
+    SlideFromLeft(f32),
+    /// Slide in from the given distance in pixels to the right.
+    SlideFromRight(f32),
+    /// Slide in from the given distance in pixels above.
+    SlideFromTop(f32),
+    /// Slide in from the given distance in pixels below.
+    SlideFromBottom(f32),
+}
+
+/// Offset a node's position by `delta` pixels, evaluating percentages against `size`.
+/// Uses the `first` edge unless only the `second` edge is defined.
+fn shift(style: &mut Style, first: ValField, second: ValField, delta: f32, size: f32) {
+    let defined = |val: Val| matches!(val, Val::Px(_) | Val::Percent(_));
+    if !defined(*first(style)) && defined(*second(style)) {
+        let second = second(style);
+        *second = Val::Px(second.evaluate(size).unwrap() - delta);
+    } else {
+        let first = first(style);
+        *first = Val::Px(first.evaluate(size).unwrap_or(0.) + delta);
+    }
+}
+
+impl Anim {
+    /// Apply the animation to a node at `visibility`, where `0` is fully hidden and
+    /// `1` is the node's original style and color.
+    pub fn apply(
+        &self,
+        visibility: f32,
+        original_style: &Style,
+        original_color: Option<BackgroundColor>,
+        style: &mut Style,
+        color: Option<&mut BackgroundColor>,
+        context_size: Vec2,
+    ) {
+        *style = original_style.clone();
+        if let (Some(color), Some(original_color)) = (color, original_color) {
+            *color = original_color;
+            if *self == Anim::Fade {
+                let alpha = original_color.0.a();
+                color.0.set_a(alpha * visibility);
+            }
+        }
+        if 1. <= visibility {
+            return;
+        }
+        let hidden = 1. - visibility;
+        match *self {
+            Anim::Fade => {}
+            Anim::SlideFromLeft(distance) => shift(
+                style,
+                Style::left_mut,
+                Style::right_mut,
+                -distance * hidden,
+                context_size.x,
+            ),
+            Anim::SlideFromRight(distance) => shift(
+                style,
+                Style::left_mut,
+                Style::right_mut,
+                distance * hidden,
+                context_size.x,
+            ),
+            Anim::SlideFromTop(distance) => shift(
+                style,
+                Style::top_mut,
+                Style::bottom_mut,
+                -distance * hidden,
+                context_size.y,
+            ),
+            Anim::SlideFromBottom(distance) => shift(
+                style,
+                Style::top_mut,
+                Style::bottom_mut,
+                distance * hidden,
+                context_size.y,
+            ),
+        }
+    }
+}
+
+/// Plays an [`Anim`] on a node, then removes itself.
+///
+/// Exit animations hide the node with `Display::None` when they finish,
+/// or despawn it and its descendants if [`UiAnimation::despawning`] was set.
+#[derive(Component, Clone, Debug)]
+pub struct UiAnimation {
+    pub anim: Anim,
+    pub duration: Duration,
+    pub ease: Ease,
+    pub elapsed: Duration,
+    exit: bool,
+    despawn: bool,
+    original: Option<(Style, Option<BackgroundColor>)>,
+}
+
+impl UiAnimation {
+    pub fn enter(anim: Anim, duration: Duration) -> Self {
+        Self {
+            anim,
+            duration,
+            ease: Ease::Linear,
+            elapsed: Duration::ZERO,
+            exit: false,
+            despawn: false,
+            original: None,
+        }
+    }
+
+    pub fn exit(anim: Anim, duration: Duration) -> Self {
+        Self {
+            exit: true,
+            ..Self::enter(anim, duration)
+        }
+    }
+
+    /// Despawn the node once the animation finishes.
+    pub fn despawning(mut self) -> Self {
+        self.despawn = true;
+        self
+    }
+
+    pub fn with_ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.duration <= self.elapsed
+    }
+
+    /// How visible the node is, from `0` (hidden) to `1` (its original style).
+    pub fn visibility(&self) -> f32 {
+        let t = if self.duration.is_zero() {
+            1.
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+        };
+        let eased = self.ease.sample(t);
+        if self.exit {
+            1. - eased
+        } else {
+            eased
+        }
+    }
+}
+
+pub trait AnimateInExt: Bundle + Sized {
+    /// Play `anim` when the node is spawned.
+    fn animate_in(self, anim: Anim, duration: Duration) -> (Self, UiAnimation) {
+        (self, UiAnimation::enter(anim, duration))
+    }
+}
+
+impl<T: Bundle> AnimateInExt for T {}
+
+pub trait UiAnimationCommandsExt {
+    /// Play `anim` as an exit animation, then despawn `entity` and its descendants.
+    fn despawn_animated(&mut self, entity: Entity, anim: Anim, duration: Duration);
+}
+
+impl<'w, 's> UiAnimationCommandsExt for Commands<'w, 's> {
+    fn despawn_animated(&mut self, entity: Entity, anim: Anim, duration: Duration) {
+        self.entity(entity)
+            .insert(UiAnimation::exit(anim, duration).despawning());
+    }
+}
+
+/// Advances every [`UiAnimation`] and finishes the completed ones.
+#[allow(clippy::type_complexity)]
+pub fn ui_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    windows: Option<Res<Windows>>,
+    mut animations: Query<(
+        Entity,
+        &mut UiAnimation,
+        &mut Style,
+        Option<&mut BackgroundColor>,
+        Option<&Parent>,
+    )>,
+    nodes: Query<&Node>,
+) {
+    for (entity, mut animation, mut style, mut color, parent) in animations.iter_mut() {
+        let (original_style, original_color) = animation
+            .original
+            .get_or_insert_with(|| (style.clone(), color.as_deref().copied()))
+            .clone();
+        animation.elapsed += time.delta();
+        let context_size = context_size(parent, &nodes, &windows);
+        animation.anim.apply(
+            animation.visibility(),
+            &original_style,
+            original_color,
+            &mut style,
+            color.as_deref_mut(),
+            context_size,
+        );
+        if animation.is_finished() {
+            if animation.despawn {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+            if animation.exit {
+                *style = original_style;
+                style.display = Display::None;
+                if let (Some(mut color), Some(original_color)) = (color, original_color) {
+                    *color = original_color;
+                }
+            }
+            commands.entity(entity).remove::<UiAnimation>();
+        }
+    }
+}
+
+/// Scales a node's [`Transform`] up while it is hovered or pressed.
+///
+/// Requires an [`Interaction`] component, for example from a `ButtonBundle`.
+/// Only the rendered node is scaled, the layout is unaffected.
+#[derive(Component, Clone, Debug)]
+pub struct HoverGrow {
+    /// The scale reached while hovered.
+    pub scale: f32,
+    /// The time taken to grow to `scale`, and to shrink back.
+    pub duration: Duration,
+    pub ease: Ease,
+    progress: f32,
+}
+
+impl HoverGrow {
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            duration: Duration::from_millis(100),
+            ease: Ease::Linear,
+            progress: 0.,
+        }
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Move towards the hovered or resting scale by `delta` seconds and return the current scale.
+    pub fn step(&mut self, hovered: bool, delta: f32) -> f32 {
+        let target = if hovered { 1. } else { 0. };
+        let step = if self.duration.is_zero() {
+            1.
+        } else {
+            delta / self.duration.as_secs_f32()
+        };
+        self.progress = if self.progress < target {
+            (self.progress + step).min(target)
+        } else {
+            (self.progress - step).max(target)
+        };
+        1. + (self.scale - 1.) * self.ease.sample(self.progress)
+    }
+}
+
+pub trait HoverGrowExt: Bundle + Sized {
+    /// Scale the node by `scale` while it is hovered.
+    fn hover_grow(self, scale: f32) -> (Self, HoverGrow) {
+        (self, HoverGrow::new(scale))
+    }
+}
+
+impl<T: Bundle> HoverGrowExt for T {}
+
+/// Updates the [`Transform`] scale of nodes with a [`HoverGrow`] component.
+pub fn hover_grow_system(
+    time: Res<Time>,
+    mut query: Query<(&Interaction, &mut HoverGrow, &mut Transform)>,
+) {
+    for (interaction, mut grow, mut transform) in query.iter_mut() {
+        let hovered = *interaction != Interaction::None;
+        let scale = grow.step(hovered, time.delta_seconds());
+        if transform.scale.x != scale {
+            transform.scale = Vec3::new(scale, scale, 1.);
+        }
+    }
+}
+
+/// Smooths out jumps in a node's position caused by layout changes.
+///
+/// When a reflow moves the node, it is offset back to where it was drawn and the
+/// offset is animated away. Only the rendered node moves, the layout is unaffected.
+#[derive(Component, Clone, Debug)]
+pub struct SmoothLayout {
+    pub timing: TransitionTiming,
+    previous: Option<Vec2>,
+    from_offset: Vec2,
+    elapsed: Duration,
+}
+
+impl Default for SmoothLayout {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(200), Ease::CubicOut)
+    }
+}
+
+impl SmoothLayout {
+    pub fn new(duration: Duration, ease: Ease) -> Self {
+        Self::with_timing(TransitionTiming::Eased(duration, ease))
+    }
+
+    pub fn spring(spring: Spring) -> Self {
+        Self::with_timing(TransitionTiming::Spring(spring))
+    }
+
+    pub fn with_timing(timing: TransitionTiming) -> Self {
+        Self {
+            timing,
+            previous: None,
+            from_offset: Vec2::ZERO,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// The offset from the layout position at which the node is currently drawn.
+    pub fn offset(&self) -> Vec2 {
+        self.from_offset * (1. - self.timing.progress(self.elapsed))
+    }
+
+    /// Record the node's latest layout position and advance by `delta`, returning the offset.
+    pub fn step(&mut self, layout_position: Vec2, delta: Duration) -> Vec2 {
+        match self.previous {
+            Some(previous) if previous != layout_position => {
+                self.from_offset = self.offset() + previous - layout_position;
+                self.elapsed = Duration::ZERO;
+            }
+            _ => self.elapsed += delta,
+        }
+        self.previous = Some(layout_position);
+        if self.timing.duration() <= self.elapsed {
+            self.from_offset = Vec2::ZERO;
+        }
+        self.offset()
+    }
+}
+
+pub trait SmoothLayoutExt: Bundle + Sized {
+    /// Animate the node to its new position when the layout moves it.
+    fn smooth_layout(self) -> (Self, SmoothLayout) {
+        (self, SmoothLayout::default())
+    }
+}
+
+impl<T: Bundle> SmoothLayoutExt for T {}
+
+/// Offsets nodes with a [`SmoothLayout`] after layout has positioned them.
+///
+/// The flex system rewrites every node's translation each frame, so the offset never accumulates.
+pub fn smooth_layout_system(
+    time: Res<Time>,
+    mut query: Query<(&mut SmoothLayout, &mut Transform)>,
+) {
+    for (mut smooth, mut transform) in query.iter_mut() {
+        let offset = smooth.step(transform.translation.truncate(), time.delta());
+        if offset != Vec2::ZERO {
+            transform.translation += offset.extend(0.);
+        }
+    }
+}
+
+/// Adds the systems that drive the crate's style animations.
+pub struct StyleAnimationPlugin;
+
+impl Plugin for StyleAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TimelineFinished>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                style_timeline_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                ui_animation_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                style_transition_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                spring_position_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                smooth_layout_system
+                    .after(UiSystem::Flex)
+                    .before(TransformSystem::TransformPropagate),
+            )
+            .add_system(hover_grow_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn timeline() -> StyleTimeline {
+        StyleTimeline::new()
+            .keyframe(2., |style| {
+                style.width(Val::Px(20.));
+            })
+            .keyframe(1., |style| {
+                style.width(Val::Px(10.));
+            })
+    }
+
+    #[test]
+    fn sample_keyframes() {
+        let base = style().width(Val::Px(0.));
+        let timeline = timeline();
+        let width = |time| timeline.sample(&base, time, Vec2::ZERO).size.width;
+        assert_eq!(timeline.duration(), 2.);
+        assert_eq!(width(0.), Val::Px(0.));
+        assert_eq!(width(0.5), Val::Px(5.));
+        assert_eq!(width(1.5), Val::Px(15.));
+        assert_eq!(width(3.), Val::Px(20.));
+    }
+
+    #[test]
+    fn repeat_modes() {
+        let timeline = timeline();
+        assert_eq!(timeline.local_time(3.), 2.);
+        let timeline = timeline.repeat(TimelineRepeat::Loop);
+        assert_eq!(timeline.local_time(3.), 1.);
+        let timeline = timeline.repeat(TimelineRepeat::PingPong);
+        assert_eq!(timeline.local_time(3.5), 0.5);
+    }
+
+    #[test]
+    fn slide_and_fade() {
+        let original = style().left(Val::Percent(10.));
+        let original_color = BackgroundColor(Color::rgba(1., 1., 1., 0.8));
+        let mut style = original.clone();
+        let mut color = original_color;
+        let context_size = Vec2::new(200., 100.);
+        Anim::SlideFromLeft(50.).apply(
+            0.5,
+            &original,
+            Some(original_color),
+            &mut style,
+            Some(&mut color),
+            context_size,
+        );
+        assert_eq!(style.position.left, Val::Px(-5.));
+        assert_eq!(color.0.a(), 0.8);
+
+        let original = crate::style().bottom(Val::Px(10.));
+        Anim::SlideFromBottom(20.).apply(0., &original, None, &mut style, None, context_size);
+        assert_eq!(style.position.bottom, Val::Px(-10.));
+
+        Anim::Fade.apply(
+            0.25,
+            &original,
+            Some(original_color),
+            &mut style,
+            Some(&mut color),
+            context_size,
+        );
+        assert_eq!(style, original);
+        assert_eq!(color.0.a(), 0.2);
+    }
+
+    #[test]
+    fn exit_visibility() {
+        let mut animation = UiAnimation::exit(Anim::Fade, Duration::from_secs(2));
+        assert_eq!(animation.visibility(), 1.);
+        animation.elapsed = Duration::from_secs(1);
+        assert_eq!(animation.visibility(), 0.5);
+        animation.elapsed = Duration::from_secs(3);
+        assert!(animation.is_finished());
+        assert_eq!(animation.visibility(), 0.);
+    }
+
+    #[test]
+    fn hover_grow_steps() {
+        let mut grow = HoverGrow::new(1.5).with_duration(Duration::from_secs(1));
+        assert_eq!(grow.step(true, 0.5), 1.25);
+        assert_eq!(grow.step(true, 1.), 1.5);
+        assert_eq!(grow.step(false, 0.25), 1.375);
+        assert_eq!(grow.step(false, 2.), 1.);
+    }
+
+    #[test]
+    fn smooth_layout_offsets_jumps() {
+        let mut smooth = SmoothLayout::new(Duration::from_secs(2), Ease::Linear);
+        assert_eq!(smooth.step(Vec2::ZERO, Duration::ZERO), Vec2::ZERO);
+        let offset = smooth.step(Vec2::new(100., 0.), Duration::from_secs(1));
+        assert_eq!(offset, Vec2::new(-100., 0.));
+        let offset = smooth.step(Vec2::new(100., 0.), Duration::from_secs(1));
+        assert_eq!(offset, Vec2::new(-50., 0.));
+        // a second jump starts from where the node is currently drawn
+        let offset = smooth.step(Vec2::new(0., 0.), Duration::ZERO);
+        assert_eq!(offset, Vec2::new(50., 0.));
+        smooth.step(Vec2::ZERO, Duration::from_secs(2));
+        assert_eq!(smooth.offset(), Vec2::ZERO);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Breadth;
+use crate::NumRect;
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+
+impl AbsDiffEq for Breadth {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    /// Breadths of different variants are never equal.
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        match (self, other) {
+            (Breadth::Px(a), Breadth::Px(b)) | (Breadth::Percent(a), Breadth::Percent(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl RelativeEq for Breadth {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        match (self, other) {
+            (Breadth::Px(a), Breadth::Px(b)) | (Breadth::Percent(a), Breadth::Percent(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl NumRect {
+    fn edges(&self) -> [Breadth; 4] {
+        [self.left, self.right, self.top, self.bottom]
+    }
+}
+
+impl AbsDiffEq for NumRect {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.edges()
+            .iter()
+            .zip(other.edges().iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for NumRect {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.edges()
+            .iter()
+            .zip(other.edges().iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use approx::assert_abs_diff_ne;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn float_noise_is_tolerated() {
+        let third = Breadth::Px(100.) / 3.;
+        assert_abs_diff_eq!(third * 3., Breadth::Px(100.), epsilon = 1e-4);
+        assert_relative_eq!(Breadth::Percent(0.1 + 0.2), Breadth::Percent(0.3));
+        assert_abs_diff_ne!(Breadth::Px(1.), Breadth::Percent(1.));
+        let rect = NumRect::all(Breadth::Px(1. / 3.));
+        assert_relative_eq!(rect, NumRect::all(Breadth::Px(0.333_333_34)));
+        assert_abs_diff_ne!(rect, NumRect::horizontal(Breadth::Px(1. / 3.)));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::widgets::node_rect;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Reads the computed layout of UI nodes in UI coordinates,
+/// with the origin at the top left of the window.
+///
+/// The values are from the most recent layout, so changes to a [`Style`]
+/// are reflected on the following frame.
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub struct ComputedUi<'w, 's> {
+    nodes: Query<
+        'w,
+        's,
+        (
+            &'static Node,
+            &'static GlobalTransform,
+            Option<&'static Style>,
+            Option<&'static Children>,
+            Option<&'static Parent>,
+        ),
+    >,
+}
+
+impl<'w, 's> ComputedUi<'w, 's> {
+    /// The rectangle covered by the node, including its padding and border.
+    pub fn rect(&self, entity: Entity) -> Option<Rect> {
+        let (node, transform, ..) = self.nodes.get(entity).ok()?;
+        Some(node_rect(node, transform))
+    }
+
+    /// The rectangle inside the node's padding and border, where its children are laid out.
+    ///
+    /// Percentages are resolved against the width of the parent, or of the node itself
+    /// if it has no parent node.
+    pub fn content_rect(&self, entity: Entity) -> Option<Rect> {
+        let (node, transform, style, _, parent) = self.nodes.get(entity).ok()?;
+        let rect = node_rect(node, transform);
+        let Some(style) = style else {
+            return Some(rect);
+        };
+        let basis = parent
+            .and_then(|parent| self.nodes.get(parent.get()).ok())
+            .map_or(node.size().x, |(parent, ..)| parent.size().x);
+        let inset = |val: Val| match val {
+            Val::Px(value) => value,
+            Val::Percent(value) => basis * value / 100.,
+            Val::Auto | Val::Undefined => 0.,
+        };
+        let edge = |padding: Val, border: Val| inset(padding) + inset(border);
+        let min = rect.min
+            + Vec2::new(
+                edge(style.padding.left, style.border.left),
+                edge(style.padding.top, style.border.top),
+            );
+        let max = rect.max
+            - Vec2::new(
+                edge(style.padding.right, style.border.right),
+                edge(style.padding.bottom, style.border.bottom),
+            );
+        Some(Rect::from_corners(min, max.max(min)))
+    }
+
+    /// The smallest rectangle containing all of the node's children,
+    /// or `None` if it has no child nodes.
+    pub fn children_extent(&self, entity: Entity) -> Option<Rect> {
+        let (_, _, _, children, _) = self.nodes.get(entity).ok()?;
+        children?
+            .iter()
+            .filter_map(|child| self.rect(*child))
+            .reduce(|extent, rect| extent.union(rect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::set_node_size;
+    use crate::prelude::*;
+    use bevy::ecs::system::SystemState;
+
+    fn spawn_node(world: &mut World, bundle: NodeBundle, rect: Rect) -> Entity {
+        let entity = world
+            .spawn(NodeBundle {
+                global_transform: GlobalTransform::from_translation(rect.center().extend(0.)),
+                ..bundle
+            })
+            .id();
+        set_node_size(world, entity, rect.size());
+        entity
+    }
+
+    #[test]
+    fn rects_are_read_from_the_layout() {
+        let mut world = World::new();
+        let panel = spawn_node(
+            &mut world,
+            node()
+                .padding(Breadth::Px(10.))
+                .border(Breadth::Percent(5.)),
+            Rect::new(0., 0., 200., 100.),
+        );
+        let first = spawn_node(&mut world, node(), Rect::new(20., 20., 70., 40.));
+        let second = spawn_node(&mut world, node(), Rect::new(20., 40., 70., 60.));
+        world.entity_mut(panel).push_children(&[first, second]);
+        let mut state = SystemState::<ComputedUi>::new(&mut world);
+        let computed = state.get(&world);
+        assert_eq!(computed.rect(panel), Some(Rect::new(0., 0., 200., 100.)));
+        assert_eq!(
+            computed.content_rect(panel),
+            Some(Rect::new(20., 20., 180., 80.))
+        );
+        assert_eq!(
+            computed.children_extent(panel),
+            Some(Rect::new(20., 20., 70., 60.))
+        );
+        assert_eq!(computed.children_extent(first), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::fields::set_if_neq;
+use crate::fields::StyleFields;
+use bevy::ecs::entity::EntityMap;
+use bevy::ecs::entity::MapEntities;
+use bevy::ecs::entity::MapEntitiesError;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// Copies the computed size of another node into this node's [`Style`].
+///
+/// The size is taken from the target's most recent layout, so a change in the target's size
+/// is matched on the following frame.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct MatchSize {
+    pub target: Entity,
+    pub width: bool,
+    pub height: bool,
+}
+
+impl FromWorld for MatchSize {
+    fn from_world(_: &mut World) -> Self {
+        Self {
+            target: Entity::from_raw(u32::MAX),
+            width: false,
+            height: false,
+        }
+    }
+}
+
+impl MapEntities for MatchSize {
+    fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+        self.target = entity_map.get(self.target)?;
+        Ok(())
+    }
+}
+
+impl MatchSize {
+    pub fn width_of(target: Entity) -> Self {
+        Self {
+            target,
+            width: true,
+            height: false,
+        }
+    }
+
+    pub fn height_of(target: Entity) -> Self {
+        Self {
+            target,
+            width: false,
+            height: true,
+        }
+    }
+
+    pub fn size_of(target: Entity) -> Self {
+        Self {
+            target,
+            width: true,
+            height: true,
+        }
+    }
+}
+
+pub trait MatchSizeExt: Bundle + Sized {
+    /// Keep the node's width equal to the width of `target`.
+    fn match_width_of(self, target: Entity) -> (Self, MatchSize) {
+        (self, MatchSize::width_of(target))
+    }
+
+    /// Keep the node's height equal to the height of `target`.
+    fn match_height_of(self, target: Entity) -> (Self, MatchSize) {
+        (self, MatchSize::height_of(target))
+    }
+
+    /// Keep the node's size equal to the size of `target`.
+    fn match_size_of(self, target: Entity) -> (Self, MatchSize) {
+        (self, MatchSize::size_of(target))
+    }
+}
+
+impl<T: Bundle> MatchSizeExt for T {}
+
+/// Sets the size of each node with a [`MatchSize`] to the computed size of its target.
+pub fn match_size_system(mut query: Query<(&MatchSize, &mut Style)>, nodes: Query<&Node>) {
+    for (match_size, mut style) in query.iter_mut() {
+        let Ok(target) = nodes.get(match_size.target) else {
+            continue;
+        };
+        let size = target.size();
+        if match_size.width {
+            set_if_neq(&mut style, Style::width_mut, Val::Px(size.x));
+        }
+        if match_size.height {
+            set_if_neq(&mut style, Style::height_mut, Val::Px(size.y));
+        }
+    }
+}
+
+/// Adds the system that keeps nodes with a [`MatchSize`] the size of their targets.
+pub struct StyleConstraintPlugin;
+
+impl Plugin for StyleConstraintPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            match_size_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+/// Set the computed size of a [`Node`] as if it had been laid out, for tests that run without the layout systems.
+#[cfg(test)]
+pub(crate) fn set_node_size(world: &mut World, entity: Entity, size: Vec2) {
+    use bevy::reflect::Struct;
+    let mut node = world.get_mut::<Node>(entity).unwrap();
+    *node
+        .field_mut("calculated_size")
+        .unwrap()
+        .downcast_mut::<Vec2>()
+        .unwrap() = size;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StyleBuilderExt;
+
+    #[test]
+    fn width_is_copied_from_target() {
+        let mut app = App::new();
+        app.add_system(match_size_system);
+        let target = app.world.spawn(NodeBundle::default()).id();
+        set_node_size(&mut app.world, target, Vec2::new(120., 40.));
+        let width = app
+            .world
+            .spawn(
+                NodeBundle::default()
+                    .height(Val::Px(10.))
+                    .match_width_of(target),
+            )
+            .id();
+        let both = app
+            .world
+            .spawn(NodeBundle::default().match_size_of(target))
+            .id();
+        app.update();
+        let style = app.world.get::<Style>(width).unwrap();
+        assert_eq!(style.size, Size::new(Val::Px(120.), Val::Px(10.)));
+        let style = app.world.get::<Style>(both).unwrap();
+        assert_eq!(style.size, Size::new(Val::Px(120.), Val::Px(40.)));
+        set_node_size(&mut app.world, target, Vec2::new(80., 40.));
+        app.update();
+        let style = app.world.get::<Style>(width).unwrap();
+        assert_eq!(style.size.width, Val::Px(80.));
+    }
+}
@@ -0,0 +1,833 @@
+use crate::fields::StyleFields;
+use crate::fields::ValField;
+use crate::parse_val;
+use crate::restyle::StylePatch;
+use crate::theme::ThemeTokens;
+use crate::Breadth;
+use crate::ParseBreadthError;
+use bevy::prelude::*;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// The CSS value of a [`Val`], or `None` for [`Val::Undefined`], which has no equivalent.
+pub fn val_to_css(val: Val) -> Option<String> {
+    match val {
+        Val::Undefined => None,
+        Val::Auto => Some("auto".to_string()),
+        Val::Px(value) => Some(Breadth::Px(value).to_string()),
+        Val::Percent(value) => Some(Breadth::Percent(value).to_string()),
+    }
+}
+
+fn display_to_css(display: Display) -> &'static str {
+    match display {
+        Display::Flex => "flex",
+        Display::None => "none",
+    }
+}
+
+fn position_type_to_css(position_type: PositionType) -> &'static str {
+    match position_type {
+        PositionType::Relative => "relative",
+        PositionType::Absolute => "absolute",
+    }
+}
+
+fn direction_to_css(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Inherit => "inherit",
+        Direction::LeftToRight => "ltr",
+        Direction::RightToLeft => "rtl",
+    }
+}
+
+fn flex_direction_to_css(flex_direction: FlexDirection) -> &'static str {
+    match flex_direction {
+        FlexDirection::Row => "row",
+        FlexDirection::Column => "column",
+        FlexDirection::RowReverse => "row-reverse",
+        FlexDirection::ColumnReverse => "column-reverse",
+    }
+}
+
+fn flex_wrap_to_css(flex_wrap: FlexWrap) -> &'static str {
+    match flex_wrap {
+        FlexWrap::NoWrap => "nowrap",
+        FlexWrap::Wrap => "wrap",
+        FlexWrap::WrapReverse => "wrap-reverse",
+    }
+}
+
+fn align_items_to_css(align_items: AlignItems) -> &'static str {
+    match align_items {
+        AlignItems::FlexStart => "flex-start",
+        AlignItems::FlexEnd => "flex-end",
+        AlignItems::Center => "center",
+        AlignItems::Baseline => "baseline",
+        AlignItems::Stretch => "stretch",
+    }
+}
+
+fn align_self_to_css(align_self: AlignSelf) -> &'static str {
+    match align_self {
+        AlignSelf::Auto => "auto",
+        AlignSelf::FlexStart => "flex-start",
+        AlignSelf::FlexEnd => "flex-end",
+        AlignSelf::Center => "center",
+        AlignSelf::Baseline => "baseline",
+        AlignSelf::Stretch => "stretch",
+    }
+}
+
+fn align_content_to_css(align_content: AlignContent) -> &'static str {
+    match align_content {
+        AlignContent::FlexStart => "flex-start",
+        AlignContent::FlexEnd => "flex-end",
+        AlignContent::Center => "center",
+        AlignContent::Stretch => "stretch",
+        AlignContent::SpaceBetween => "space-between",
+        AlignContent::SpaceAround => "space-around",
+    }
+}
+
+fn justify_content_to_css(justify_content: JustifyContent) -> &'static str {
+    match justify_content {
+        JustifyContent::FlexStart => "flex-start",
+        JustifyContent::FlexEnd => "flex-end",
+        JustifyContent::Center => "center",
+        JustifyContent::SpaceBetween => "space-between",
+        JustifyContent::SpaceAround => "space-around",
+        JustifyContent::SpaceEvenly => "space-evenly",
+    }
+}
+
+fn overflow_to_css(overflow: Overflow) -> &'static str {
+    match overflow {
+        Overflow::Visible => "visible",
+        Overflow::Hidden => "hidden",
+    }
+}
+
+/// Collects declarations, skipping any that are unchanged from the default style.
+struct Declarations(String);
+
+impl Declarations {
+    fn push(&mut self, property: &str, value: impl std::fmt::Display) {
+        let _ = writeln!(self.0, "{property}: {value};");
+    }
+
+    fn push_if<T: PartialEq>(
+        &mut self,
+        property: &str,
+        value: T,
+        default: T,
+        css: fn(T) -> &'static str,
+    ) {
+        if value != default {
+            self.push(property, css(value));
+        }
+    }
+
+    fn push_val(&mut self, property: &str, value: Val, default: Val) {
+        if value != default {
+            if let Some(css) = val_to_css(value) {
+                self.push(property, css);
+            }
+        }
+    }
+
+    /// Push the edges of a rect, using the shorthand when all four edges are equal.
+    ///
+    /// Without a shorthand, as for `position`, each edge is pushed under its own name.
+    fn push_rect(&mut self, shorthand: Option<&str>, rect: UiRect, default: UiRect) {
+        if rect == default {
+            return;
+        }
+        let edges = [
+            ("left", rect.left, default.left),
+            ("right", rect.right, default.right),
+            ("top", rect.top, default.top),
+            ("bottom", rect.bottom, default.bottom),
+        ];
+        match shorthand {
+            Some(shorthand)
+                if rect.left == rect.right && rect.left == rect.top && rect.left == rect.bottom =>
+            {
+                self.push_val(shorthand, rect.left, default.left);
+            }
+            Some(shorthand) => {
+                for (edge, value, default) in edges {
+                    self.push_val(&format!("{shorthand}-{edge}"), value, default);
+                }
+            }
+            None => {
+                for (edge, value, default) in edges {
+                    self.push_val(edge, value, default);
+                }
+            }
+        }
+    }
+}
+
+/// A CSS-like representation of `style`, with one declaration per line.
+///
+/// Only properties that differ from [`Style::default`] are written, apart from `display`,
+/// which is always written as the nodes are laid out as flexboxes. Undefined values have no
+/// CSS equivalent and are left out.
+pub fn style_to_css(style: &Style) -> String {
+    let default = Style::default();
+    let mut css = Declarations(String::new());
+    css.push("display", display_to_css(style.display));
+    css.push_if(
+        "position",
+        style.position_type,
+        default.position_type,
+        position_type_to_css,
+    );
+    css.push_rect(None, insets(style), insets(&default));
+    css.push_if(
+        "direction",
+        style.direction,
+        default.direction,
+        direction_to_css,
+    );
+    css.push_if(
+        "flex-direction",
+        style.flex_direction,
+        default.flex_direction,
+        flex_direction_to_css,
+    );
+    css.push_if(
+        "flex-wrap",
+        style.flex_wrap,
+        default.flex_wrap,
+        flex_wrap_to_css,
+    );
+    css.push_if(
+        "align-items",
+        style.align_items,
+        default.align_items,
+        align_items_to_css,
+    );
+    css.push_if(
+        "align-self",
+        style.align_self,
+        default.align_self,
+        align_self_to_css,
+    );
+    css.push_if(
+        "align-content",
+        style.align_content,
+        default.align_content,
+        align_content_to_css,
+    );
+    css.push_if(
+        "justify-content",
+        style.justify_content,
+        default.justify_content,
+        justify_content_to_css,
+    );
+    css.push_rect(Some("margin"), style.margin, default.margin);
+    css.push_rect(Some("padding"), style.padding, default.padding);
+    css.push_rect(Some("border-width"), style.border, default.border);
+    if style.flex_grow != default.flex_grow {
+        css.push("flex-grow", style.flex_grow);
+    }
+    if style.flex_shrink != default.flex_shrink {
+        css.push("flex-shrink", style.flex_shrink);
+    }
+    css.push_val("flex-basis", style.flex_basis, default.flex_basis);
+    css.push_val("width", style.width_val(), default.width_val());
+    css.push_val("height", style.height_val(), default.height_val());
+    css.push_val("min-width", style.min_width_val(), default.min_width_val());
+    css.push_val(
+        "min-height",
+        style.min_height_val(),
+        default.min_height_val(),
+    );
+    css.push_val("max-width", style.max_width_val(), default.max_width_val());
+    css.push_val(
+        "max-height",
+        style.max_height_val(),
+        default.max_height_val(),
+    );
+    if let Some(aspect_ratio) = style.aspect_ratio {
+        css.push("aspect-ratio", aspect_ratio);
+    }
+    css.push_if(
+        "overflow",
+        style.overflow,
+        default.overflow,
+        overflow_to_css,
+    );
+    css.0
+}
+
+/// An error from parsing CSS declarations with [`parse_css`].
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum ParseCssError {
+    #[error("`{0}` is not a declaration, expected `property: value`")]
+    MissingColon(String),
+    #[error("unknown property `{0}`")]
+    UnknownProperty(String),
+    #[error("`{value}` is not a valid value for `{property}`")]
+    InvalidValue { property: String, value: String },
+    #[error("invalid length for `{property}`: {source}")]
+    InvalidLength {
+        property: String,
+        source: ParseBreadthError,
+    },
+    #[error("unknown theme token `${token}` for `{property}`")]
+    UnknownToken { property: String, token: String },
+}
+
+/// How lengths such as `$spacing-2` are resolved.
+#[derive(Clone, Copy)]
+enum Tokens<'a> {
+    /// Tokens are unknown.
+    None,
+    /// Tokens are looked up in the theme.
+    Theme(&'a ThemeTokens),
+    /// Every token is accepted as [`Val::Undefined`], to check the rest of the declarations.
+    Any,
+}
+
+const DISPLAYS: [Display; 2] = [Display::Flex, Display::None];
+const POSITION_TYPES: [PositionType; 2] = [PositionType::Relative, PositionType::Absolute];
+const DIRECTIONS: [Direction; 3] = [
+    Direction::Inherit,
+    Direction::LeftToRight,
+    Direction::RightToLeft,
+];
+const FLEX_DIRECTIONS: [FlexDirection; 4] = [
+    FlexDirection::Row,
+    FlexDirection::Column,
+    FlexDirection::RowReverse,
+    FlexDirection::ColumnReverse,
+];
+const FLEX_WRAPS: [FlexWrap; 3] = [FlexWrap::NoWrap, FlexWrap::Wrap, FlexWrap::WrapReverse];
+const ALIGN_ITEMS: [AlignItems; 5] = [
+    AlignItems::FlexStart,
+    AlignItems::FlexEnd,
+    AlignItems::Center,
+    AlignItems::Baseline,
+    AlignItems::Stretch,
+];
+const ALIGN_SELFS: [AlignSelf; 6] = [
+    AlignSelf::Auto,
+    AlignSelf::FlexStart,
+    AlignSelf::FlexEnd,
+    AlignSelf::Center,
+    AlignSelf::Baseline,
+    AlignSelf::Stretch,
+];
+const ALIGN_CONTENTS: [AlignContent; 6] = [
+    AlignContent::FlexStart,
+    AlignContent::FlexEnd,
+    AlignContent::Center,
+    AlignContent::Stretch,
+    AlignContent::SpaceBetween,
+    AlignContent::SpaceAround,
+];
+const JUSTIFY_CONTENTS: [JustifyContent; 6] = [
+    JustifyContent::FlexStart,
+    JustifyContent::FlexEnd,
+    JustifyContent::Center,
+    JustifyContent::SpaceBetween,
+    JustifyContent::SpaceAround,
+    JustifyContent::SpaceEvenly,
+];
+const OVERFLOWS: [Overflow; 2] = [Overflow::Visible, Overflow::Hidden];
+
+/// A parser for the value of one declaration.
+struct Value<'a> {
+    property: &'a str,
+    value: &'a str,
+    tokens: Tokens<'a>,
+}
+
+impl<'a> Value<'a> {
+    fn invalid(&self) -> ParseCssError {
+        ParseCssError::InvalidValue {
+            property: self.property.to_string(),
+            value: self.value.to_string(),
+        }
+    }
+
+    fn length(&self, value: &str) -> Result<Val, ParseCssError> {
+        if let Some(token) = value.strip_prefix('$') {
+            let val = match self.tokens {
+                Tokens::None => None,
+                Tokens::Theme(tokens) => tokens.get(token),
+                Tokens::Any => Some(Val::Undefined),
+            };
+            return val.ok_or_else(|| ParseCssError::UnknownToken {
+                property: self.property.to_string(),
+                token: token.to_string(),
+            });
+        }
+        parse_val(value).map_err(|source| ParseCssError::InvalidLength {
+            property: self.property.to_string(),
+            source,
+        })
+    }
+
+    fn number(&self, value: &str) -> Result<f32, ParseCssError> {
+        value.parse().map_err(|_| self.invalid())
+    }
+
+    /// The variant of `T` whose CSS keyword is the value.
+    fn keyword<T: Copy>(
+        &self,
+        variants: &[T],
+        css: fn(T) -> &'static str,
+    ) -> Result<T, ParseCssError> {
+        variants
+            .iter()
+            .copied()
+            .find(|&variant| self.value.eq_ignore_ascii_case(css(variant)))
+            .ok_or_else(|| self.invalid())
+    }
+
+    /// One to four lengths, in the CSS order of top, right, bottom and left.
+    fn rect(&self) -> Result<UiRect, ParseCssError> {
+        let lengths = self
+            .value
+            .split_whitespace()
+            .map(|value| self.length(value))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (top, right, bottom, left) = match lengths[..] {
+            [all] => (all, all, all, all),
+            [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+            [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+            [top, right, bottom, left] => (top, right, bottom, left),
+            _ => return Err(self.invalid()),
+        };
+        Ok(UiRect::new(left, right, top, bottom))
+    }
+
+    /// The grow, shrink and basis of the `flex` shorthand.
+    fn flex(&self) -> Result<(f32, f32, Val), ParseCssError> {
+        match self.value.to_ascii_lowercase().as_str() {
+            "none" => return Ok((0., 0., Val::Auto)),
+            "auto" => return Ok((1., 1., Val::Auto)),
+            "initial" => return Ok((0., 1., Val::Auto)),
+            _ => {}
+        }
+        let values = self.value.split_whitespace().collect::<Vec<_>>();
+        let is_number = |value: &str| value.parse::<f32>().is_ok();
+        match values[..] {
+            [grow] if is_number(grow) => Ok((self.number(grow)?, 1., Val::Px(0.))),
+            [basis] => Ok((1., 1., self.length(basis)?)),
+            [grow, shrink] if is_number(shrink) => {
+                Ok((self.number(grow)?, self.number(shrink)?, Val::Px(0.)))
+            }
+            [grow, basis] => Ok((self.number(grow)?, 1., self.length(basis)?)),
+            [grow, shrink, basis] => Ok((
+                self.number(grow)?,
+                self.number(shrink)?,
+                self.length(basis)?,
+            )),
+            _ => Err(self.invalid()),
+        }
+    }
+
+    /// A number, a ratio such as `16 / 9`, or `auto`.
+    fn aspect_ratio(&self) -> Result<Option<f32>, ParseCssError> {
+        if self.value.eq_ignore_ascii_case("auto") {
+            return Ok(None);
+        }
+        match self.value.split_once('/') {
+            Some((width, height)) => Ok(Some(
+                self.number(width.trim())? / self.number(height.trim())?,
+            )),
+            None => Ok(Some(self.number(self.value)?)),
+        }
+    }
+}
+
+/// The field of a rect for the CSS edge `edge`.
+fn edge_mut<'r>(rect: &'r mut UiRect, edge: &str) -> Option<&'r mut Val> {
+    match edge {
+        "left" => Some(&mut rect.left),
+        "right" => Some(&mut rect.right),
+        "top" => Some(&mut rect.top),
+        "bottom" => Some(&mut rect.bottom),
+        _ => None,
+    }
+}
+
+/// The `left`, `right`, `top` and `bottom` insets of `style`.
+fn insets(style: &Style) -> UiRect {
+    UiRect::new(
+        style.left_val(),
+        style.right_val(),
+        style.top_val(),
+        style.bottom_val(),
+    )
+}
+
+fn flex_basis_mut(style: &mut Style) -> &mut Val {
+    &mut style.flex_basis
+}
+
+/// The rect of `style` named by a `margin`, `padding` or `border-width` property.
+fn rect_mut<'s>(style: &'s mut Style, property: &str) -> Option<&'s mut UiRect> {
+    match property {
+        "margin" => Some(&mut style.margin),
+        "padding" => Some(&mut style.padding),
+        "border-width" => Some(&mut style.border),
+        _ => None,
+    }
+}
+
+/// Splits a per-edge property such as `margin-left`, `border-left-width`
+/// or `border-width-left` into its rect property and edge.
+fn split_edge(property: &str) -> Option<(&'static str, &str)> {
+    if let Some(edge) = property
+        .strip_prefix("border-")
+        .and_then(|rest| rest.strip_suffix("-width"))
+    {
+        return Some(("border-width", edge));
+    }
+    ["margin", "padding", "border-width"]
+        .into_iter()
+        .find_map(|rect| Some((rect, property.strip_prefix(rect)?.strip_prefix('-')?)))
+}
+
+fn declaration(property: &str, value: &str, tokens: Tokens) -> Result<StylePatch, ParseCssError> {
+    let value = Value {
+        property,
+        value,
+        tokens,
+    };
+    let patch = match property {
+        "display" => {
+            let display = value.keyword(&DISPLAYS, display_to_css)?;
+            StylePatch::new(move |style| style.display = display)
+        }
+        "position" => {
+            let position_type = value.keyword(&POSITION_TYPES, position_type_to_css)?;
+            StylePatch::new(move |style| style.position_type = position_type)
+        }
+        "direction" => {
+            let direction = value.keyword(&DIRECTIONS, direction_to_css)?;
+            StylePatch::new(move |style| style.direction = direction)
+        }
+        "flex-direction" => {
+            let flex_direction = value.keyword(&FLEX_DIRECTIONS, flex_direction_to_css)?;
+            StylePatch::new(move |style| style.flex_direction = flex_direction)
+        }
+        "flex-wrap" => {
+            let flex_wrap = value.keyword(&FLEX_WRAPS, flex_wrap_to_css)?;
+            StylePatch::new(move |style| style.flex_wrap = flex_wrap)
+        }
+        "align-items" => {
+            let align_items = value.keyword(&ALIGN_ITEMS, align_items_to_css)?;
+            StylePatch::new(move |style| style.align_items = align_items)
+        }
+        "align-self" => {
+            let align_self = value.keyword(&ALIGN_SELFS, align_self_to_css)?;
+            StylePatch::new(move |style| style.align_self = align_self)
+        }
+        "align-content" => {
+            let align_content = value.keyword(&ALIGN_CONTENTS, align_content_to_css)?;
+            StylePatch::new(move |style| style.align_content = align_content)
+        }
+        "justify-content" => {
+            let justify_content = value.keyword(&JUSTIFY_CONTENTS, justify_content_to_css)?;
+            StylePatch::new(move |style| style.justify_content = justify_content)
+        }
+        "overflow" => {
+            let overflow = value.keyword(&OVERFLOWS, overflow_to_css)?;
+            StylePatch::new(move |style| style.overflow = overflow)
+        }
+        "left" | "right" | "top" | "bottom" => {
+            let length = value.length(value.value)?;
+            let inset: ValField = match property {
+                "left" => Style::left_mut,
+                "right" => Style::right_mut,
+                "top" => Style::top_mut,
+                _ => Style::bottom_mut,
+            };
+            StylePatch::new(move |style| *inset(style) = length)
+        }
+        "margin" | "padding" | "border-width" => {
+            let rect = value.rect()?;
+            let property = property.to_string();
+            StylePatch::new(move |style| {
+                if let Some(target) = rect_mut(style, &property) {
+                    *target = rect;
+                }
+            })
+        }
+        "width" | "height" | "min-width" | "min-height" | "max-width" | "max-height"
+        | "flex-basis" => {
+            let length = value.length(value.value)?;
+            let setter: ValField = match property {
+                "width" => Style::width_mut,
+                "height" => Style::height_mut,
+                "min-width" => Style::min_width_mut,
+                "min-height" => Style::min_height_mut,
+                "max-width" => Style::max_width_mut,
+                "max-height" => Style::max_height_mut,
+                _ => flex_basis_mut,
+            };
+            StylePatch::new(move |style| *setter(style) = length)
+        }
+        "flex-grow" => {
+            let grow = value.number(value.value)?;
+            StylePatch::new(move |style| style.flex_grow = grow)
+        }
+        "flex-shrink" => {
+            let shrink = value.number(value.value)?;
+            StylePatch::new(move |style| style.flex_shrink = shrink)
+        }
+        "flex" => {
+            let (grow, shrink, basis) = value.flex()?;
+            StylePatch::new(move |style| {
+                style.flex_grow = grow;
+                style.flex_shrink = shrink;
+                style.flex_basis = basis;
+            })
+        }
+        "aspect-ratio" => {
+            let aspect_ratio = value.aspect_ratio()?;
+            StylePatch::new(move |style| style.aspect_ratio = aspect_ratio)
+        }
+        _ => {
+            let Some((rect, edge)) = split_edge(property)
+                .filter(|(_, edge)| matches!(*edge, "left" | "right" | "top" | "bottom"))
+            else {
+                return Err(ParseCssError::UnknownProperty(property.to_string()));
+            };
+            let length = value.length(value.value)?;
+            let edge = edge.to_string();
+            StylePatch::new(move |style| {
+                if let Some(val) = rect_mut(style, rect).and_then(|rect| edge_mut(rect, &edge)) {
+                    *val = length;
+                }
+            })
+        }
+    };
+    Ok(patch)
+}
+
+/// Parses each of the `;` separated declarations in `css`.
+fn declarations<'a>(
+    css: &'a str,
+    tokens: Tokens<'a>,
+) -> impl Iterator<Item = Result<StylePatch, ParseCssError>> + 'a {
+    css.split(';')
+        .map(str::trim)
+        .filter(|declaration| !declaration.is_empty())
+        .map(move |text| {
+            let (property, value) = text
+                .split_once(':')
+                .ok_or_else(|| ParseCssError::MissingColon(text.to_string()))?;
+            declaration(&property.trim().to_ascii_lowercase(), value.trim(), tokens)
+        })
+}
+
+/// Parses CSS declarations such as `"margin: 4px 8px; flex: 1 0 auto;"` into a patch
+/// that applies them in order.
+///
+/// The properties written by [`style_to_css`] are supported, along with the `flex` shorthand,
+/// the one to four value forms of `margin`, `padding` and `border-width`, and their per-edge
+/// properties such as `margin-left` and `border-left-width`.
+///
+/// Lengths can't be theme tokens such as `$spacing-2`, see [`parse_themed_css`].
+pub fn parse_css(css: &str) -> Result<StylePatch, ParseCssError> {
+    let patches = declarations(css, Tokens::None).collect::<Result<Vec<_>, _>>()?;
+    Ok(StylePatch::new(move |style| {
+        for patch in &patches {
+            patch.apply(style);
+        }
+    }))
+}
+
+/// The errors from every invalid declaration in `css`, where [`parse_css`] stops at the first.
+pub fn css_errors(css: &str) -> Vec<ParseCssError> {
+    declarations(css, Tokens::None)
+        .filter_map(Result::err)
+        .collect()
+}
+
+/// Like [`parse_css`], with lengths such as `$spacing-2` looked up in `tokens`.
+pub fn parse_themed_css(css: &str, tokens: &ThemeTokens) -> Result<StylePatch, ParseCssError> {
+    let patches = declarations(css, Tokens::Theme(tokens)).collect::<Result<Vec<_>, _>>()?;
+    Ok(StylePatch::new(move |style| {
+        for patch in &patches {
+            patch.apply(style);
+        }
+    }))
+}
+
+/// The errors from every invalid declaration in `css`, with lengths such as `$spacing-2`
+/// looked up in `tokens`. Without tokens, any token is accepted.
+pub fn themed_css_errors(css: &str, tokens: Option<&ThemeTokens>) -> Vec<ParseCssError> {
+    let tokens = tokens.map_or(Tokens::Any, Tokens::Theme);
+    declarations(css, tokens).filter_map(Result::err).collect()
+}
+
+/// Apply the declarations in `css` to `style`, looking up lengths such as `$spacing-2` in `tokens`.
+/// Declarations that fail, such as those using a token that isn't set, are skipped.
+pub(crate) fn apply_themed_css(css: &str, tokens: &ThemeTokens, style: &mut Style) {
+    for patch in declarations(css, Tokens::Theme(tokens)).flatten() {
+        patch.apply(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumRect;
+    use crate::StyleBuilderExt;
+
+    #[test]
+    fn default_style_is_a_flexbox() {
+        assert_eq!(style_to_css(&Style::default()), "display: flex;\n");
+    }
+
+    #[test]
+    fn changed_properties_are_written() {
+        let style = crate::style()
+            .absolute()
+            .left(Val::Px(10.))
+            .row()
+            .padding(Breadth::Px(4.))
+            .margin(UiRect::new(
+                Val::Px(1.),
+                Val::Px(2.),
+                Val::Undefined,
+                Val::Auto,
+            ))
+            .width(Val::Percent(100.))
+            .grow(1.)
+            .hide_overflow();
+        assert_eq!(
+            style_to_css(&style),
+            "display: flex;\n\
+             position: absolute;\n\
+             left: 10px;\n\
+             margin-left: 1px;\n\
+             margin-right: 2px;\n\
+             margin-bottom: auto;\n\
+             padding: 4px;\n\
+             flex-grow: 1;\n\
+             width: 100%;\n\
+             overflow: hidden;\n"
+        );
+    }
+
+    #[test]
+    fn exported_css_parses_back() {
+        let style = crate::style()
+            .absolute()
+            .left(Val::Px(10.))
+            .column()
+            .align_self(AlignSelf::Center)
+            .padding(Breadth::Px(4.))
+            .margin(UiRect::new(
+                Val::Px(1.),
+                Val::Px(2.),
+                Val::Undefined,
+                Val::Auto,
+            ))
+            .border(NumRect::horizontal(Breadth::Px(3.)))
+            .width(Val::Percent(100.))
+            .max_height(Val::Px(300.))
+            .grow(1.)
+            .hide_overflow();
+        let mut parsed = Style::default();
+        parse_css(&style_to_css(&style)).unwrap().apply(&mut parsed);
+        assert_eq!(parsed, style);
+    }
+
+    #[test]
+    fn shorthands_are_expanded() {
+        let style = crate::style().css("margin: 4px 8px; flex: 1 0 auto; aspect-ratio: 16 / 8;");
+        assert_eq!(
+            style.margin,
+            UiRect::new(Val::Px(8.), Val::Px(8.), Val::Px(4.), Val::Px(4.))
+        );
+        assert_eq!(
+            (style.flex_grow, style.flex_shrink, style.flex_basis),
+            (1., 0., Val::Auto)
+        );
+        assert_eq!(style.aspect_ratio, Some(2.));
+        let node = crate::node().css("padding: 1px 2px 3px; border-left-width: 5%; flex: 2");
+        assert_eq!(
+            node.style.padding,
+            UiRect::new(Val::Px(2.), Val::Px(2.), Val::Px(1.), Val::Px(3.))
+        );
+        assert_eq!(node.style.border.left, Val::Percent(5.));
+        assert_eq!(node.style.flex_grow, 2.);
+    }
+
+    #[test]
+    fn every_invalid_declaration_is_collected() {
+        let errors = css_errors("width: 10px; colour: red; height: 5; display: flex");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0],
+            ParseCssError::UnknownProperty("colour".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_declarations_are_reported() {
+        let error = |css| crate::style().try_css(css).unwrap_err();
+        assert_eq!(
+            error("width 10px"),
+            ParseCssError::MissingColon("width 10px".to_string())
+        );
+        assert_eq!(
+            error("colour: red"),
+            ParseCssError::UnknownProperty("colour".to_string())
+        );
+        assert_eq!(
+            error("display: grid"),
+            ParseCssError::InvalidValue {
+                property: "display".to_string(),
+                value: "grid".to_string()
+            }
+        );
+        assert!(matches!(
+            error("width: 10em"),
+            ParseCssError::InvalidLength { .. }
+        ));
+    }
+
+    #[test]
+    fn tokens_are_looked_up_in_the_theme() {
+        let tokens = ThemeTokens::default().with("spacing-2", Val::Px(8.));
+        let mut style = Style::default();
+        parse_themed_css("margin: $spacing-2 4px; width: $spacing-2", &tokens)
+            .unwrap()
+            .apply(&mut style);
+        assert_eq!(
+            style.margin,
+            UiRect::new(Val::Px(4.), Val::Px(4.), Val::Px(8.), Val::Px(8.))
+        );
+        assert_eq!(style.size.width, Val::Px(8.));
+
+        let unknown = ParseCssError::UnknownToken {
+            property: "width".to_string(),
+            token: "spacing-3".to_string(),
+        };
+        assert_eq!(
+            parse_themed_css("width: $spacing-3", &tokens).unwrap_err(),
+            unknown
+        );
+        assert_eq!(
+            themed_css_errors("width: $spacing-3", Some(&tokens)),
+            [unknown]
+        );
+        assert!(themed_css_errors("width: $spacing-3", None).is_empty());
+        assert!(matches!(
+            parse_css("width: $spacing-2"),
+            Err(ParseCssError::UnknownToken { .. })
+        ));
+    }
+}
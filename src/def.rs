@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+const fn rect_all(value: Val) -> UiRect {
+    UiRect {
+        left: value,
+        right: value,
+        top: value,
+        bottom: value,
+    }
+}
+
+/// A plain copy of a [`Style`] whose constructors are all `const fn`,
+/// so style tables can be declared as `const` or `static` items.
+/// Convert it into a [`Style`] when spawning a node, for example with `node().style(ROW.into())`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StyleDef {
+    pub display: Display,
+    pub position_type: PositionType,
+    pub direction: Direction,
+    pub flex_direction: FlexDirection,
+    pub flex_wrap: FlexWrap,
+    pub align_items: AlignItems,
+    pub align_self: AlignSelf,
+    pub align_content: AlignContent,
+    pub justify_content: JustifyContent,
+    pub position: UiRect,
+    pub margin: UiRect,
+    pub padding: UiRect,
+    pub border: UiRect,
+    pub flex_grow: f32,
+    pub flex_shrink: f32,
+    pub flex_basis: Val,
+    pub size: Size,
+    pub min_size: Size,
+    pub max_size: Size,
+    pub aspect_ratio: Option<f32>,
+    pub overflow: Overflow,
+}
+
+impl StyleDef {
+    /// The same values as [`Style::default`].
+    pub const DEFAULT: StyleDef = StyleDef {
+        display: Display::Flex,
+        position_type: PositionType::Relative,
+        direction: Direction::Inherit,
+        flex_direction: FlexDirection::Row,
+        flex_wrap: FlexWrap::NoWrap,
+        align_items: AlignItems::Stretch,
+        align_self: AlignSelf::Auto,
+        align_content: AlignContent::Stretch,
+        justify_content: JustifyContent::FlexStart,
+        position: rect_all(Val::Undefined),
+        margin: rect_all(Val::Undefined),
+        padding: rect_all(Val::Undefined),
+        border: rect_all(Val::Undefined),
+        flex_grow: 0.,
+        flex_shrink: 1.,
+        flex_basis: Val::Auto,
+        size: Size::AUTO,
+        min_size: Size::AUTO,
+        max_size: Size::AUTO,
+        aspect_ratio: None,
+        overflow: Overflow::Visible,
+    };
+
+    /// The [`Style`] described by this definition.
+    pub fn to_style(&self) -> Style {
+        Style {
+            display: self.display,
+            position_type: self.position_type,
+            direction: self.direction,
+            flex_direction: self.flex_direction,
+            flex_wrap: self.flex_wrap,
+            align_items: self.align_items,
+            align_self: self.align_self,
+            align_content: self.align_content,
+            justify_content: self.justify_content,
+            position: self.position,
+            margin: self.margin,
+            padding: self.padding,
+            border: self.border,
+            flex_grow: self.flex_grow,
+            flex_shrink: self.flex_shrink,
+            flex_basis: self.flex_basis,
+            size: self.size,
+            min_size: self.min_size,
+            max_size: self.max_size,
+            aspect_ratio: self.aspect_ratio,
+            overflow: self.overflow,
+        }
+    }
+
+    pub const fn hide(mut self) -> Self {
+        self.display = Display::None;
+        self
+    }
+
+    pub const fn absolute(mut self) -> Self {
+        self.position_type = PositionType::Absolute;
+        self
+    }
+
+    pub const fn position(mut self, position: UiRect) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub const fn row(mut self) -> Self {
+        self.flex_direction = FlexDirection::Row;
+        self
+    }
+
+    pub const fn column(mut self) -> Self {
+        self.flex_direction = FlexDirection::Column;
+        self
+    }
+
+    pub const fn wrap(mut self) -> Self {
+        self.flex_wrap = FlexWrap::Wrap;
+        self
+    }
+
+    pub const fn align_items(mut self, align: AlignItems) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    pub const fn align_self(mut self, align: AlignSelf) -> Self {
+        self.align_self = align;
+        self
+    }
+
+    pub const fn align_content(mut self, align: AlignContent) -> Self {
+        self.align_content = align;
+        self
+    }
+
+    pub const fn justify_content(mut self, justify: JustifyContent) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
+    pub const fn margin(mut self, margin: UiRect) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set all four margins to `value`.
+    pub const fn margin_all(self, value: Val) -> Self {
+        self.margin(rect_all(value))
+    }
+
+    pub const fn padding(mut self, padding: UiRect) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the padding on all four sides to `value`.
+    pub const fn padding_all(self, value: Val) -> Self {
+        self.padding(rect_all(value))
+    }
+
+    pub const fn border(mut self, border: UiRect) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set the border thickness on all four sides to `value`.
+    pub const fn border_all(self, value: Val) -> Self {
+        self.border(rect_all(value))
+    }
+
+    pub const fn grow(mut self, grow: f32) -> Self {
+        self.flex_grow = grow;
+        self
+    }
+
+    pub const fn shrink(mut self, shrink: f32) -> Self {
+        self.flex_shrink = shrink;
+        self
+    }
+
+    pub const fn basis(mut self, basis: Val) -> Self {
+        self.flex_basis = basis;
+        self
+    }
+
+    pub const fn width(mut self, width: Val) -> Self {
+        self.size.width = width;
+        self
+    }
+
+    pub const fn height(mut self, height: Val) -> Self {
+        self.size.height = height;
+        self
+    }
+
+    pub const fn size(mut self, width: Val, height: Val) -> Self {
+        self.size = Size { width, height };
+        self
+    }
+
+    pub const fn min_size(mut self, width: Val, height: Val) -> Self {
+        self.min_size = Size { width, height };
+        self
+    }
+
+    pub const fn max_size(mut self, width: Val, height: Val) -> Self {
+        self.max_size = Size { width, height };
+        self
+    }
+
+    pub const fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    pub const fn hide_overflow(mut self) -> Self {
+        self.overflow = Overflow::Hidden;
+        self
+    }
+}
+
+impl Default for StyleDef {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<StyleDef> for Style {
+    fn from(def: StyleDef) -> Self {
+        def.to_style()
+    }
+}
+
+impl From<&StyleDef> for Style {
+    fn from(def: &StyleDef) -> Self {
+        def.to_style()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn default_matches_style_default() {
+        assert_eq!(StyleDef::DEFAULT.to_style(), Style::default());
+    }
+
+    #[test]
+    fn const_tables_convert_to_styles() {
+        const ROWS: [StyleDef; 2] = [
+            StyleDef::DEFAULT.row().height(Val::Px(24.)),
+            StyleDef::DEFAULT.column().padding_all(Val::Px(4.)).grow(1.),
+        ];
+        let built = style().column().padding(Breadth::Px(4.)).grow(1.);
+        assert_eq!(Style::from(&ROWS[1]), built);
+        assert_eq!(node().style(ROWS[0].into()).style.size.height, Val::Px(24.));
+    }
+}
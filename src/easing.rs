@@ -0,0 +1,170 @@
+/// An easing function, mapping linear progress in `0..=1` to eased progress.
+///
+/// `Back` easings overshoot outside of `0..=1` before settling.
+#[derive(Copy, Clone, Debug, Default)]
+pub enum Ease {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// A user supplied easing function.
+    Custom(fn(f32) -> f32),
+}
+
+const BACK_C1: f32 = 1.70158;
+const BACK_C2: f32 = BACK_C1 * 1.525;
+const BACK_C3: f32 = BACK_C1 + 1.;
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1. / D {
+        N * t * t
+    } else if t < 2. / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+impl Ease {
+    /// Ease `t`, which is clamped to `0..=1` first.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1. - (1. - t) * (1. - t),
+            Ease::QuadInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+            Ease::CubicIn => t * t * t,
+            Ease::CubicOut => 1. - (1. - t).powi(3),
+            Ease::CubicInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Ease::ExpoIn => {
+                if t == 0. {
+                    0.
+                } else {
+                    2f32.powf(10. * t - 10.)
+                }
+            }
+            Ease::ExpoOut => {
+                if t == 1. {
+                    1.
+                } else {
+                    1. - 2f32.powf(-10. * t)
+                }
+            }
+            Ease::ExpoInOut => {
+                if t == 0. || t == 1. {
+                    t
+                } else if t < 0.5 {
+                    2f32.powf(20. * t - 10.) / 2.
+                } else {
+                    (2. - 2f32.powf(-20. * t + 10.)) / 2.
+                }
+            }
+            Ease::BackIn => BACK_C3 * t * t * t - BACK_C1 * t * t,
+            Ease::BackOut => 1. + BACK_C3 * (t - 1.).powi(3) + BACK_C1 * (t - 1.).powi(2),
+            Ease::BackInOut => {
+                if t < 0.5 {
+                    (2. * t).powi(2) * ((BACK_C2 + 1.) * 2. * t - BACK_C2) / 2.
+                } else {
+                    ((2. * t - 2.).powi(2) * ((BACK_C2 + 1.) * (t * 2. - 2.) + BACK_C2) + 2.) / 2.
+                }
+            }
+            Ease::BounceIn => 1. - bounce_out(1. - t),
+            Ease::BounceOut => bounce_out(t),
+            Ease::BounceInOut => {
+                if t < 0.5 {
+                    (1. - bounce_out(1. - 2. * t)) / 2.
+                } else {
+                    (1. + bounce_out(2. * t - 1.)) / 2.
+                }
+            }
+            Ease::Custom(ease) => ease(t),
+        }
+    }
+}
+
+impl From<fn(f32) -> f32> for Ease {
+    fn from(ease: fn(f32) -> f32) -> Self {
+        Ease::Custom(ease)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Ease; 17] = [
+        Ease::Linear,
+        Ease::QuadIn,
+        Ease::QuadOut,
+        Ease::QuadInOut,
+        Ease::CubicIn,
+        Ease::CubicOut,
+        Ease::CubicInOut,
+        Ease::ExpoIn,
+        Ease::ExpoOut,
+        Ease::ExpoInOut,
+        Ease::BackIn,
+        Ease::BackOut,
+        Ease::BackInOut,
+        Ease::BounceIn,
+        Ease::BounceOut,
+        Ease::BounceInOut,
+        Ease::Custom(|t| t * t * (3. - 2. * t)),
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for ease in ALL {
+            assert!(ease.sample(0.).abs() < 1e-5, "{ease:?}");
+            assert!((ease.sample(1.) - 1.).abs() < 1e-5, "{ease:?}");
+        }
+    }
+
+    #[test]
+    fn in_out_easings_are_symmetric() {
+        for ease in [
+            Ease::QuadInOut,
+            Ease::CubicInOut,
+            Ease::ExpoInOut,
+            Ease::BackInOut,
+            Ease::BounceInOut,
+        ] {
+            assert!((ease.sample(0.5) - 0.5).abs() < 1e-5, "{ease:?}");
+        }
+        assert_eq!(Ease::QuadIn.sample(0.5), 0.25);
+        assert_eq!(Ease::QuadOut.sample(0.5), 0.75);
+    }
+}
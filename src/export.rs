@@ -0,0 +1,151 @@
+use crate::css::style_to_css;
+use crate::registry::StyleClasses;
+use crate::stylesheet::StyleRule;
+use crate::stylesheet::StyleSheet;
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+/// A saved copy of a UI tree: the name, classes and style of each node,
+/// with the style written as CSS declarations.
+///
+/// Export a tree with [`export_ui_tree`] after tweaking it live, then save it
+/// with [`to_style_sheet`](Self::to_style_sheet) or serialize the description itself.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
+pub struct UiTreeDescription {
+    pub name: Option<String>,
+    pub classes: Vec<String>,
+    pub css: String,
+    pub children: Vec<UiTreeDescription>,
+}
+
+impl UiTreeDescription {
+    /// A stylesheet with a rule for each node that can be found by its path of names,
+    /// such as `hud/healthbar/fill`, as used by [`UiQueryExt`](crate::query::UiQueryExt).
+    ///
+    /// Nodes without a [`Name`], and their descendants, have no path and are left out.
+    pub fn to_style_sheet(&self) -> StyleSheet {
+        let mut sheet = StyleSheet::default();
+        self.push_rules(None, &mut sheet);
+        sheet
+    }
+
+    fn push_rules(&self, parent: Option<&str>, sheet: &mut StyleSheet) {
+        let Some(name) = &self.name else {
+            return;
+        };
+        let path = match parent {
+            Some(parent) => format!("{parent}/{name}"),
+            None => name.clone(),
+        };
+        sheet.rules.push(StyleRule {
+            class: path.clone(),
+            css: self.css.clone(),
+            media: None,
+        });
+        for child in &self.children {
+            child.push_rules(Some(&path), sheet);
+        }
+    }
+}
+
+/// Describe the UI node `entity` and all of its descendants.
+///
+/// Returns `None` if `entity` has no [`Style`]. Children without a [`Style`] are left out.
+pub fn export_ui_tree(world: &World, entity: Entity) -> Option<UiTreeDescription> {
+    let style = world.get::<Style>(entity)?;
+    let children = world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .filter_map(|child| export_ui_tree(world, *child))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(UiTreeDescription {
+        name: world.get::<Name>(entity).map(|name| name.to_string()),
+        classes: world
+            .get::<StyleClasses>(entity)
+            .map(|classes| classes.names().map(str::to_string).collect())
+            .unwrap_or_default(),
+        css: style_to_css(style),
+        children,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn spawn_hud(world: &mut World) -> Entity {
+        let fill = world
+            .spawn((node().width(Val::Percent(75.)), Name::new("fill")))
+            .id();
+        let unnamed = world.spawn(node().height(Val::Px(2.))).id();
+        let healthbar = world
+            .spawn((
+                node().padding(Breadth::Px(2.)).classes(["bar"]),
+                Name::new("healthbar"),
+            ))
+            .push_children(&[fill, unnamed])
+            .id();
+        world
+            .spawn((node().column(), Name::new("hud")))
+            .push_children(&[healthbar])
+            .id()
+    }
+
+    #[test]
+    fn trees_are_exported_with_their_names_and_styles() {
+        let mut world = World::new();
+        let hud = spawn_hud(&mut world);
+        world.get_mut::<Style>(hud).unwrap().size.width = Val::Px(300.);
+        let tree = export_ui_tree(&world, hud).unwrap();
+        assert_eq!(tree.name.as_deref(), Some("hud"));
+        assert!(tree.css.contains("width: 300px"));
+        assert!(tree.css.contains("flex-direction: column"));
+        let healthbar = &tree.children[0];
+        assert_eq!(healthbar.classes, vec!["bar".to_string()]);
+        assert_eq!(healthbar.children.len(), 2);
+        assert_eq!(healthbar.children[1].name, None);
+        let empty = world.spawn_empty().id();
+        assert!(export_ui_tree(&world, empty).is_none());
+    }
+
+    #[test]
+    fn named_nodes_are_written_to_a_style_sheet() {
+        let mut world = World::new();
+        let hud = spawn_hud(&mut world);
+        let sheet = export_ui_tree(&world, hud).unwrap().to_style_sheet();
+        assert_eq!(
+            sheet.classes(),
+            vec!["hud", "hud/healthbar", "hud/healthbar/fill"]
+        );
+        assert!(sheet.validate(None).is_empty());
+
+        let mut registry = StyleRegistry::default();
+        sheet.register(&mut registry).unwrap();
+        let mut style = Style::default();
+        registry
+            .get("hud/healthbar/fill")
+            .unwrap()
+            .apply(&mut style);
+        assert_eq!(style.size.width, Val::Percent(75.));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn exported_sheets_round_trip_through_ron() {
+        let mut world = World::new();
+        let hud = spawn_hud(&mut world);
+        let sheet = export_ui_tree(&world, hud).unwrap().to_style_sheet();
+        let ron = sheet.to_ron().unwrap();
+        assert_eq!(ron::from_str::<StyleSheet>(&ron).unwrap(), sheet);
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+/// Access to the [`Style`] fields that Bevy has been reshaping between releases,
+/// such as `size` becoming `width` and `height` and `position` becoming inset fields.
+///
+/// The builder setters, CSS parsing and export, and the systems that read or write
+/// single fields go through these accessors instead of naming the fields.
+/// Conversions that map whole structs, such as [`StyleDef`](crate::def::StyleDef), lerping,
+/// scaling and the taffy conversion, still name the fields and change alongside this impl.
+pub(crate) trait StyleFields {
+    fn left_val(&self) -> Val;
+    fn right_val(&self) -> Val;
+    fn top_val(&self) -> Val;
+    fn bottom_val(&self) -> Val;
+    fn width_val(&self) -> Val;
+    fn height_val(&self) -> Val;
+    fn min_width_val(&self) -> Val;
+    fn min_height_val(&self) -> Val;
+    fn max_width_val(&self) -> Val;
+    fn max_height_val(&self) -> Val;
+
+    fn left_mut(&mut self) -> &mut Val;
+    fn right_mut(&mut self) -> &mut Val;
+    fn top_mut(&mut self) -> &mut Val;
+    fn bottom_mut(&mut self) -> &mut Val;
+    fn width_mut(&mut self) -> &mut Val;
+    fn height_mut(&mut self) -> &mut Val;
+    fn min_width_mut(&mut self) -> &mut Val;
+    fn min_height_mut(&mut self) -> &mut Val;
+    fn max_width_mut(&mut self) -> &mut Val;
+    fn max_height_mut(&mut self) -> &mut Val;
+
+    fn set_size(&mut self, size: Size) {
+        *self.width_mut() = size.width;
+        *self.height_mut() = size.height;
+    }
+
+    fn set_min_size(&mut self, size: Size) {
+        *self.min_width_mut() = size.width;
+        *self.min_height_mut() = size.height;
+    }
+
+    fn set_max_size(&mut self, size: Size) {
+        *self.max_width_mut() = size.width;
+        *self.max_height_mut() = size.height;
+    }
+}
+
+macro_rules! impl_style_fields {
+    ($($val:ident, $mut:ident => $($field:ident).+;)*) => {
+        impl StyleFields for Style {
+            $(
+                fn $val(&self) -> Val {
+                    self.$($field).+
+                }
+
+                fn $mut(&mut self) -> &mut Val {
+                    &mut self.$($field).+
+                }
+            )*
+        }
+    };
+}
+
+impl_style_fields! {
+    left_val, left_mut => position.left;
+    right_val, right_mut => position.right;
+    top_val, top_mut => position.top;
+    bottom_val, bottom_mut => position.bottom;
+    width_val, width_mut => size.width;
+    height_val, height_mut => size.height;
+    min_width_val, min_width_mut => min_size.width;
+    min_height_val, min_height_mut => min_size.height;
+    max_width_val, max_width_mut => max_size.width;
+    max_height_val, max_height_mut => max_size.height;
+}
+
+/// An accessor for one [`Val`] field, such as `Style::width_mut`.
+pub(crate) type ValField = fn(&mut Style) -> &mut Val;
+
+/// Set the field of `style` reached by `field`, such as `Style::width_mut`, to `val`,
+/// only marking the style as changed if the value differs. Returns whether it was written.
+pub(crate) fn set_if_neq(style: &mut Mut<Style>, field: ValField, val: Val) -> bool {
+    let target = field(style.bypass_change_detection());
+    if *target == val {
+        return false;
+    }
+    *target = val;
+    style.set_changed();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_reach_the_fields() {
+        let mut style = Style::default();
+        *style.left_mut() = Val::Px(1.);
+        *style.bottom_mut() = Val::Px(2.);
+        style.set_size(Size::new(Val::Px(3.), Val::Percent(4.)));
+        *style.max_height_mut() = Val::Auto;
+        assert_eq!(style.position.left, Val::Px(1.));
+        assert_eq!(style.position.bottom, Val::Px(2.));
+        assert_eq!(style.size, Size::new(Val::Px(3.), Val::Percent(4.)));
+        assert_eq!(style.max_size.height, Val::Auto);
+        assert_eq!(style.width_val(), Val::Px(3.));
+        assert_eq!(style.top_val(), Val::Undefined);
+    }
+
+    #[test]
+    fn set_if_neq_only_marks_changed_values() {
+        let mut world = World::new();
+        let entity = world.spawn(Style::default()).id();
+        world.clear_trackers();
+        let mut style = world.get_mut::<Style>(entity).unwrap();
+        assert!(!set_if_neq(&mut style, Style::width_mut, Val::Auto));
+        assert!(!style.is_changed());
+        assert!(set_if_neq(&mut style, Style::width_mut, Val::Px(5.)));
+        assert!(style.is_changed());
+        assert_eq!(style.size.width, Val::Px(5.));
+    }
+}
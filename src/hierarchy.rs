@@ -0,0 +1,428 @@
+use bevy::ecs::system::Command;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::utils::HashMap;
+
+use crate::node;
+use crate::StyleBuilderExt;
+
+/// Spawns children like a [`ChildBuilder`], recording the entities of named children.
+pub struct NamedChildBuilder<'w, 's, 'a, 'b> {
+    builder: &'b mut ChildBuilder<'w, 's, 'a>,
+    names: &'b mut HashMap<String, Entity>,
+}
+
+impl<'w, 's, 'a, 'b> NamedChildBuilder<'w, 's, 'a, 'b> {
+    /// Spawn an unnamed child.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> EntityCommands<'w, 's, '_> {
+        self.builder.spawn(bundle)
+    }
+
+    /// Spawn a child with a [`Name`], recording its entity under `name`.
+    ///
+    /// A later child with the same name replaces the earlier entry.
+    pub fn child_named(
+        &mut self,
+        name: impl Into<String>,
+        bundle: impl Bundle,
+    ) -> EntityCommands<'w, 's, '_> {
+        let name = name.into();
+        let child = self.builder.spawn((bundle, Name::new(name.clone())));
+        self.names.insert(name, child.id());
+        child
+    }
+
+    /// Spawn an unnamed child with children of its own, whose names are recorded too.
+    pub fn spawn_with_children(
+        &mut self,
+        bundle: impl Bundle,
+        children: impl FnOnce(&mut NamedChildBuilder),
+    ) -> Entity {
+        let names = &mut *self.names;
+        self.builder
+            .spawn(bundle)
+            .with_children(|builder| children(&mut NamedChildBuilder { builder, names }))
+            .id()
+    }
+
+    /// Spawn a named child with children of its own, whose names are recorded too.
+    pub fn child_named_with_children(
+        &mut self,
+        name: impl Into<String>,
+        bundle: impl Bundle,
+        children: impl FnOnce(&mut NamedChildBuilder),
+    ) -> Entity {
+        let name = name.into();
+        let names = &mut *self.names;
+        let entity = self
+            .builder
+            .spawn((bundle, Name::new(name.clone())))
+            .with_children(|builder| children(&mut NamedChildBuilder { builder, names }))
+            .id();
+        self.names.insert(name, entity);
+        entity
+    }
+}
+
+pub trait NamedChildrenExt {
+    /// Spawn children with `children` and return the entities of the named descendants by name.
+    fn with_named_children(
+        &mut self,
+        children: impl FnOnce(&mut NamedChildBuilder),
+    ) -> HashMap<String, Entity>;
+}
+
+impl<'w, 's, 'a> NamedChildrenExt for EntityCommands<'w, 's, 'a> {
+    fn with_named_children(
+        &mut self,
+        children: impl FnOnce(&mut NamedChildBuilder),
+    ) -> HashMap<String, Entity> {
+        let mut names = HashMap::default();
+        self.with_children(|builder| {
+            children(&mut NamedChildBuilder {
+                builder,
+                names: &mut names,
+            })
+        });
+        names
+    }
+}
+
+pub trait ChildShorthandExt {
+    /// Spawn a single text child.
+    fn with_text(&mut self, text: impl Into<String>, style: TextStyle) -> &mut Self;
+
+    /// Spawn a single image child.
+    fn with_image(&mut self, image: Handle<Image>) -> &mut Self;
+}
+
+impl<'w, 's, 'a> ChildShorthandExt for EntityCommands<'w, 's, 'a> {
+    fn with_text(&mut self, text: impl Into<String>, style: TextStyle) -> &mut Self {
+        self.with_children(|builder| {
+            builder.spawn(TextBundle::from_section(text, style));
+        })
+    }
+
+    fn with_image(&mut self, image: Handle<Image>) -> &mut Self {
+        self.with_children(|builder| {
+            builder.spawn(ImageBundle {
+                image: UiImage(image),
+                ..default()
+            });
+        })
+    }
+}
+
+pub trait ChildrenFromIterExt {
+    /// Call `spawn_child` with each of `items` to spawn a child for it.
+    fn children_from_iter<I: IntoIterator>(
+        &mut self,
+        items: I,
+        spawn_child: impl FnMut(&mut ChildBuilder, I::Item),
+    ) -> &mut Self;
+}
+
+impl<'w, 's, 'a> ChildrenFromIterExt for EntityCommands<'w, 's, 'a> {
+    fn children_from_iter<I: IntoIterator>(
+        &mut self,
+        items: I,
+        spawn_child: impl FnMut(&mut ChildBuilder, I::Item),
+    ) -> &mut Self {
+        self.with_children(|builder| {
+            builder.children_from_iter(items, spawn_child);
+        })
+    }
+}
+
+impl<'w, 's, 'a> ChildrenFromIterExt for ChildBuilder<'w, 's, 'a> {
+    fn children_from_iter<I: IntoIterator>(
+        &mut self,
+        items: I,
+        mut spawn_child: impl FnMut(&mut ChildBuilder, I::Item),
+    ) -> &mut Self {
+        for item in items {
+            spawn_child(self, item);
+        }
+        self
+    }
+}
+
+pub trait SpawnNodeExt<'w, 's> {
+    /// Spawn a [`NodeBundle`] styled by `build`.
+    fn spawn_node<'a, B: Bundle>(
+        &'a mut self,
+        build: impl FnOnce(NodeBundle) -> B,
+    ) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> SpawnNodeExt<'w, 's> for Commands<'w, 's> {
+    fn spawn_node<'a, B: Bundle>(
+        &'a mut self,
+        build: impl FnOnce(NodeBundle) -> B,
+    ) -> EntityCommands<'w, 's, 'a> {
+        self.spawn(build(node()))
+    }
+}
+
+impl<'w, 's, 'b> SpawnNodeExt<'w, 's> for ChildBuilder<'w, 's, 'b> {
+    fn spawn_node<'a, B: Bundle>(
+        &'a mut self,
+        build: impl FnOnce(NodeBundle) -> B,
+    ) -> EntityCommands<'w, 's, 'a> {
+        self.spawn(build(node()))
+    }
+}
+
+/// Spawns many bundles in one batch, optionally as children of `parent`.
+pub struct SpawnBatchStyled<B: Bundle> {
+    pub parent: Option<Entity>,
+    pub bundles: Vec<B>,
+}
+
+impl<B: Bundle> Command for SpawnBatchStyled<B> {
+    fn write(self, world: &mut World) {
+        let entities = world.spawn_batch(self.bundles).collect::<Vec<_>>();
+        if let Some(parent) = self.parent {
+            world.entity_mut(parent).push_children(&entities);
+        }
+    }
+}
+
+/// Clones `template` `count` times and passes each clone and its index to `build`.
+fn build_batch<T: Clone, B>(
+    count: usize,
+    template: T,
+    mut build: impl FnMut(usize, T) -> B,
+) -> Vec<B> {
+    (0..count).map(|i| build(i, template.clone())).collect()
+}
+
+pub trait SpawnBatchStyledExt {
+    /// Spawn `count` nodes in one batch, each built by `build` from its index and a clone of `template`.
+    ///
+    /// Much faster than spawning large lists and grids one entity at a time,
+    /// but the spawned entities are not returned.
+    fn spawn_batch_styled<T: Clone, B: Bundle>(
+        &mut self,
+        count: usize,
+        template: T,
+        build: impl FnMut(usize, T) -> B,
+    );
+}
+
+impl<'w, 's> SpawnBatchStyledExt for Commands<'w, 's> {
+    fn spawn_batch_styled<T: Clone, B: Bundle>(
+        &mut self,
+        count: usize,
+        template: T,
+        build: impl FnMut(usize, T) -> B,
+    ) {
+        self.add(SpawnBatchStyled {
+            parent: None,
+            bundles: build_batch(count, template, build),
+        });
+    }
+}
+
+impl<'w, 's, 'b> SpawnBatchStyledExt for ChildBuilder<'w, 's, 'b> {
+    fn spawn_batch_styled<T: Clone, B: Bundle>(
+        &mut self,
+        count: usize,
+        template: T,
+        build: impl FnMut(usize, T) -> B,
+    ) {
+        let parent = self.parent_entity();
+        self.add_command(SpawnBatchStyled {
+            parent: Some(parent),
+            bundles: build_batch(count, template, build),
+        });
+    }
+}
+
+/// Spawns a [`Camera2dBundle`] if the world has no camera.
+pub struct SpawnUiCamera;
+
+impl Command for SpawnUiCamera {
+    fn write(self, world: &mut World) {
+        if world.query::<&Camera>().iter(world).next().is_none() {
+            world.spawn(Camera2dBundle::default());
+        }
+    }
+}
+
+pub trait UiRootExt<'w, 's> {
+    /// Spawn a transparent node filling the window that lets interaction pass through.
+    fn spawn_ui_root<'a>(&'a mut self) -> EntityCommands<'w, 's, 'a>;
+
+    /// Spawn a root node with [`UiRootExt::spawn_ui_root`], and a camera if there isn't one.
+    fn spawn_ui_root_with_camera<'a>(&'a mut self) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> UiRootExt<'w, 's> for Commands<'w, 's> {
+    fn spawn_ui_root<'a>(&'a mut self) -> EntityCommands<'w, 's, 'a> {
+        self.spawn(NodeBundle {
+            style: crate::style()
+                .absolute()
+                .size((Val::Percent(100.), Val::Percent(100.))),
+            background_color: Color::NONE.into(),
+            focus_policy: FocusPolicy::Pass,
+            ..default()
+        })
+    }
+
+    fn spawn_ui_root_with_camera<'a>(&'a mut self) -> EntityCommands<'w, 's, 'a> {
+        self.add(SpawnUiCamera);
+        self.spawn_ui_root()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Breadth;
+    use crate::NodeNameExt;
+    use bevy::ecs::system::CommandQueue;
+
+    #[test]
+    fn named_children_are_returned() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let mut dialog = commands.spawn(node());
+        let dialog_id = dialog.id();
+        let names = dialog.with_named_children(|dialog| {
+            dialog.spawn(node());
+            dialog.spawn_with_children(node(), |buttons| {
+                buttons.child_named("ok_button", ButtonBundle::default());
+                buttons.child_named("cancel_button", ButtonBundle::default());
+            });
+            dialog.child_named_with_children("body", node(), |body| {
+                body.spawn(node());
+            });
+        });
+        queue.apply(&mut world);
+        assert_eq!(names.len(), 3);
+        let ok = names["ok_button"];
+        assert_eq!(world.get::<Name>(ok).unwrap().as_str(), "ok_button");
+        let buttons = world.get::<Parent>(ok).unwrap().get();
+        assert_eq!(world.get::<Parent>(buttons).unwrap().get(), dialog_id);
+        assert_eq!(world.get::<Parent>(names["body"]).unwrap().get(), dialog_id);
+        assert_eq!(world.get::<Children>(names["body"]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn shorthands_spawn_one_child() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let label = commands
+            .spawn(node())
+            .with_text("Label", TextStyle::default())
+            .id();
+        let icon = commands.spawn(node()).with_image(Handle::default()).id();
+        queue.apply(&mut world);
+        let children = world.get::<Children>(label).unwrap();
+        assert_eq!(children.len(), 1);
+        let text = world.get::<Text>(children[0]).unwrap();
+        assert_eq!(text.sections[0].value, "Label");
+        let children = world.get::<Children>(icon).unwrap();
+        assert_eq!(children.len(), 1);
+        assert!(world.get::<UiImage>(children[0]).is_some());
+    }
+
+    #[test]
+    fn children_are_spawned_for_each_item() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let items = ["Audio", "Video", "Controls"];
+        let list = commands
+            .spawn(node())
+            .children_from_iter(items.iter(), |list, item| {
+                list.spawn(node()).with_text(*item, TextStyle::default());
+            })
+            .id();
+        let mut nested = None;
+        commands.spawn(node()).with_children(|parent| {
+            nested = Some(
+                parent
+                    .spawn(node())
+                    .with_children(|group| {
+                        group.children_from_iter(0..2, |group, _| {
+                            group.spawn(node());
+                        });
+                    })
+                    .id(),
+            );
+        });
+        queue.apply(&mut world);
+        let children = world.get::<Children>(list).unwrap();
+        assert_eq!(children.len(), 3);
+        let label = world.get::<Children>(children[1]).unwrap()[0];
+        assert_eq!(world.get::<Text>(label).unwrap().sections[0].value, "Video");
+        assert_eq!(world.get::<Children>(nested.unwrap()).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn spawn_node_styles_the_node() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let mut child = None;
+        let row = commands
+            .spawn_node(|n| n.row().padding(Breadth::Px(4.)))
+            .with_children(|row| {
+                child = Some(row.spawn_node(|n| n.grow(1.).named("fill")).id());
+            })
+            .id();
+        queue.apply(&mut world);
+        let style = world.get::<Style>(row).unwrap();
+        assert_eq!(style.flex_direction, FlexDirection::Row);
+        assert_eq!(style.padding.left, Val::Px(4.));
+        let child = child.unwrap();
+        assert_eq!(world.get::<Name>(child).unwrap().as_str(), "fill");
+        assert_eq!(world.get::<Style>(child).unwrap().flex_grow, 1.);
+    }
+
+    #[test]
+    fn ui_root_fills_the_window() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let root = commands.spawn_ui_root_with_camera().id();
+        commands.spawn_ui_root_with_camera();
+        queue.apply(&mut world);
+        let style = world.get::<Style>(root).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(
+            style.size,
+            Size::new(Val::Percent(100.), Val::Percent(100.))
+        );
+        assert_eq!(*world.get::<FocusPolicy>(root).unwrap(), FocusPolicy::Pass);
+        assert_eq!(world.query::<&Camera>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn batches_are_spawned_under_the_parent() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let template = node().size_all(Val::Px(16.));
+        let grid = commands
+            .spawn(node().wrap())
+            .with_children(|grid| {
+                grid.spawn_batch_styled(100, template.clone(), |i, cell| {
+                    (cell, Name::new(format!("cell {i}")))
+                });
+            })
+            .id();
+        commands.spawn_batch_styled(3, template, |_, cell| cell);
+        queue.apply(&mut world);
+        let cells = world.get::<Children>(grid).unwrap();
+        assert_eq!(cells.len(), 100);
+        assert_eq!(world.get::<Name>(cells[42]).unwrap().as_str(), "cell 42");
+        assert_eq!(world.get::<Parent>(cells[0]).unwrap().get(), grid);
+        assert_eq!(world.query::<&Node>().iter(&world).count(), 104);
+    }
+}
@@ -0,0 +1,309 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::utils::HashSet;
+
+/// The opacity multiplier applied to the colors of disabled nodes without [`ColorStates`].
+pub const DISABLED_ALPHA: f32 = 0.5;
+
+/// The background colors of an interactive node in each of its states.
+///
+/// The node also needs an [`Interaction`], from a `ButtonBundle` or
+/// [`InteractiveExt::interactive`](crate::InteractiveExt::interactive).
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ColorStates {
+    pub normal: Color,
+    pub hovered: Color,
+    pub pressed: Color,
+    /// The color of the node while it is disabled.
+    pub disabled: Color,
+}
+
+impl FromWorld for ColorStates {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(Color::NONE, Color::NONE, Color::NONE)
+    }
+}
+
+impl ColorStates {
+    /// Colors for each interaction, with the normal color faded by [`DISABLED_ALPHA`] when disabled.
+    pub fn new(normal: Color, hovered: Color, pressed: Color) -> Self {
+        Self {
+            normal,
+            hovered,
+            pressed,
+            disabled: faded(normal),
+        }
+    }
+
+    pub fn with_disabled(mut self, disabled: Color) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// The color for `interaction`.
+    pub fn color(&self, interaction: Interaction) -> Color {
+        match interaction {
+            Interaction::Clicked => self.pressed,
+            Interaction::Hovered => self.hovered,
+            Interaction::None => self.normal,
+        }
+    }
+}
+
+pub trait ColorStatesExt: Bundle + Sized {
+    /// Change the node's background color when it is hovered or pressed.
+    fn colors(self, normal: Color, hovered: Color, pressed: Color) -> (Self, ColorStates) {
+        (self, ColorStates::new(normal, hovered, pressed))
+    }
+}
+
+impl<T: Bundle> ColorStatesExt for T {}
+
+/// Sets the background color of each node with [`ColorStates`] to the color for its interaction.
+///
+/// Disabled nodes are left to [`disabled_system`].
+#[allow(clippy::type_complexity)]
+pub fn color_states_system(
+    mut query: Query<
+        (&ColorStates, &Interaction, &mut BackgroundColor),
+        (
+            Or<(Changed<ColorStates>, Changed<Interaction>)>,
+            Without<DisabledVisuals>,
+        ),
+    >,
+) {
+    for (states, interaction, mut background_color) in query.iter_mut() {
+        let color = states.color(*interaction);
+        if background_color.0 != color {
+            background_color.0 = color;
+        }
+    }
+}
+
+/// Disables a node and its descendants.
+///
+/// Their [`Interaction`] is held at [`Interaction::None`], so the crate's widgets and
+/// any other system reading interactions ignore clicks and hovers on them,
+/// and their colors are greyed out until the marker is removed.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Disabled;
+
+/// The colors of a disabled node from before it was greyed out, restored when it is re-enabled.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct DisabledVisuals {
+    background: Option<Color>,
+    text: Vec<Color>,
+}
+
+pub trait DisabledExt {
+    /// Add [`Disabled`] to the entity if `disabled` is true, otherwise remove it.
+    fn disabled_when(&mut self, disabled: bool) -> &mut Self;
+}
+
+impl<'w, 's, 'a> DisabledExt for EntityCommands<'w, 's, 'a> {
+    fn disabled_when(&mut self, disabled: bool) -> &mut Self {
+        if disabled {
+            self.insert(Disabled)
+        } else {
+            self.remove::<Disabled>()
+        }
+    }
+}
+
+fn faded(mut color: Color) -> Color {
+    color.set_a(color.a() * DISABLED_ALPHA);
+    color
+}
+
+/// Suppresses the interactions of disabled nodes and greys them out,
+/// restoring their colors once they are no longer disabled.
+#[allow(clippy::type_complexity)]
+pub fn disabled_system(
+    mut commands: Commands,
+    roots: Query<Entity, With<Disabled>>,
+    children: Query<&Children>,
+    mut nodes: Query<(
+        Option<&mut Interaction>,
+        Option<&mut BackgroundColor>,
+        Option<&mut Text>,
+        Option<&ColorStates>,
+        Option<&DisabledVisuals>,
+    )>,
+    greyed: Query<Entity, With<DisabledVisuals>>,
+) {
+    let mut disabled = HashSet::default();
+    let mut stack = roots.iter().collect::<Vec<_>>();
+    while let Some(entity) = stack.pop() {
+        if disabled.insert(entity) {
+            if let Ok(children) = children.get(entity) {
+                stack.extend(children.iter().copied());
+            }
+        }
+    }
+    for &entity in &disabled {
+        let Ok((interaction, background_color, text, states, visuals)) = nodes.get_mut(entity)
+        else {
+            continue;
+        };
+        if let Some(mut interaction) = interaction {
+            if *interaction != Interaction::None {
+                *interaction = Interaction::None;
+            }
+        }
+        if visuals.is_some() {
+            continue;
+        }
+        let background = background_color.map(|mut background_color| {
+            let original = background_color.0;
+            background_color.0 = states.map_or_else(|| faded(original), |states| states.disabled);
+            original
+        });
+        let text = text.map_or_else(Vec::new, |mut text| {
+            text.sections
+                .iter_mut()
+                .map(|section| {
+                    let original = section.style.color;
+                    section.style.color = faded(original);
+                    original
+                })
+                .collect()
+        });
+        commands
+            .entity(entity)
+            .insert(DisabledVisuals { background, text });
+    }
+    for entity in greyed.iter().filter(|entity| !disabled.contains(entity)) {
+        let Ok((interaction, background_color, text, states, Some(visuals))) =
+            nodes.get_mut(entity)
+        else {
+            continue;
+        };
+        let visuals = visuals.clone();
+        if let (Some(mut background_color), Some(original)) = (background_color, visuals.background)
+        {
+            // The saved color can be a pressed or hovered state that no longer applies.
+            let interaction = interaction.map_or(Interaction::None, |interaction| *interaction);
+            background_color.0 = states.map_or(original, |states| states.color(interaction));
+        }
+        if let Some(mut text) = text {
+            for (section, &original) in text.sections.iter_mut().zip(&visuals.text) {
+                section.style.color = original;
+            }
+        }
+        commands.entity(entity).remove::<DisabledVisuals>();
+    }
+}
+
+/// Adds the systems that color nodes by their interaction and disable [`Disabled`] nodes.
+pub struct StyleInteractionPlugin;
+
+impl Plugin for StyleInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(CoreStage::PreUpdate, disabled_system.after(UiSystem::Focus))
+            .add_system(color_states_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InteractiveExt;
+    use crate::NodeColorExt;
+
+    #[test]
+    fn background_follows_interaction() {
+        let mut app = App::new();
+        app.add_plugin(StyleInteractionPlugin);
+        let button = app
+            .world
+            .spawn(NodeBundle::default().interactive().colors(
+                Color::GRAY,
+                Color::WHITE,
+                Color::BLACK,
+            ))
+            .id();
+        app.update();
+        let color = |app: &App| app.world.get::<BackgroundColor>(button).unwrap().0;
+        assert_eq!(color(&app), Color::GRAY);
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(color(&app), Color::WHITE);
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+        app.update();
+        assert_eq!(color(&app), Color::BLACK);
+    }
+
+    #[test]
+    fn disabled_nodes_ignore_interaction_and_are_greyed() {
+        let mut app = App::new();
+        app.add_system_to_stage(CoreStage::PreUpdate, disabled_system)
+            .add_system(color_states_system);
+        let button = app
+            .world
+            .spawn(NodeBundle::default().interactive().colors(
+                Color::GRAY,
+                Color::WHITE,
+                Color::BLACK,
+            ))
+            .id();
+        let label = app
+            .world
+            .spawn(TextBundle::from_section(
+                "Buy",
+                TextStyle {
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ))
+            .id();
+        let panel = app
+            .world
+            .spawn(NodeBundle::default().background_color(Color::BLUE))
+            .id();
+        app.world.entity_mut(button).push_children(&[label]);
+        app.world.entity_mut(panel).push_children(&[button]);
+        app.update();
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+        app.update();
+        let background = |app: &App, entity| app.world.get::<BackgroundColor>(entity).unwrap().0;
+        assert_eq!(background(&app, button), Color::BLACK);
+
+        let mut queue = bevy::ecs::system::CommandQueue::default();
+        Commands::new(&mut queue, &app.world)
+            .entity(panel)
+            .disabled_when(true);
+        queue.apply(&mut app.world);
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world.get::<Interaction>(button).unwrap(),
+            Interaction::None
+        );
+        assert_eq!(
+            background(&app, button),
+            ColorStates::new(Color::GRAY, Color::WHITE, Color::BLACK).disabled
+        );
+        assert_eq!(background(&app, panel).a(), DISABLED_ALPHA);
+        let text_color = |app: &App| {
+            app.world.get::<Text>(label).unwrap().sections[0]
+                .style
+                .color
+        };
+        assert_eq!(text_color(&app).a(), DISABLED_ALPHA);
+
+        Commands::new(&mut queue, &app.world)
+            .entity(panel)
+            .disabled_when(false);
+        queue.apply(&mut app.world);
+        app.update();
+        app.update();
+        assert_eq!(background(&app, button), Color::GRAY);
+        assert_eq!(background(&app, panel), Color::BLUE);
+        assert_eq!(text_color(&app), Color::WHITE);
+        assert!(app.world.get::<DisabledVisuals>(label).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn step<T: Clone>(a: &T, b: &T, t: f32) -> T {
+    if t < 0.5 {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+/// Interpolate between two [`Val`]s.
+///
+/// Values of the same numeric variant are interpolated directly.
+/// Mixed [`Val::Px`] and [`Val::Percent`] values are evaluated against `size` and
+/// interpolated in pixels. Non-numeric values step from `a` to `b` at `t = 0.5`.
+///
+/// At `t = 0` and `t = 1` the end values are returned unchanged. Other values of `t`
+/// outside `0..=1` extrapolate, so overshooting easings and springs are kept.
+pub fn lerp_val(a: Val, b: Val, t: f32, size: f32) -> Val {
+    if t == 0. {
+        return a;
+    }
+    if t == 1. {
+        return b;
+    }
+    match (a, b) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(lerp(a, b, t)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(lerp(a, b, t)),
+        (Val::Px(_) | Val::Percent(_), Val::Px(_) | Val::Percent(_)) => Val::Px(lerp(
+            a.evaluate(size).unwrap(),
+            b.evaluate(size).unwrap(),
+            t,
+        )),
+        _ => step(&a, &b, t),
+    }
+}
+
+/// Interpolate between two [`UiRect`]s. The left and right edges are evaluated against
+/// the width of `size`, the top and bottom edges against its height.
+pub fn lerp_rect(a: UiRect, b: UiRect, t: f32, size: Vec2) -> UiRect {
+    UiRect {
+        left: lerp_val(a.left, b.left, t, size.x),
+        right: lerp_val(a.right, b.right, t, size.x),
+        top: lerp_val(a.top, b.top, t, size.y),
+        bottom: lerp_val(a.bottom, b.bottom, t, size.y),
+    }
+}
+
+/// Interpolate between two [`Size`]s, evaluated against `size`.
+pub fn lerp_size(a: Size, b: Size, t: f32, size: Vec2) -> Size {
+    Size {
+        width: lerp_val(a.width, b.width, t, size.x),
+        height: lerp_val(a.height, b.height, t, size.y),
+    }
+}
+
+/// Interpolate between two [`Color`]s in linear RGBA space.
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    if t <= 0. {
+        return a;
+    }
+    if 1. <= t {
+        return b;
+    }
+    let [r, g, b_, alpha] = a.as_linear_rgba_f32();
+    let [r2, g2, b2, alpha2] = b.as_linear_rgba_f32();
+    Color::rgba_linear(
+        lerp(r, r2, t),
+        lerp(g, g2, t),
+        lerp(b_, b2, t),
+        lerp(alpha, alpha2, t),
+    )
+}
+
+/// Interpolate between two [`Style`]s.
+///
+/// Numeric fields are interpolated, with mixed units evaluated against `context_size`
+/// (usually the size of the parent node). Discrete fields such as `display` and
+/// `flex_direction` step from `a` to `b` at `t = 0.5`.
+pub fn lerp_styles(a: &Style, b: &Style, t: f32, context_size: Vec2) -> Style {
+    Style {
+        display: step(&a.display, &b.display, t),
+        position_type: step(&a.position_type, &b.position_type, t),
+        direction: step(&a.direction, &b.direction, t),
+        flex_direction: step(&a.flex_direction, &b.flex_direction, t),
+        flex_wrap: step(&a.flex_wrap, &b.flex_wrap, t),
+        align_items: step(&a.align_items, &b.align_items, t),
+        align_self: step(&a.align_self, &b.align_self, t),
+        align_content: step(&a.align_content, &b.align_content, t),
+        justify_content: step(&a.justify_content, &b.justify_content, t),
+        position: lerp_rect(a.position, b.position, t, context_size),
+        margin: lerp_rect(a.margin, b.margin, t, context_size),
+        padding: lerp_rect(a.padding, b.padding, t, context_size),
+        border: lerp_rect(a.border, b.border, t, context_size),
+        flex_grow: lerp(a.flex_grow, b.flex_grow, t),
+        flex_shrink: lerp(a.flex_shrink, b.flex_shrink, t),
+        flex_basis: lerp_val(a.flex_basis, b.flex_basis, t, context_size.x),
+        size: lerp_size(a.size, b.size, t, context_size),
+        min_size: lerp_size(a.min_size, b.min_size, t, context_size),
+        max_size: lerp_size(a.max_size, b.max_size, t, context_size),
+        aspect_ratio: match (a.aspect_ratio, b.aspect_ratio) {
+            (Some(a), Some(b)) => Some(lerp(a, b, t)),
+            _ => step(&a.aspect_ratio, &b.aspect_ratio, t),
+        },
+        overflow: step(&a.overflow, &b.overflow, t),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn lerp_vals() {
+        assert_eq!(lerp_val(Val::Px(0.), Val::Px(10.), 0.5, 0.), Val::Px(5.));
+        assert_eq!(
+            lerp_val(Val::Percent(10.), Val::Percent(30.), 0.5, 0.),
+            Val::Percent(20.)
+        );
+        assert_eq!(
+            lerp_val(Val::Px(0.), Val::Percent(50.), 0.5, 200.),
+            Val::Px(50.)
+        );
+        assert_eq!(lerp_val(Val::Auto, Val::Px(10.), 0.4, 0.), Val::Auto);
+        assert_eq!(lerp_val(Val::Auto, Val::Px(10.), 0.5, 0.), Val::Px(10.));
+        assert_eq!(lerp_val(Val::Px(0.), Val::Px(10.), 1.5, 0.), Val::Px(15.));
+    }
+
+    #[test]
+    fn lerp_style_fields() {
+        let a = style().row().width(Val::Px(0.)).grow(0.);
+        let b = style().column().width(Val::Percent(100.)).grow(2.);
+        let mid = lerp_styles(&a, &b, 0.25, Vec2::new(400., 0.));
+        assert_eq!(mid.flex_direction, FlexDirection::Row);
+        assert_eq!(mid.size.width, Val::Px(100.));
+        assert_eq!(mid.flex_grow, 0.5);
+        let end = lerp_styles(&a, &b, 1., Vec2::new(400., 0.));
+        assert_eq!(end, b);
+    }
+}
@@ -1,16 +1,251 @@
+use bevy::ecs::world::EntityMut;
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use fields::StyleFields;
+use std::borrow::Borrow;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::ops::Div;
 use std::ops::DivAssign;
 use std::ops::Mul;
 use std::ops::MulAssign;
+use std::str::FromStr;
 use thiserror::Error;
 
+pub mod anchor;
+pub mod animation;
+#[cfg(feature = "approx")]
+mod approx_eq;
+pub mod computed;
+pub mod constraint;
+pub mod css;
+pub mod def;
+pub mod easing;
+pub mod export;
+mod fields;
+pub mod hierarchy;
+pub mod interaction;
+pub mod lerp;
+pub mod lint;
+pub mod measure;
+pub mod navigation;
+pub mod prefab;
+pub mod preset;
+pub mod query;
+pub mod reactive;
+pub mod registry;
+pub mod rem;
+pub mod restyle;
+pub mod root;
+pub mod scale;
+pub mod scene;
+pub mod shared;
+pub mod signal;
+pub mod snap;
+pub mod snapshot;
+pub mod spring;
+pub mod stylesheet;
+#[cfg(feature = "taffy")]
+pub mod taffy_style;
+pub mod text;
+pub mod theme;
+pub mod transition;
+pub mod viewport;
+pub mod widgets;
+
 pub mod prelude {
+    pub use crate::anchor::Anchor;
+    pub use crate::anchor::AnchorExt;
+    pub use crate::anchor::Anchored;
+    pub use crate::anchor::AnchoredExt;
+    pub use crate::anchor::StyleAnchorPlugin;
+    pub use crate::animation::Anim;
+    pub use crate::animation::AnimateInExt;
+    pub use crate::animation::HoverGrowExt;
+    pub use crate::animation::SmoothLayout;
+    pub use crate::animation::SmoothLayoutExt;
+    pub use crate::animation::StyleAnimationPlugin;
+    pub use crate::animation::StyleTimeline;
+    pub use crate::animation::TimelineFinished;
+    pub use crate::animation::TimelineRepeat;
+    pub use crate::animation::UiAnimation;
+    pub use crate::animation::UiAnimationCommandsExt;
+    pub use crate::computed::ComputedUi;
+    pub use crate::constraint::MatchSize;
+    pub use crate::constraint::MatchSizeExt;
+    pub use crate::constraint::StyleConstraintPlugin;
+    pub use crate::css::parse_css;
+    pub use crate::css::parse_themed_css;
+    pub use crate::css::style_to_css;
+    pub use crate::def::StyleDef;
+    pub use crate::easing::Ease;
+    pub use crate::export::export_ui_tree;
+    pub use crate::export::UiTreeDescription;
+    pub use crate::hierarchy::ChildShorthandExt;
+    pub use crate::hierarchy::ChildrenFromIterExt;
+    pub use crate::hierarchy::NamedChildBuilder;
+    pub use crate::hierarchy::NamedChildrenExt;
+    pub use crate::hierarchy::SpawnBatchStyledExt;
+    pub use crate::hierarchy::SpawnNodeExt;
+    pub use crate::hierarchy::UiRootExt;
+    pub use crate::interaction::ColorStates;
+    pub use crate::interaction::ColorStatesExt;
+    pub use crate::interaction::Disabled;
+    pub use crate::interaction::DisabledExt;
+    pub use crate::interaction::StyleInteractionPlugin;
+    pub use crate::lerp::lerp_styles;
+    pub use crate::lint::StyleLintPlugin;
+    pub use crate::measure::ContentMeasure;
+    pub use crate::measure::MeasureConstraints;
+    pub use crate::measure::MeasureExt;
+    pub use crate::measure::StyleMeasurePlugin;
+    pub use crate::navigation::Focusable;
+    pub use crate::navigation::FocusableExt;
+    pub use crate::navigation::NavContainer;
+    pub use crate::navigation::NavDirection;
+    pub use crate::navigation::NavigateEvent;
+    pub use crate::navigation::StyleNavigationPlugin;
+    pub use crate::navigation::UiFocus;
     pub use crate::node;
+    pub use crate::parse_val;
+    pub use crate::prefab::PrefabParams;
+    pub use crate::prefab::UiPrefab;
+    pub use crate::prefab::UiPrefabs;
+    pub use crate::preset;
+    pub use crate::presets;
+    pub use crate::query::UiQueryExt;
+    pub use crate::reactive::reactive_list;
+    pub use crate::reactive::ReactiveList;
+    pub use crate::reactive::ReactiveListPlugin;
+    pub use crate::registry::MediaQuery;
+    pub use crate::registry::PseudoClass;
+    pub use crate::registry::StyleClasses;
+    pub use crate::registry::StyleClassesExt;
+    pub use crate::registry::StylePriority;
+    pub use crate::registry::StyleRegistry;
+    pub use crate::registry::StyleRegistryPlugin;
+    pub use crate::registry::StyleScope;
+    pub use crate::registry::StyleSelectorPlugin;
+    pub use crate::rem::RemBase;
+    pub use crate::rem::RemStyle;
+    pub use crate::rem::RemStyleExt;
+    pub use crate::rem::StyleRemPlugin;
+    pub use crate::restyle::RestyleEvent;
+    pub use crate::restyle::RestylePlugin;
+    pub use crate::restyle::StylePatch;
+    pub use crate::root::fullscreen_root;
+    pub use crate::root::letterboxed_root;
+    pub use crate::root::FullscreenRoot;
+    pub use crate::root::Letterbox;
+    pub use crate::root::LetterboxBar;
+    pub use crate::root::StyleRootPlugin;
+    pub use crate::scale::ScaledBundle;
+    pub use crate::scale::ScaledStyle;
+    pub use crate::scale::ScaledStyleExt;
+    pub use crate::scale::StyleScalePlugin;
+    pub use crate::scale::UiStyleScale;
+    pub use crate::scene::StyleReflectPlugin;
+    pub use crate::shared::SharedStyle;
+    pub use crate::shared::SharedStyleExt;
+    pub use crate::shared::StyleOverride;
+    pub use crate::shared::StyleSharedPlugin;
+    pub use crate::signal::Signal;
+    pub use crate::signal::SignalBindingExt;
+    pub use crate::signal::StyleSignalPlugin;
+    pub use crate::snap::SnapToPixels;
+    pub use crate::snap::SnapToPixelsExt;
+    pub use crate::snap::StyleSnapPlugin;
+    pub use crate::snapshot::StyleSnapshot;
+    pub use crate::spring::Spring;
+    pub use crate::spring::SpringPosition;
     pub use crate::style;
+    pub use crate::stylesheet::ScopedStyleSheet;
+    pub use crate::stylesheet::StyleSheet;
+    pub use crate::stylesheet::StyleSheetPlugin;
+    pub use crate::text::FitText;
+    pub use crate::text::FitTextExt;
+    pub use crate::text::StyleTextPlugin;
+    pub use crate::text::TruncateText;
+    pub use crate::text::TruncateTextExt;
+    pub use crate::theme::ThemeTokens;
+    pub use crate::transition::StyleTransition;
+    pub use crate::transition::StyleTransitionExt;
+    pub use crate::transition::TransitionProperty;
+    pub use crate::transition::TransitionTiming;
+    pub use crate::viewport::StyleViewportPlugin;
+    pub use crate::viewport::ViewportLength;
+    pub use crate::viewport::ViewportSize;
+    pub use crate::viewport::ViewportSizeExt;
+    pub use crate::viewport::ViewportUnit;
+    pub use crate::widgets::backdrop::backdrop;
+    pub use crate::widgets::backdrop::backdrop_with_color;
+    pub use crate::widgets::backdrop::Backdrop;
+    pub use crate::widgets::backdrop::BackdropDismissed;
+    pub use crate::widgets::badge::badge;
+    pub use crate::widgets::badge::chip;
+    pub use crate::widgets::badge::pill;
+    pub use crate::widgets::badge::Corner;
+    pub use crate::widgets::badge::CornerExt;
+    pub use crate::widgets::badge::Pill;
+    pub use crate::widgets::badge::PillColors;
+    pub use crate::widgets::breadcrumbs::breadcrumbs;
+    pub use crate::widgets::breadcrumbs::BreadcrumbClicked;
+    pub use crate::widgets::breadcrumbs::Breadcrumbs;
+    pub use crate::widgets::canvas::canvas;
+    pub use crate::widgets::canvas::Canvas;
+    pub use crate::widgets::canvas::CanvasPosition;
+    pub use crate::widgets::canvas::CanvasPositionExt;
+    pub use crate::widgets::collapsible::Collapsible;
+    pub use crate::widgets::collapsible::CollapsibleExt;
+    pub use crate::widgets::collapsible::CollapsibleOptions;
+    pub use crate::widgets::collapsible::CollapsibleStates;
+    pub use crate::widgets::dock::dock_area;
+    pub use crate::widgets::dock::DockArea;
+    pub use crate::widgets::dock::DockLayout;
+    pub use crate::widgets::dock::DockPanel;
+    pub use crate::widgets::dock::DockSplit;
+    pub use crate::widgets::floating_panel::FloatingPanel;
+    pub use crate::widgets::floating_panel::FloatingPanelCommandsExt;
+    pub use crate::widgets::floating_panel::FloatingPanelOptions;
+    pub use crate::widgets::image_fallback::image_with_fallback;
+    pub use crate::widgets::image_fallback::Fallback;
+    pub use crate::widgets::image_fallback::ImageWithFallback;
+    pub use crate::widgets::menu_bar::Menu;
+    pub use crate::widgets::menu_bar::MenuAction;
+    pub use crate::widgets::menu_bar::MenuBar;
+    pub use crate::widgets::menu_bar::MenuBarCommandsExt;
+    pub use crate::widgets::pager::Pager;
+    pub use crate::widgets::pager::PagerExt;
+    pub use crate::widgets::reorderable_list::reorderable_list;
+    pub use crate::widgets::reorderable_list::ReorderEvent;
+    pub use crate::widgets::reorderable_list::ReorderableList;
+    pub use crate::widgets::skeleton::skeleton;
+    pub use crate::widgets::skeleton::Skeleton;
+    pub use crate::widgets::spinner::circular_progress;
+    pub use crate::widgets::spinner::spinner;
+    pub use crate::widgets::spinner::CircularProgress;
+    pub use crate::widgets::stepper::stepper;
+    pub use crate::widgets::stepper::Stepper;
+    pub use crate::widgets::stepper::StepperChanged;
+    pub use crate::widgets::table::table;
+    pub use crate::widgets::table::CellAlign;
+    pub use crate::widgets::table::Table;
+    pub use crate::widgets::table::TableColumn;
+    pub use crate::widgets::table::TableSortRequested;
+    pub use crate::widgets::WidgetsPlugin;
     pub use crate::Breadth;
+    pub use crate::BreadthIteratorExt;
+    pub use crate::InsertExt;
+    pub use crate::InteractiveExt;
+    pub use crate::IntoNumRectLike;
+    pub use crate::IntoUiRectLike;
     pub use crate::NodeColorExt;
+    pub use crate::NodeNameExt;
     pub use crate::NumRect;
+    pub use crate::OrderedBreadth;
     pub use crate::StyleBuilderExt;
 }
 
@@ -22,11 +257,6 @@ pub fn style() -> Style {
     Style::default()
 }
 
-pub enum Either<L, R> {
-    Left(L),
-    Right(R),
-}
-
 /// An enum that describes the possible evaluatable (numeric) values in a flexbox layout.
 /// `Breadth` is used to represent distances from side to side that the UI layout algorithm
 /// cannot infer automatically.
@@ -45,7 +275,7 @@ pub enum Breadth {
 
 impl Default for Breadth {
     fn default() -> Self {
-        Self::Px(0.)
+        Self::ZERO
     }
 }
 
@@ -69,6 +299,29 @@ impl TryFrom<Val> for Breadth {
     }
 }
 
+impl fmt::Display for Breadth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breadth::Px(value) => write!(f, "{value}px"),
+            Breadth::Percent(value) => write!(f, "{value}%"),
+        }
+    }
+}
+
+/// Breadths of the same variant are ordered by value.
+/// Breadths of different variants can't be compared without a size, see [`Breadth::try_cmp_with_size`].
+impl PartialOrd for Breadth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Breadth::Px(value), Breadth::Px(other_value))
+            | (Breadth::Percent(value), Breadth::Percent(other_value)) => {
+                value.partial_cmp(other_value)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Mul<f32> for Breadth {
     type Output = Breadth;
 
@@ -119,7 +372,74 @@ pub enum BreadthConversionError {
     NonEvaluateable,
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum ParseBreadthError {
+    #[error("expected a value such as `10px` or `50%`, found an empty string")]
+    Empty,
+    #[error("`{0}` has no unit, expected `px` or `%`")]
+    MissingUnit(String),
+    #[error("`{value}` has an unknown unit `{unit}`, expected `px` or `%`")]
+    UnknownUnit { value: String, unit: String },
+    #[error("`{0}` does not start with a number")]
+    InvalidNumber(String),
+    #[error("`{0}` is not numeric and can't be used as a Breadth")]
+    NonEvaluateable(String),
+}
+
+impl FromStr for Breadth {
+    type Err = ParseBreadthError;
+
+    /// Parses a number followed by `px` or `%`, such as `"10px"` or `"50%"`.
+    /// A unitless zero is accepted as `0px`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if value.is_empty() {
+            return Err(ParseBreadthError::Empty);
+        }
+        if value.eq_ignore_ascii_case("auto") || value.eq_ignore_ascii_case("undefined") {
+            return Err(ParseBreadthError::NonEvaluateable(value.to_string()));
+        }
+        let split = value
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+            .unwrap_or(value.len());
+        // An exponent marker is only part of the number if digits follow it.
+        let split = value[..split]
+            .rfind(['e', 'E'])
+            .filter(|&e| !value[e + 1..split].chars().any(|c| c.is_ascii_digit()))
+            .unwrap_or(split);
+        let (number, unit) = value.split_at(split);
+        let number = number
+            .parse::<f32>()
+            .map_err(|_| ParseBreadthError::InvalidNumber(value.to_string()))?;
+        match unit.trim().to_ascii_lowercase().as_str() {
+            "px" => Ok(Breadth::Px(number)),
+            "%" => Ok(Breadth::Percent(number)),
+            "" if number == 0. => Ok(Breadth::Px(0.)),
+            "" => Err(ParseBreadthError::MissingUnit(value.to_string())),
+            _ => Err(ParseBreadthError::UnknownUnit {
+                value: value.to_string(),
+                unit: unit.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Parses a [`Val`] from a string such as `"10px"`, `"50%"`, `"auto"` or `"undefined"`.
+pub fn parse_val(s: &str) -> Result<Val, ParseBreadthError> {
+    let value = s.trim();
+    if value.eq_ignore_ascii_case("auto") {
+        Ok(Val::Auto)
+    } else if value.eq_ignore_ascii_case("undefined") {
+        Ok(Val::Undefined)
+    } else {
+        value.parse::<Breadth>().map(Val::from)
+    }
+}
+
 impl Breadth {
+    /// Zero pixels.
+    pub const ZERO: Breadth = Breadth::Px(0.);
+
     /// Tries to add the values of two [`Breadth`]s.
     /// Returns [`BreadthArithmeticError::NonIdenticalVariants`] if two [`Breadth`]s are of different variants.
     pub fn try_add(&self, rhs: Breadth) -> Result<Breadth, BreadthArithmeticError> {
@@ -156,6 +476,28 @@ impl Breadth {
         Ok(())
     }
 
+    /// Rounds the value to the nearest whole number, keeping the variant.
+    pub fn round(&self) -> Breadth {
+        self.map(f32::round)
+    }
+
+    /// Rounds the value down to a whole number, keeping the variant.
+    pub fn floor(&self) -> Breadth {
+        self.map(f32::floor)
+    }
+
+    /// Rounds the value up to a whole number, keeping the variant.
+    pub fn ceil(&self) -> Breadth {
+        self.map(f32::ceil)
+    }
+
+    fn map(&self, f: impl Fn(f32) -> f32) -> Breadth {
+        match *self {
+            Breadth::Px(value) => Breadth::Px(f(value)),
+            Breadth::Percent(value) => Breadth::Percent(f(value)),
+        }
+    }
+
     /// A convenience function for simple evaluation of [`Breadth::Percent`] variant into a concrete [`Breadth::Px`] value.
     /// Otherwise it returns an [`f32`] containing the evaluated value in pixels.
     ///
@@ -167,6 +509,12 @@ impl Breadth {
         }
     }
 
+    /// Compares two [`Breadth`]s of any variants after performing [`Breadth::evaluate`] on both.
+    /// Returns `None` if either value is NaN.
+    pub fn try_cmp_with_size(&self, rhs: Breadth, size: f32) -> Option<Ordering> {
+        self.evaluate(size).partial_cmp(&rhs.evaluate(size))
+    }
+
     /// Similar to [`Breadth::try_add`], but performs [`Breadth::evaluate`] on both values before adding.
     /// Returns an [`f32`] value in pixels.
     pub fn add_with_size(&self, rhs: Breadth, size: f32) -> f32 {
@@ -192,8 +540,91 @@ impl Breadth {
     }
 }
 
+/// A [`Breadth`] that can be hashed and totally ordered, for use as a map key.
+///
+/// Values are compared bit for bit, so `0px` and `-0px` are different keys.
+/// Pixel values are ordered before percentages.
+#[derive(Copy, Clone, Debug)]
+pub struct OrderedBreadth(pub Breadth);
+
+impl OrderedBreadth {
+    fn key(&self) -> (u8, f32) {
+        match self.0 {
+            Breadth::Px(value) => (0, value),
+            Breadth::Percent(value) => (1, value),
+        }
+    }
+}
+
+impl From<Breadth> for OrderedBreadth {
+    fn from(breadth: Breadth) -> Self {
+        Self(breadth)
+    }
+}
+
+impl From<OrderedBreadth> for Breadth {
+    fn from(ordered: OrderedBreadth) -> Self {
+        ordered.0
+    }
+}
+
+impl PartialEq for OrderedBreadth {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedBreadth {}
+
+impl PartialOrd for OrderedBreadth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedBreadth {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (variant, value) = self.key();
+        let (other_variant, other_value) = other.key();
+        variant
+            .cmp(&other_variant)
+            .then_with(|| value.total_cmp(&other_value))
+    }
+}
+
+impl Hash for OrderedBreadth {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (variant, value) = self.key();
+        variant.hash(state);
+        value.to_bits().hash(state);
+    }
+}
+
+pub trait BreadthIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<Breadth>,
+{
+    /// Tries to add up the [`Breadth`]s (see [`Breadth::try_add`]).
+    /// The sum of no breadths is `Breadth::Px(0.)`.
+    fn try_sum(mut self) -> Result<Breadth, BreadthArithmeticError> {
+        let Some(first) = self.next() else {
+            return Ok(Breadth::default());
+        };
+        self.try_fold(*first.borrow(), |sum, breadth| {
+            sum.try_add(*breadth.borrow())
+        })
+    }
+
+    /// Adds up the [`Breadth`]s in pixels, evaluating percentages against `size`.
+    fn sum_with_size(self, size: f32) -> f32 {
+        self.map(|breadth| breadth.borrow().evaluate(size)).sum()
+    }
+}
+
+impl<I: Iterator> BreadthIteratorExt for I where I::Item: Borrow<Breadth> {}
+
 /// A copy of [`UiRect`] but without non-numeric values.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct NumRect {
     pub left: Breadth,
     pub right: Breadth,
@@ -202,7 +633,10 @@ pub struct NumRect {
 }
 
 impl NumRect {
-    pub fn new(left: Breadth, right: Breadth, top: Breadth, bottom: Breadth) -> Self {
+    /// A rect with all edges zero pixels.
+    pub const ZERO: NumRect = NumRect::all(Breadth::ZERO);
+
+    pub const fn new(left: Breadth, right: Breadth, top: Breadth, bottom: Breadth) -> Self {
         NumRect {
             left,
             right,
@@ -211,60 +645,70 @@ impl NumRect {
         }
     }
 
-    pub fn all(value: Breadth) -> Self {
-        NumRect {
-            left: value,
-            right: value,
-            top: value,
-            bottom: value,
-        }
+    pub const fn all(value: Breadth) -> Self {
+        NumRect::new(value, value, value, value)
     }
 
-    pub fn horizontal(value: Breadth) -> Self {
-        NumRect {
-            left: value,
-            right: value,
-            ..Default::default()
-        }
+    pub const fn horizontal(value: Breadth) -> Self {
+        NumRect::new(value, value, Breadth::ZERO, Breadth::ZERO)
     }
 
-    pub fn vertical(value: Breadth) -> Self {
-        NumRect {
-            top: value,
-            bottom: value,
-            ..Default::default()
-        }
+    pub const fn vertical(value: Breadth) -> Self {
+        NumRect::new(Breadth::ZERO, Breadth::ZERO, value, value)
     }
 
-    pub fn left(value: Breadth) -> Self {
-        NumRect {
-            left: value,
-            ..Default::default()
-        }
+    pub const fn left(value: Breadth) -> Self {
+        NumRect::new(value, Breadth::ZERO, Breadth::ZERO, Breadth::ZERO)
     }
 
-    pub fn right(value: Breadth) -> Self {
-        NumRect {
-            right: value,
-            ..Default::default()
-        }
+    pub const fn right(value: Breadth) -> Self {
+        NumRect::new(Breadth::ZERO, value, Breadth::ZERO, Breadth::ZERO)
     }
 
-    pub fn top(value: Breadth) -> Self {
-        NumRect {
-            top: value,
-            ..Default::default()
-        }
+    pub const fn top(value: Breadth) -> Self {
+        NumRect::new(Breadth::ZERO, Breadth::ZERO, value, Breadth::ZERO)
+    }
+
+    pub const fn bottom(value: Breadth) -> Self {
+        NumRect::new(Breadth::ZERO, Breadth::ZERO, Breadth::ZERO, value)
     }
 
-    pub fn bottom(value: Breadth) -> Self {
+    /// Convert a [`UiRect`], replacing any non-evaluatable edges with `default`.
+    pub fn try_from_uirect_or(rect: UiRect, default: Breadth) -> Self {
+        let edge = |val: Val| Breadth::try_from(val).unwrap_or(default);
         NumRect {
-            bottom: value,
-            ..Default::default()
+            left: edge(rect.left),
+            right: edge(rect.right),
+            top: edge(rect.top),
+            bottom: edge(rect.bottom),
         }
     }
 }
 
+impl TryFrom<UiRect> for NumRect {
+    type Error = BreadthConversionError;
+
+    /// Fails if any edge of the rect is [`Val::Auto`] or [`Val::Undefined`].
+    fn try_from(rect: UiRect) -> Result<Self, Self::Error> {
+        Ok(NumRect {
+            left: rect.left.try_into()?,
+            right: rect.right.try_into()?,
+            top: rect.top.try_into()?,
+            bottom: rect.bottom.try_into()?,
+        })
+    }
+}
+
+impl fmt::Display for NumRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(l:{} r:{} t:{} b:{})",
+            self.left, self.right, self.top, self.bottom
+        )
+    }
+}
+
 impl From<NumRect> for UiRect {
     fn from(rect: NumRect) -> Self {
         UiRect {
@@ -276,58 +720,158 @@ impl From<NumRect> for UiRect {
     }
 }
 
-impl From<Breadth> for Either<Breadth, NumRect> {
-    fn from(breadth: Breadth) -> Self {
-        Either::Left(breadth)
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Values accepted by [`StyleBuilderExt::margin`].
+///
+/// A single value sets all four edges, and a `(horizontal, vertical)` pair sets
+/// the left and right edges to the first value and the top and bottom edges to the second.
+pub trait IntoUiRectLike: sealed::Sealed {
+    fn into_ui_rect(self) -> UiRect;
+}
+
+/// Values accepted by [`StyleBuilderExt::border`] and [`StyleBuilderExt::padding`].
+///
+/// A single value sets all four edges, and a `(horizontal, vertical)` pair sets
+/// the left and right edges to the first value and the top and bottom edges to the second.
+pub trait IntoNumRectLike: sealed::Sealed {
+    fn into_num_rect(self) -> NumRect;
+}
+
+impl sealed::Sealed for Val {}
+impl sealed::Sealed for UiRect {}
+impl sealed::Sealed for (Val, Val) {}
+impl sealed::Sealed for Breadth {}
+impl sealed::Sealed for NumRect {}
+impl sealed::Sealed for (Breadth, Breadth) {}
+
+impl IntoUiRectLike for Val {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        UiRect::all(self)
     }
 }
 
-impl From<NumRect> for Either<Breadth, NumRect> {
-    fn from(rect: NumRect) -> Self {
-        Either::Right(rect)
+impl IntoUiRectLike for UiRect {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        self
+    }
+}
+
+impl IntoUiRectLike for (Val, Val) {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        UiRect::new(self.0, self.0, self.1, self.1)
+    }
+}
+
+impl IntoUiRectLike for Breadth {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        UiRect::all(self.into())
     }
 }
 
-impl From<Val> for Either<Val, UiRect> {
-    fn from(val: Val) -> Self {
-        Either::Left(val)
+impl IntoUiRectLike for NumRect {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        self.into()
     }
 }
 
-impl From<UiRect> for Either<Val, UiRect> {
-    fn from(rect: UiRect) -> Self {
-        Either::Right(rect)
+impl IntoUiRectLike for (Breadth, Breadth) {
+    #[inline]
+    fn into_ui_rect(self) -> UiRect {
+        self.into_num_rect().into()
+    }
+}
+
+impl IntoNumRectLike for Breadth {
+    #[inline]
+    fn into_num_rect(self) -> NumRect {
+        NumRect::all(self)
+    }
+}
+
+impl IntoNumRectLike for NumRect {
+    #[inline]
+    fn into_num_rect(self) -> NumRect {
+        self
+    }
+}
+
+impl IntoNumRectLike for (Breadth, Breadth) {
+    #[inline]
+    fn into_num_rect(self) -> NumRect {
+        NumRect::new(self.0, self.0, self.1, self.1)
     }
 }
 
 pub trait StyleBuilderExt: Sized {
     fn update_style(self, s: impl FnOnce(&mut Style)) -> Self;
 
+    /// Replace the whole style of the node.
+    fn style(self, style: Style) -> Self {
+        self.update_style(|current| {
+            *current = style;
+        })
+    }
+
+    /// Replace the whole style of the node with a [`StyleDef`](crate::def::StyleDef) preset.
+    fn preset(self, preset: &def::StyleDef) -> Self {
+        self.update_style(|current| {
+            *current = preset.to_style();
+        })
+    }
+
+    /// Apply CSS declarations such as `"margin: 4px 8px; flex: 1 0 auto;"`,
+    /// see [`parse_css`](crate::css::parse_css) for the supported properties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the declarations can't be parsed. Use [`try_css`](Self::try_css) to handle the error.
+    fn css(self, css: &str) -> Self {
+        match self.try_css(css) {
+            Ok(styled) => styled,
+            Err(error) => panic!("invalid CSS `{css}`: {error}"),
+        }
+    }
+
+    /// Apply CSS declarations such as `"margin: 4px 8px; flex: 1 0 auto;"`,
+    /// or return the error if they can't be parsed.
+    fn try_css(self, css: &str) -> Result<Self, css::ParseCssError> {
+        let patch = css::parse_css(css)?;
+        Ok(self.update_style(|style| patch.apply(style)))
+    }
+
     /// Set the left displacement of the node.
     fn left(self, left: Val) -> Self {
         self.update_style(|style| {
-            style.position.left = left;
+            *style.left_mut() = left;
         })
     }
 
     /// Set the right displacement of the node.
     fn right(self, right: Val) -> Self {
         self.update_style(|style| {
-            style.position.right = right;
+            *style.right_mut() = right;
         })
     }
 
     /// Set the top displacement of the node.
     fn top(self, top: Val) -> Self {
         self.update_style(|style| {
-            style.position.top = top;
+            *style.top_mut() = top;
         })
     }
 
     /// Set the bottom displacement of the node.
     fn bottom(self, bottom: Val) -> Self {
         self.update_style(|style| {
-            style.position.bottom = bottom;
+            *style.bottom_mut() = bottom;
         })
     }
 
@@ -432,74 +976,63 @@ pub trait StyleBuilderExt: Sized {
     /// Set the minimum width of the node.
     fn min_width(self, min_width: Val) -> Self {
         self.update_style(|style| {
-            style.min_size.width = min_width;
+            *style.min_width_mut() = min_width;
         })
     }
 
     /// Set the width of the node.
     fn width(self, width: Val) -> Self {
         self.update_style(|style| {
-            style.size.width = width;
+            *style.width_mut() = width;
         })
     }
 
     /// Set the maximum width of the node.
     fn max_width(self, max_width: Val) -> Self {
         self.update_style(|style| {
-            style.max_size.width = max_width;
+            *style.max_width_mut() = max_width;
         })
     }
 
     /// Set the minimum height of the node.
     fn min_height(self, min_height: Val) -> Self {
         self.update_style(|style| {
-            style.min_size.height = min_height;
+            *style.min_height_mut() = min_height;
         })
     }
 
     /// Set the height of the node.
     fn height(self, height: Val) -> Self {
         self.update_style(|style| {
-            style.size.height = height;
+            *style.height_mut() = height;
         })
     }
 
     /// Set the maximum height of the node.
     fn max_height(self, max_height: Val) -> Self {
         self.update_style(|style| {
-            style.max_size.height = max_height;
+            *style.max_height_mut() = max_height;
         })
     }
 
     /// Set margins for the node.
-    fn margin(self, margin: impl Into<Either<Val, UiRect>>) -> Self {
+    fn margin(self, margin: impl IntoUiRectLike) -> Self {
         self.update_style(|style| {
-            style.margin = match margin.into() {
-                Either::Left(val) => UiRect::all(val),
-                Either::Right(rect) => rect,
-            };
+            style.margin = margin.into_ui_rect();
         })
     }
 
     /// Set border thickness for the node.
-    fn border(self, border: impl Into<Either<Breadth, NumRect>>) -> Self {
+    fn border(self, border: impl IntoNumRectLike) -> Self {
         self.update_style(|style| {
-            style.border = match border.into() {
-                Either::Left(breadth) => NumRect::all(breadth),
-                Either::Right(rect) => rect,
-            }
-            .into();
+            style.border = border.into_num_rect().into();
         })
     }
 
     /// Set padding for the node.
-    fn padding(self, padding: impl Into<Either<Breadth, NumRect>>) -> Self {
+    fn padding(self, padding: impl IntoNumRectLike) -> Self {
         self.update_style(|style| {
-            style.padding = match padding.into() {
-                Either::Left(breadth) => NumRect::all(breadth),
-                Either::Right(rect) => rect,
-            }
-            .into();
+            style.padding = padding.into_num_rect().into();
         })
     }
 
@@ -519,30 +1052,59 @@ pub trait StyleBuilderExt: Sized {
 
     /// The minimum size of the node.
     /// `min_size` overrides the `size` and `max_size` properties.
-    fn min_size(self, size: Size) -> Self {
+    fn min_size(self, size: impl Into<Size>) -> Self {
         self.update_style(|style| {
-            style.min_size = size;
+            style.set_min_size(size.into());
         })
     }
 
     /// Set the size of the node.
-    fn size(self, size: Size) -> Self {
+    /// Accepts either a [`Size`] or a `(width, height)` tuple.
+    fn size(self, size: impl Into<Size>) -> Self {
         self.update_style(|style| {
-            style.size = size;
+            style.set_size(size.into());
         })
     }
 
     /// Set width and height to the same value.
     fn size_all(self, value: Val) -> Self {
         self.update_style(|style| {
-            style.size = Size::new(value, value);
+            style.set_size(Size::new(value, value));
         })
     }
 
     /// The maximum size of the node.
-    fn max_size(self, size: Size) -> Self {
+    fn max_size(self, size: impl Into<Size>) -> Self {
+        self.update_style(|style| {
+            style.set_max_size(size.into());
+        })
+    }
+
+    /// Set the width of the node to `Val::Auto`.
+    fn width_auto(self) -> Self {
+        self.update_style(|style| {
+            *style.width_mut() = Val::Auto;
+        })
+    }
+
+    /// Set the height of the node to `Val::Auto`.
+    fn height_auto(self) -> Self {
+        self.update_style(|style| {
+            *style.height_mut() = Val::Auto;
+        })
+    }
+
+    /// Set both the width and height of the node to `Val::Auto`.
+    fn size_auto(self) -> Self {
         self.update_style(|style| {
-            style.max_size = size;
+            style.set_size(Size::AUTO);
+        })
+    }
+
+    /// Reset both the width and height of the node to `Val::Undefined`.
+    fn size_undefined(self) -> Self {
+        self.update_style(|style| {
+            style.set_size(Size::UNDEFINED);
         })
     }
 
@@ -713,38 +1275,50 @@ pub trait StyleBuilderExt: Sized {
     }
 }
 
-impl StyleBuilderExt for NodeBundle {
-    fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
-        s(&mut self.style);
-        self
-    }
+/// Implements [`StyleBuilderExt`] for bundles that store their style in a `style` field.
+macro_rules! impl_style_builder_for_bundles {
+    ($($bundle:ty),* $(,)?) => {
+        $(
+            impl StyleBuilderExt for $bundle {
+                fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
+                    s(&mut self.style);
+                    self
+                }
+            }
+        )*
+    };
 }
 
-impl StyleBuilderExt for TextBundle {
+impl_style_builder_for_bundles!(NodeBundle, TextBundle, ImageBundle, ButtonBundle);
+
+impl StyleBuilderExt for Style {
     fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
-        s(&mut self.style);
+        s(&mut self);
         self
     }
 }
 
-impl StyleBuilderExt for ImageBundle {
-    fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
-        s(&mut self.style);
+impl StyleBuilderExt for &mut Style {
+    fn update_style(self, s: impl FnOnce(&mut Style)) -> Self {
+        s(self);
         self
     }
 }
 
-impl StyleBuilderExt for Style {
+/// Sets the style of an entity in a [`World`], for use in exclusive systems and tools.
+/// Does nothing if the entity has no [`Style`].
+impl<'w> StyleBuilderExt for EntityMut<'w> {
     fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
-        s(&mut self);
+        if let Some(mut style) = self.get_mut::<Style>() {
+            s(&mut style);
+        }
         self
     }
 }
 
-impl StyleBuilderExt for &mut Style {
+impl<T: StyleBuilderExt, B: Bundle> StyleBuilderExt for (T, B) {
     fn update_style(self, s: impl FnOnce(&mut Style)) -> Self {
-        s(self);
-        self
+        (self.0.update_style(s), self.1)
     }
 }
 
@@ -752,18 +1326,87 @@ pub trait NodeColorExt {
     fn background_color(self, color: Color) -> Self;
 }
 
-impl NodeColorExt for NodeBundle {
-    fn background_color(mut self, color: Color) -> Self {
-        self.background_color = color.into();
-        self
+/// Implements [`NodeColorExt`] for bundles with a `background_color` field.
+macro_rules! impl_node_color_for_bundles {
+    ($($bundle:ty),* $(,)?) => {
+        $(
+            impl NodeColorExt for $bundle {
+                fn background_color(mut self, color: Color) -> Self {
+                    self.background_color = color.into();
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_node_color_for_bundles!(NodeBundle, ImageBundle, ButtonBundle);
+
+impl<T: NodeColorExt, B: Bundle> NodeColorExt for (T, B) {
+    fn background_color(self, color: Color) -> Self {
+        (self.0.background_color(color), self.1)
+    }
+}
+
+pub trait InteractiveExt: Bundle + Sized {
+    /// Make the node hoverable and clickable, blocking interaction with the nodes beneath it.
+    fn interactive(self) -> (Self, Interaction);
+}
+
+impl InteractiveExt for NodeBundle {
+    fn interactive(mut self) -> (Self, Interaction) {
+        self.focus_policy = FocusPolicy::Block;
+        (self, Interaction::default())
+    }
+}
+
+impl InteractiveExt for ImageBundle {
+    fn interactive(mut self) -> (Self, Interaction) {
+        self.focus_policy = FocusPolicy::Block;
+        (self, Interaction::default())
+    }
+}
+
+impl<T: InteractiveExt, B: Bundle> InteractiveExt for (T, B) {
+    fn interactive(self) -> (Self, Interaction) {
+        let (node, interaction) = self.0.interactive();
+        ((node, self.1), interaction)
     }
 }
 
+pub trait NodeNameExt: Bundle + Sized {
+    /// Attach a [`Name`] to the node.
+    /// The name is used by the lint warnings to identify the node.
+    fn named(self, name: impl Into<Cow<'static, str>>) -> (Self, Name) {
+        (self, Name::new(name))
+    }
+}
+
+impl<T: Bundle> NodeNameExt for T {}
+
+pub trait InsertExt: Bundle + Sized {
+    /// Attach a component, such as a marker, to the node.
+    fn insert<C: Component>(self, component: C) -> (Self, C) {
+        (self, component)
+    }
+
+    /// Attach a bundle of components to the node.
+    fn insert_bundle<B: Bundle>(self, bundle: B) -> (Self, B) {
+        (self, bundle)
+    }
+}
+
+impl<T: Bundle> InsertExt for T {}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
     use crate::BreadthArithmeticError;
+    use crate::BreadthConversionError;
+    use crate::OrderedBreadth;
+    use crate::ParseBreadthError;
     use bevy::prelude::*;
+    use bevy::ui::FocusPolicy;
 
     #[test]
     fn test_breadth() {
@@ -922,4 +1565,326 @@ mod tests {
         let node = node().left(value);
         assert_eq!(node.style.position.left, value);
     }
+
+    #[test]
+    fn size_auto_and_undefined() {
+        let node = node().size_all(Val::Px(10.)).width_auto();
+        assert_eq!(node.style.size, Size::new(Val::Auto, Val::Px(10.)));
+        let node = node.height_auto();
+        assert_eq!(node.style.size, Size::AUTO);
+        let node = node.size_undefined();
+        assert_eq!(node.style.size, Size::UNDEFINED);
+        assert_eq!(style().size_auto().size, Size::AUTO);
+    }
+
+    #[test]
+    fn size_from_tuple() {
+        let node = node()
+            .size((Val::Px(1.), Val::Percent(2.)))
+            .min_size((Val::Px(3.), Val::Auto))
+            .max_size(Size::UNDEFINED);
+        assert_eq!(node.style.size, Size::new(Val::Px(1.), Val::Percent(2.)));
+        assert_eq!(node.style.min_size, Size::new(Val::Px(3.), Val::Auto));
+        assert_eq!(node.style.max_size, Size::UNDEFINED);
+    }
+
+    #[test]
+    fn named_node_keeps_chaining() {
+        let (node, name) = node()
+            .named("sidebar")
+            .width(Val::Px(5.))
+            .background_color(Color::RED);
+        assert_eq!(name.as_str(), "sidebar");
+        assert_eq!(node.style.size.width, Val::Px(5.));
+        assert_eq!(node.background_color.0, Color::RED);
+    }
+
+    #[derive(Component)]
+    struct ScrollingList;
+
+    #[test]
+    fn test_insert() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(
+                node()
+                    .insert(ScrollingList)
+                    .insert_bundle((Interaction::default(), Name::new("list")))
+                    .column()
+                    .width(Val::Px(200.)),
+            )
+            .id();
+        assert!(world.get::<ScrollingList>(entity).is_some());
+        assert!(world.get::<Interaction>(entity).is_some());
+        let style = world.get::<Style>(entity).unwrap();
+        assert_eq!(style.flex_direction, FlexDirection::Column);
+        assert_eq!(style.size.width, Val::Px(200.));
+    }
+
+    #[test]
+    fn test_interactive() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(
+                node()
+                    .background_color(Color::RED)
+                    .width(Val::Px(50.))
+                    .named("hoverable")
+                    .interactive(),
+            )
+            .id();
+        assert_eq!(
+            *world.get::<Interaction>(entity).unwrap(),
+            Interaction::None
+        );
+        assert_eq!(
+            *world.get::<FocusPolicy>(entity).unwrap(),
+            FocusPolicy::Block
+        );
+        assert_eq!(world.get::<Style>(entity).unwrap().size.width, Val::Px(50.));
+    }
+
+    #[test]
+    fn test_parse_breadth() {
+        assert_eq!("10px".parse(), Ok(Breadth::Px(10.)));
+        assert_eq!(" 50% ".parse(), Ok(Breadth::Percent(50.)));
+        assert_eq!("-2.5 PX".parse(), Ok(Breadth::Px(-2.5)));
+        assert_eq!("1e2px".parse(), Ok(Breadth::Px(100.)));
+        assert_eq!("0".parse(), Ok(Breadth::Px(0.)));
+        assert_eq!("".parse::<Breadth>(), Err(ParseBreadthError::Empty));
+        assert_eq!(
+            "10".parse::<Breadth>(),
+            Err(ParseBreadthError::MissingUnit("10".to_string()))
+        );
+        assert_eq!(
+            "10em".parse::<Breadth>(),
+            Err(ParseBreadthError::UnknownUnit {
+                value: "10em".to_string(),
+                unit: "em".to_string()
+            })
+        );
+        assert_eq!(
+            "px".parse::<Breadth>(),
+            Err(ParseBreadthError::InvalidNumber("px".to_string()))
+        );
+        assert_eq!(
+            "auto".parse::<Breadth>(),
+            Err(ParseBreadthError::NonEvaluateable("auto".to_string()))
+        );
+        assert_eq!(
+            "10em".parse::<Breadth>().unwrap_err().to_string(),
+            "`10em` has an unknown unit `em`, expected `px` or `%`"
+        );
+    }
+
+    #[test]
+    fn test_parse_val() {
+        assert_eq!(parse_val("auto"), Ok(Val::Auto));
+        assert_eq!(parse_val("Undefined"), Ok(Val::Undefined));
+        assert_eq!(parse_val("25%"), Ok(Val::Percent(25.)));
+        assert!(parse_val("wide").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Breadth::Px(10.).to_string(), "10px");
+        assert_eq!(Breadth::Percent(12.5).to_string(), "12.5%");
+        assert_eq!(
+            NumRect::new(
+                Breadth::Px(4.),
+                Breadth::Px(4.),
+                Breadth::Px(2.),
+                Breadth::Percent(5.)
+            )
+            .to_string(),
+            "(l:4px r:4px t:2px b:5%)"
+        );
+        let breadth = Breadth::Percent(-3.);
+        assert_eq!(breadth.to_string().parse(), Ok(breadth));
+    }
+
+    #[test]
+    fn test_num_rect_from_ui_rect() {
+        let rect = UiRect::new(Val::Px(1.), Val::Percent(2.), Val::Px(3.), Val::Px(4.));
+        let num_rect = NumRect::try_from(rect).unwrap();
+        assert_eq!(UiRect::from(num_rect), rect);
+        let rect = UiRect::new(Val::Px(1.), Val::Auto, Val::Undefined, Val::Px(4.));
+        assert_eq!(
+            NumRect::try_from(rect).unwrap_err(),
+            BreadthConversionError::NonEvaluateable
+        );
+        let num_rect = NumRect::try_from_uirect_or(rect, Breadth::Px(0.));
+        assert_eq!(
+            UiRect::from(num_rect),
+            UiRect::new(Val::Px(1.), Val::Px(0.), Val::Px(0.), Val::Px(4.))
+        );
+    }
+
+    #[test]
+    fn test_breadth_sums() {
+        let heights = [Breadth::Px(10.), Breadth::Px(20.), Breadth::Px(5.)];
+        assert_eq!(heights.iter().try_sum(), Ok(Breadth::Px(35.)));
+        assert_eq!(
+            [Breadth::Percent(10.), Breadth::Percent(15.)]
+                .into_iter()
+                .try_sum(),
+            Ok(Breadth::Percent(25.))
+        );
+        assert_eq!(std::iter::empty::<Breadth>().try_sum(), Ok(Breadth::Px(0.)));
+        let mixed = [Breadth::Px(10.), Breadth::Percent(50.)];
+        assert_eq!(
+            mixed.iter().try_sum(),
+            Err(BreadthArithmeticError::NonIdenticalVariants)
+        );
+        assert_eq!(mixed.iter().sum_with_size(200.), 110.);
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        const PANEL_PADDING: NumRect = NumRect::horizontal(Breadth::Px(8.));
+        static GUTTER: NumRect = NumRect::all(Breadth::Percent(2.));
+        assert_eq!(
+            UiRect::from(PANEL_PADDING),
+            UiRect::new(Val::Px(8.), Val::Px(8.), Val::Px(0.), Val::Px(0.))
+        );
+        assert_eq!(GUTTER.bottom, Breadth::Percent(2.));
+        assert_eq!(Breadth::ZERO, Breadth::default());
+        assert_eq!(
+            UiRect::from(NumRect::ZERO),
+            UiRect::from(NumRect::default())
+        );
+    }
+
+    #[test]
+    fn test_ordered_breadth() {
+        let mut spacing = std::collections::HashMap::new();
+        spacing.insert(OrderedBreadth(Breadth::Px(4.)), "small");
+        spacing.insert(OrderedBreadth(Breadth::Percent(4.)), "relative");
+        assert_eq!(spacing[&Breadth::Px(4.).into()], "small");
+        assert_eq!(spacing[&Breadth::Percent(4.).into()], "relative");
+        let sorted = [Breadth::Percent(1.), Breadth::Px(8.), Breadth::Px(-2.)]
+            .into_iter()
+            .map(OrderedBreadth)
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .map(Breadth::from)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sorted,
+            vec![Breadth::Px(-2.), Breadth::Px(8.), Breadth::Percent(1.)]
+        );
+        assert_ne!(
+            OrderedBreadth(Breadth::Px(0.)),
+            OrderedBreadth(Breadth::Px(-0.))
+        );
+    }
+
+    #[test]
+    fn test_breadth_ordering() {
+        assert!(Breadth::Px(4.) < Breadth::Px(8.));
+        assert!(Breadth::Percent(50.) >= Breadth::Percent(50.));
+        assert_eq!(Breadth::Px(4.).partial_cmp(&Breadth::Percent(4.)), None);
+        assert!(!Breadth::Px(4.).lt(&Breadth::Percent(50.)));
+        assert!(!Breadth::Px(4.).ge(&Breadth::Percent(50.)));
+        assert_eq!(
+            Breadth::Px(40.).try_cmp_with_size(Breadth::Percent(50.), 100.),
+            Some(std::cmp::Ordering::Less)
+        );
+        assert_eq!(
+            Breadth::Px(f32::NAN).try_cmp_with_size(Breadth::Px(0.), 100.),
+            None
+        );
+        let mut paddings = vec![Breadth::Px(8.), Breadth::Px(2.), Breadth::Px(4.)];
+        paddings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            paddings,
+            vec![Breadth::Px(2.), Breadth::Px(4.), Breadth::Px(8.)]
+        );
+    }
+
+    #[test]
+    fn test_breadth_rounding() {
+        assert_eq!(Breadth::Px(1.5).round(), Breadth::Px(2.));
+        assert_eq!(Breadth::Px(1.7).floor(), Breadth::Px(1.));
+        assert_eq!(Breadth::Percent(33.3).ceil(), Breadth::Percent(34.));
+    }
+
+    #[test]
+    fn test_rect_like_setters() {
+        let style = style()
+            .margin((Val::Px(1.), Val::Auto))
+            .padding(Breadth::Percent(2.))
+            .border((Breadth::Px(3.), Breadth::Px(4.)));
+        assert_eq!(
+            style.margin,
+            UiRect::new(Val::Px(1.), Val::Px(1.), Val::Auto, Val::Auto)
+        );
+        assert_eq!(style.padding, UiRect::all(Val::Percent(2.)));
+        assert_eq!(
+            style.border,
+            UiRect::new(Val::Px(3.), Val::Px(3.), Val::Px(4.), Val::Px(4.))
+        );
+        let style = crate::style().margin(NumRect::left(Breadth::Px(5.)));
+        assert_eq!(style.margin.left, Val::Px(5.));
+        assert_eq!(style.margin.right, Val::Px(0.));
+    }
+
+    #[test]
+    fn test_bundle_builders() {
+        fn check(style: &Style) {
+            assert_eq!(style.size.width, Val::Px(7.));
+            assert_eq!(style.flex_direction, FlexDirection::Column);
+        }
+        check(&node().width(Val::Px(7.)).column().style);
+        check(&ImageBundle::default().width(Val::Px(7.)).column().style);
+        check(&ButtonBundle::default().width(Val::Px(7.)).column().style);
+        check(
+            &TextBundle::from_section("", TextStyle::default())
+                .width(Val::Px(7.))
+                .column()
+                .style,
+        );
+    }
+
+    #[test]
+    fn test_entity_mut_builder() {
+        let mut world = World::new();
+        let entity = world.spawn(node()).id();
+        world
+            .entity_mut(entity)
+            .width(Val::Px(3.))
+            .padding(Breadth::Px(1.));
+        let style = world.get::<Style>(entity).unwrap();
+        assert_eq!(style.size.width, Val::Px(3.));
+        assert_eq!(style.padding, UiRect::all(Val::Px(1.)));
+        let empty = world.spawn_empty().id();
+        world.entity_mut(empty).width(Val::Px(3.));
+        assert!(world.get::<Style>(empty).is_none());
+    }
+
+    #[test]
+    fn test_builder_parity() {
+        fn styled<T: StyleBuilderExt>(target: T) -> T {
+            target.style(style().width(Val::Px(9.)).no_wrap())
+        }
+        fn colored<T>(target: T) -> crate::scale::ScaledBundle<T>
+        where
+            T: StyleBuilderExt + NodeColorExt + crate::scale::ScaledStyleExt,
+        {
+            styled(target).background_color(Color::RED).scaled()
+        }
+        colored(node());
+        colored(ImageBundle::default());
+        colored(ButtonBundle::default());
+        colored((node(), Name::new("tuple")));
+        let text = styled(TextBundle::from_section("", TextStyle::default()));
+        assert_eq!(text.style.size.width, Val::Px(9.));
+        assert_eq!(text.style.flex_wrap, FlexWrap::NoWrap);
+        let button = styled(ButtonBundle::default()).background_color(Color::RED);
+        assert_eq!(button.style.size.width, Val::Px(9.));
+        assert_eq!(button.background_color.0, Color::RED);
+        let mut style = style();
+        styled(&mut style);
+        assert_eq!(style.size.width, Val::Px(9.));
+    }
 }
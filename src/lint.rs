@@ -0,0 +1,227 @@
+use crate::fields::StyleFields;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::utils::HashSet;
+use thiserror::Error;
+
+/// A contradiction or likely mistake found in a node's [`Style`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Error)]
+pub enum StyleLint {
+    #[error("min_size.width is greater than max_size.width")]
+    MinWidthGreaterThanMax,
+    #[error("min_size.height is greater than max_size.height")]
+    MinHeightGreaterThanMax,
+    #[error("percentage padding but the parent's width is not defined")]
+    PercentPaddingWithUndefinedParent,
+    #[error("flex_grow with a zero flex_basis inside a clipped, non-wrapping parent")]
+    GrowWithZeroBasisInNoWrap,
+    #[error("leaf node with no defined size and no content will not be visible")]
+    EmptyLeaf,
+}
+
+fn exceeds(min: Val, max: Val) -> bool {
+    match (min, max) {
+        (Val::Px(min), Val::Px(max)) | (Val::Percent(min), Val::Percent(max)) => max < min,
+        _ => false,
+    }
+}
+
+fn is_definite(val: Val) -> bool {
+    matches!(val, Val::Px(_) | Val::Percent(_))
+}
+
+fn is_zero(val: Val) -> bool {
+    matches!(val, Val::Px(value) | Val::Percent(value) if value == 0.)
+}
+
+/// Check a style for contradictions, optionally against the style of its parent node.
+pub fn lint_style(style: &Style, parent: Option<&Style>) -> Vec<StyleLint> {
+    let mut lints = Vec::new();
+    if exceeds(style.min_width_val(), style.max_width_val()) {
+        lints.push(StyleLint::MinWidthGreaterThanMax);
+    }
+    if exceeds(style.min_height_val(), style.max_height_val()) {
+        lints.push(StyleLint::MinHeightGreaterThanMax);
+    }
+    if let Some(parent) = parent {
+        let padding = style.padding;
+        let percent_padding = [padding.left, padding.right, padding.top, padding.bottom]
+            .into_iter()
+            .any(|val| matches!(val, Val::Percent(_)));
+        if percent_padding && !is_definite(parent.width_val()) {
+            lints.push(StyleLint::PercentPaddingWithUndefinedParent);
+        }
+        if 0. < style.flex_grow
+            && is_zero(style.flex_basis)
+            && parent.flex_wrap == FlexWrap::NoWrap
+            && parent.overflow == Overflow::Hidden
+        {
+            lints.push(StyleLint::GrowWithZeroBasisInNoWrap);
+        }
+    }
+    lints
+}
+
+/// Returns true if a node without children or content would be laid out with zero size.
+///
+/// Padding, borders, and opposing insets of an absolutely positioned node
+/// all give the node a size, as do its size constraints.
+pub fn is_empty_leaf(style: &Style) -> bool {
+    let edges = |rect: UiRect| [rect.left, rect.right, rect.top, rect.bottom];
+    let sized_by_edges = edges(style.padding)
+        .into_iter()
+        .chain(edges(style.border))
+        .any(|val| is_definite(val) && !is_zero(val));
+    let sized_by_insets = style.position_type == PositionType::Absolute
+        && (is_definite(style.left_val()) && is_definite(style.right_val())
+            || is_definite(style.top_val()) && is_definite(style.bottom_val()));
+    style.display != Display::None
+        && style.flex_grow == 0.
+        && !is_definite(style.flex_basis)
+        && !sized_by_edges
+        && !sized_by_insets
+        && [
+            style.width_val(),
+            style.height_val(),
+            style.min_width_val(),
+            style.min_height_val(),
+        ]
+        .into_iter()
+        .all(|val| !is_definite(val))
+}
+
+fn warn_lint(entity: Entity, name: Option<&Name>, lint: StyleLint) {
+    match name {
+        Some(name) => warn!("UI node \"{name}\" ({entity:?}): {lint}"),
+        None => warn!("UI node {entity:?}: {lint}"),
+    }
+}
+
+/// Logs a warning for every [`StyleLint`] found in newly added or changed styles.
+///
+/// Each lint is logged once per node while it keeps applying, so a style that is
+/// animated every frame doesn't repeat the warning. It is logged again if it's fixed and then returns.
+#[allow(clippy::type_complexity)]
+pub fn lint_styles_system(
+    mut warned: Local<HashMap<Entity, Vec<StyleLint>>>,
+    changed_query: Query<(Entity, &Style, Option<&Parent>, Option<&Name>), Changed<Style>>,
+    style_query: Query<&Style>,
+    removed: RemovedComponents<Style>,
+) {
+    for entity in removed.iter() {
+        warned.remove(&entity);
+    }
+    for (entity, style, parent, name) in changed_query.iter() {
+        let parent_style = parent.and_then(|parent| style_query.get(parent.get()).ok());
+        let lints = lint_style(style, parent_style);
+        let previous = warned.remove(&entity).unwrap_or_default();
+        for &lint in lints.iter().filter(|lint| !previous.contains(lint)) {
+            warn_lint(entity, name, lint);
+        }
+        if !lints.is_empty() {
+            warned.insert(entity, lints);
+        }
+    }
+}
+
+/// Logs a warning for leaf nodes without content that would be laid out with zero size,
+/// once each time a node becomes empty, so a leaf whose style is animated doesn't warn every frame.
+#[allow(clippy::type_complexity)]
+pub fn warn_empty_leaves_system(
+    mut warned: Local<HashSet<Entity>>,
+    leaf_query: Query<
+        (Entity, &Style, Option<&Name>),
+        (
+            Changed<Style>,
+            With<Node>,
+            Without<Children>,
+            Without<CalculatedSize>,
+        ),
+    >,
+    removed: RemovedComponents<Style>,
+) {
+    for entity in removed.iter() {
+        warned.remove(&entity);
+    }
+    for (entity, style, name) in leaf_query.iter() {
+        if !is_empty_leaf(style) {
+            warned.remove(&entity);
+        } else if warned.insert(entity) {
+            warn_lint(entity, name, StyleLint::EmptyLeaf);
+        }
+    }
+}
+
+/// Opt-in plugin that checks UI styles for contradictions and logs warnings.
+///
+/// In debug builds it also warns about empty zero-sized leaf nodes.
+pub struct StyleLintPlugin;
+
+impl Plugin for StyleLintPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(CoreStage::PostUpdate, lint_styles_system);
+        #[cfg(debug_assertions)]
+        app.add_system_to_stage(CoreStage::PostUpdate, warn_empty_leaves_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn min_greater_than_max() {
+        let style = style()
+            .min_width(Val::Px(20.))
+            .max_width(Val::Px(10.))
+            .min_height(Val::Percent(10.))
+            .max_height(Val::Px(5.));
+        assert_eq!(
+            lint_style(&style, None),
+            vec![StyleLint::MinWidthGreaterThanMax]
+        );
+    }
+
+    #[test]
+    fn percent_padding_and_zero_basis() {
+        let parent = style().no_wrap().hide_overflow();
+        let child = style()
+            .padding(Breadth::Percent(5.))
+            .grow(1.)
+            .basis(Val::Px(0.));
+        assert_eq!(
+            lint_style(&child, Some(&parent)),
+            vec![
+                StyleLint::PercentPaddingWithUndefinedParent,
+                StyleLint::GrowWithZeroBasisInNoWrap
+            ]
+        );
+        let parent = parent.width(Val::Px(100.)).wrap();
+        assert!(lint_style(&child, Some(&parent)).is_empty());
+    }
+
+    #[test]
+    fn empty_leaf() {
+        assert!(is_empty_leaf(&style()));
+        assert!(is_empty_leaf(&style().size_auto()));
+        assert!(!is_empty_leaf(&style().width(Val::Px(10.))));
+        assert!(!is_empty_leaf(&style().min_height(Val::Percent(10.))));
+        assert!(!is_empty_leaf(&style().grow(1.)));
+        assert!(!is_empty_leaf(&style().disable()));
+        assert!(!is_empty_leaf(&style().padding(Breadth::Px(4.))));
+        assert!(!is_empty_leaf(&style().border(Breadth::Px(1.))));
+        assert!(is_empty_leaf(&style().padding(Breadth::Px(0.))));
+        assert!(!is_empty_leaf(
+            &style().absolute().left(Val::Px(0.)).right(Val::Px(0.))
+        ));
+        assert!(!is_empty_leaf(
+            &style()
+                .absolute()
+                .top(Val::Px(0.))
+                .bottom(Val::Percent(10.))
+        ));
+        assert!(is_empty_leaf(&style().left(Val::Px(0.)).right(Val::Px(0.))));
+        assert!(is_empty_leaf(&style().absolute().left(Val::Px(0.))));
+    }
+}
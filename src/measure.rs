@@ -0,0 +1,185 @@
+use crate::fields::StyleFields;
+use crate::scale::ScaledBundle;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use std::sync::Arc;
+
+/// The definite width and height of a node, in pixels, available when measuring its content.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MeasureConstraints {
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+}
+
+impl MeasureConstraints {
+    /// The constraints set by the pixel sizes of `style`.
+    pub fn from_style(style: &Style) -> Self {
+        let px = |val: Val| match val {
+            Val::Px(value) => Some(value),
+            _ => None,
+        };
+        Self {
+            width: px(style.width_val()),
+            height: px(style.height_val()),
+        }
+    }
+}
+
+/// Measures the natural size of a node's content, such as a canvas or chart drawn by the user.
+///
+/// The measured size is written to the node's [`CalculatedSize`], which the layout uses as the
+/// size of the node's content. Bevy's layout keeps the aspect ratio of the measured size when
+/// only one of the width and height is constrained.
+/// The content is measured again whenever the node's [`Style`] or its `ContentMeasure` changes.
+#[derive(Component, Clone)]
+pub struct ContentMeasure(Arc<dyn Fn(MeasureConstraints) -> Vec2 + Send + Sync>);
+
+impl ContentMeasure {
+    pub fn new(measure: impl Fn(MeasureConstraints) -> Vec2 + Send + Sync + 'static) -> Self {
+        Self(Arc::new(measure))
+    }
+
+    /// The size of the content within `constraints`.
+    pub fn measure(&self, constraints: MeasureConstraints) -> Vec2 {
+        (self.0)(constraints)
+    }
+}
+
+impl std::fmt::Debug for ContentMeasure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentMeasure").finish_non_exhaustive()
+    }
+}
+
+pub trait MeasureExt: Bundle + Sized {
+    /// Size the node's content with `measure`.
+    fn measure(
+        self,
+        measure: impl Fn(MeasureConstraints) -> Vec2 + Send + Sync + 'static,
+    ) -> (Self, ContentMeasure, CalculatedSize);
+
+    /// Lay out the node as if its content was `width` by `height` pixels,
+    /// for example as a placeholder for an image that is still loading.
+    fn intrinsic_size(self, width: f32, height: f32) -> (Self, CalculatedSize);
+}
+
+fn calculated_size(width: f32, height: f32) -> CalculatedSize {
+    CalculatedSize {
+        size: Size::new(Val::Px(width), Val::Px(height)),
+    }
+}
+
+impl MeasureExt for NodeBundle {
+    fn measure(
+        self,
+        measure: impl Fn(MeasureConstraints) -> Vec2 + Send + Sync + 'static,
+    ) -> (Self, ContentMeasure, CalculatedSize) {
+        (
+            self,
+            ContentMeasure::new(measure),
+            CalculatedSize::default(),
+        )
+    }
+
+    fn intrinsic_size(self, width: f32, height: f32) -> (Self, CalculatedSize) {
+        (self, calculated_size(width, height))
+    }
+}
+
+impl<T: MeasureExt, B: Bundle> MeasureExt for (T, B) {
+    fn measure(
+        self,
+        measure: impl Fn(MeasureConstraints) -> Vec2 + Send + Sync + 'static,
+    ) -> (Self, ContentMeasure, CalculatedSize) {
+        let (node, measure, size) = self.0.measure(measure);
+        ((node, self.1), measure, size)
+    }
+
+    fn intrinsic_size(self, width: f32, height: f32) -> (Self, CalculatedSize) {
+        let (node, size) = self.0.intrinsic_size(width, height);
+        ((node, self.1), size)
+    }
+}
+
+impl<T: MeasureExt> MeasureExt for ScaledBundle<T> {
+    fn measure(
+        self,
+        measure: impl Fn(MeasureConstraints) -> Vec2 + Send + Sync + 'static,
+    ) -> (Self, ContentMeasure, CalculatedSize) {
+        let (node, measure, size) = self.node.measure(measure);
+        let authored = self.authored;
+        (Self { node, authored }, measure, size)
+    }
+
+    fn intrinsic_size(self, width: f32, height: f32) -> (Self, CalculatedSize) {
+        let (node, size) = self.node.intrinsic_size(width, height);
+        let authored = self.authored;
+        (Self { node, authored }, size)
+    }
+}
+
+/// Measures the content of each node with a new or changed [`ContentMeasure`] or [`Style`].
+#[allow(clippy::type_complexity)]
+pub fn content_measure_system(
+    mut query: Query<
+        (&ContentMeasure, &Style, &mut CalculatedSize),
+        Or<(Changed<ContentMeasure>, Changed<Style>)>,
+    >,
+) {
+    for (measure, style, mut calculated_size) in query.iter_mut() {
+        let measured = measure.measure(MeasureConstraints::from_style(style));
+        let size = Size::new(Val::Px(measured.x), Val::Px(measured.y));
+        if calculated_size.size != size {
+            calculated_size.size = size;
+        }
+    }
+}
+
+/// Adds the system that measures node content.
+pub struct StyleMeasurePlugin;
+
+impl Plugin for StyleMeasurePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            content_measure_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn content_is_measured_within_the_style() {
+        let mut app = App::new();
+        app.add_plugin(StyleMeasurePlugin);
+        let chart = app
+            .world
+            .spawn(node().width(Val::Px(300.)).measure(|constraints| {
+                let width = constraints.width.unwrap_or(100.);
+                Vec2::new(width, width / 2.)
+            }))
+            .id();
+        app.update();
+        let size = |app: &App| app.world.get::<CalculatedSize>(chart).unwrap().size;
+        assert_eq!(size(&app), Size::new(Val::Px(300.), Val::Px(150.)));
+        app.world.get_mut::<Style>(chart).unwrap().size.width = Val::Auto;
+        app.update();
+        assert_eq!(size(&app), Size::new(Val::Px(100.), Val::Px(50.)));
+    }
+
+    #[test]
+    fn intrinsic_size_sets_the_content_size() {
+        let mut world = World::new();
+        let placeholder = world
+            .spawn(node().named("cover").intrinsic_size(320., 180.))
+            .id();
+        assert_eq!(
+            world.get::<CalculatedSize>(placeholder).unwrap().size,
+            Size::new(Val::Px(320.), Val::Px(180.))
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::interaction::Disabled;
+use crate::widgets::node_rect;
+use bevy::prelude::*;
+
+/// A direction to move the focus in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NavDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl NavDirection {
+    /// The direction as a unit vector in UI coordinates, with y increasing downwards.
+    pub fn vector(self) -> Vec2 {
+        match self {
+            NavDirection::Up => Vec2::NEG_Y,
+            NavDirection::Down => Vec2::Y,
+            NavDirection::Left => Vec2::NEG_X,
+            NavDirection::Right => Vec2::X,
+        }
+    }
+}
+
+/// Moves the focus to the nearest [`Focusable`] node in a direction.
+///
+/// Sent by the gamepad's D-pad, and can be sent by any other input source.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NavigateEvent(pub NavDirection);
+
+/// A node that can receive the focus.
+#[derive(Component, Copy, Clone, Debug, Default)]
+pub struct Focusable;
+
+/// Limits navigation from the focusable nodes inside a node.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavContainer {
+    /// Keep the focus among the container's descendants.
+    pub contain: bool,
+    /// Past the last node in a direction, move to the furthest node in the opposite direction.
+    pub wrap: bool,
+}
+
+impl NavContainer {
+    pub fn contained(mut self) -> Self {
+        self.contain = true;
+        self
+    }
+
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+}
+
+/// The focused node.
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UiFocus(pub Option<Entity>);
+
+pub trait FocusableExt: Bundle + Sized {
+    /// Let the node receive the focus.
+    fn focusable(self) -> (Self, Focusable) {
+        (self, Focusable)
+    }
+}
+
+impl<T: Bundle> FocusableExt for T {}
+
+/// Sends a [`NavigateEvent`] for each D-pad button pressed on any gamepad.
+pub fn gamepad_navigation_system(
+    buttons: Res<Input<GamepadButton>>,
+    mut events: EventWriter<NavigateEvent>,
+) {
+    for button in buttons.get_just_pressed() {
+        let direction = match button.button_type {
+            GamepadButtonType::DPadUp => NavDirection::Up,
+            GamepadButtonType::DPadDown => NavDirection::Down,
+            GamepadButtonType::DPadLeft => NavDirection::Left,
+            GamepadButtonType::DPadRight => NavDirection::Right,
+            _ => continue,
+        };
+        events.send(NavigateEvent(direction));
+    }
+}
+
+/// The nearest [`NavContainer`] enclosing `entity`, and the container's entity.
+fn nav_container(
+    entity: Entity,
+    containers: &Query<&NavContainer>,
+    parents: &Query<&Parent>,
+) -> Option<(Entity, NavContainer)> {
+    let mut current = entity;
+    while let Ok(parent) = parents.get(current) {
+        current = parent.get();
+        if let Ok(container) = containers.get(current) {
+            return Some((current, *container));
+        }
+    }
+    None
+}
+
+/// Whether `entity` or any of its ancestors is [`Disabled`].
+fn is_disabled(
+    entity: Entity,
+    disabled: &Query<(), With<Disabled>>,
+    parents: &Query<&Parent>,
+) -> bool {
+    let mut current = entity;
+    loop {
+        if disabled.contains(current) {
+            return true;
+        }
+        let Ok(parent) = parents.get(current) else {
+            return false;
+        };
+        current = parent.get();
+    }
+}
+
+fn is_descendant(entity: Entity, ancestor: Entity, parents: &Query<&Parent>) -> bool {
+    let mut current = entity;
+    while let Ok(parent) = parents.get(current) {
+        current = parent.get();
+        if current == ancestor {
+            return true;
+        }
+    }
+    false
+}
+
+/// The node to move to from `from` in `direction`, scored by the distance along the direction
+/// plus twice the distance across it, so nodes in line with `from` are preferred.
+fn nearest(
+    from: Vec2,
+    direction: Vec2,
+    wrap: bool,
+    candidates: &[(Entity, Vec2)],
+) -> Option<Entity> {
+    let score = |center: Vec2| {
+        let offset = center - from;
+        let along = offset.dot(direction);
+        (along, along + 2. * offset.perp_dot(direction).abs())
+    };
+    let best = |ahead: bool| {
+        candidates
+            .iter()
+            .map(|&(entity, center)| (entity, score(center)))
+            .filter(|(_, (along, _))| if ahead { 0. < *along } else { *along < 0. })
+            .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+            .map(|(entity, _)| entity)
+    };
+    best(true).or_else(|| wrap.then(|| best(false)).flatten())
+}
+
+/// Moves the [`UiFocus`] for each [`NavigateEvent`] to the nearest [`Focusable`] node
+/// in the event's direction, using the nodes' positions from the last layout.
+///
+/// Zero-sized nodes, and nodes that are disabled or inside a disabled node, are skipped.
+/// If nothing is focused, the top left focusable node is focused.
+pub fn spatial_navigation_system(
+    mut events: EventReader<NavigateEvent>,
+    mut focus: ResMut<UiFocus>,
+    focusables: Query<(Entity, &Node, &GlobalTransform), With<Focusable>>,
+    disabled: Query<(), With<Disabled>>,
+    containers: Query<&NavContainer>,
+    parents: Query<&Parent>,
+) {
+    for NavigateEvent(direction) in events.iter() {
+        let nodes = focusables
+            .iter()
+            .filter(|(_, node, _)| node.size() != Vec2::ZERO)
+            .filter(|(entity, _, _)| !is_disabled(*entity, &disabled, &parents))
+            .map(|(entity, node, transform)| (entity, node_rect(node, transform).center()))
+            .collect::<Vec<_>>();
+        let current = focus
+            .0
+            .and_then(|focused| nodes.iter().find(|(entity, _)| *entity == focused));
+        let Some(&(current, from)) = current else {
+            focus.0 = nodes
+                .iter()
+                .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+                .map(|(entity, _)| *entity);
+            continue;
+        };
+        let container = nav_container(current, &containers, &parents);
+        let candidates = nodes
+            .iter()
+            .copied()
+            .filter(|(entity, _)| *entity != current)
+            .filter(|(entity, _)| match container {
+                Some((ancestor, container)) if container.contain => {
+                    is_descendant(*entity, ancestor, &parents)
+                }
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+        let wrap = container.is_some_and(|(_, container)| container.wrap);
+        if let Some(next) = nearest(from, direction.vector(), wrap, &candidates) {
+            focus.0 = Some(next);
+        }
+    }
+}
+
+/// Adds the [`UiFocus`] resource and the systems that move it with the gamepad's D-pad.
+pub struct StyleNavigationPlugin;
+
+impl Plugin for StyleNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFocus>()
+            .add_event::<NavigateEvent>()
+            .add_system(gamepad_navigation_system)
+            .add_system(spatial_navigation_system.after(gamepad_navigation_system));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::set_node_size;
+    use crate::prelude::*;
+    use bevy::math::Rect;
+
+    fn spawn_button(app: &mut App, x: f32, y: f32) -> Entity {
+        let rect = Rect::from_center_size(Vec2::new(x, y), Vec2::splat(40.));
+        let entity = app
+            .world
+            .spawn(
+                NodeBundle {
+                    global_transform: GlobalTransform::from_translation(rect.center().extend(0.)),
+                    ..default()
+                }
+                .focusable(),
+            )
+            .id();
+        set_node_size(&mut app.world, entity, rect.size());
+        entity
+    }
+
+    fn navigate(app: &mut App, direction: NavDirection) -> Option<Entity> {
+        app.world.send_event(NavigateEvent(direction));
+        app.update();
+        app.world.resource::<UiFocus>().0
+    }
+
+    /// A 3 x 2 grid of buttons, with the top row inside a container.
+    fn grid(app: &mut App, container: NavContainer) -> Vec<Entity> {
+        app.add_plugin(StyleNavigationPlugin)
+            .init_resource::<Input<GamepadButton>>();
+        let buttons = [
+            (0., 0.),
+            (100., 0.),
+            (200., 0.),
+            (0., 100.),
+            (100., 100.),
+            (200., 100.),
+        ]
+        .map(|(x, y)| spawn_button(app, x, y))
+        .to_vec();
+        app.world
+            .spawn((node(), container))
+            .push_children(&buttons[..3]);
+        buttons
+    }
+
+    #[test]
+    fn focus_moves_to_the_nearest_node() {
+        let mut app = App::new();
+        let buttons = grid(&mut app, NavContainer::default());
+        assert_eq!(navigate(&mut app, NavDirection::Down), Some(buttons[0]));
+        assert_eq!(navigate(&mut app, NavDirection::Right), Some(buttons[1]));
+        assert_eq!(navigate(&mut app, NavDirection::Down), Some(buttons[4]));
+        assert_eq!(navigate(&mut app, NavDirection::Down), Some(buttons[4]));
+
+        app.world.entity_mut(buttons[5]).insert(Disabled);
+        assert_eq!(navigate(&mut app, NavDirection::Right), Some(buttons[2]));
+
+        app.world
+            .resource_mut::<Input<GamepadButton>>()
+            .press(GamepadButton::new(
+                Gamepad::new(0),
+                GamepadButtonType::DPadLeft,
+            ));
+        app.update();
+        assert_eq!(app.world.resource::<UiFocus>().0, Some(buttons[1]));
+    }
+
+    #[test]
+    fn containers_keep_and_wrap_the_focus() {
+        let mut app = App::new();
+        let buttons = grid(&mut app, NavContainer::default().contained().wrapping());
+        app.world.resource_mut::<UiFocus>().0 = Some(buttons[2]);
+        assert_eq!(navigate(&mut app, NavDirection::Right), Some(buttons[0]));
+        assert_eq!(navigate(&mut app, NavDirection::Down), Some(buttons[0]));
+        assert_eq!(navigate(&mut app, NavDirection::Left), Some(buttons[2]));
+    }
+
+    #[test]
+    fn nodes_inside_disabled_nodes_are_skipped() {
+        let mut app = App::new();
+        let buttons = grid(&mut app, NavContainer::default());
+        let container = app.world.get::<Parent>(buttons[0]).unwrap().get();
+        app.world.entity_mut(container).insert(Disabled);
+        app.world.resource_mut::<UiFocus>().0 = Some(buttons[4]);
+        assert_eq!(navigate(&mut app, NavDirection::Up), Some(buttons[4]));
+        assert_eq!(navigate(&mut app, NavDirection::Left), Some(buttons[3]));
+    }
+}
@@ -0,0 +1,412 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::borrow::Cow;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum PrefabError {
+    #[error("there is no prefab named `{0}`")]
+    UnknownPrefab(String),
+    #[error("the prefab has no parameter `{0}`")]
+    UnknownParam(String),
+    #[error("the prefab has no slot `{0}`")]
+    UnknownSlot(String),
+    #[error("the parameter `{name}` is a {expected}, found a {found}")]
+    WrongType {
+        name: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+/// The value of a [`UiPrefab`] parameter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrefabParam {
+    Color(Color),
+    Text(String),
+    Count(usize),
+}
+
+impl PrefabParam {
+    fn kind(&self) -> &'static str {
+        match self {
+            PrefabParam::Color(_) => "color",
+            PrefabParam::Text(_) => "text",
+            PrefabParam::Count(_) => "count",
+        }
+    }
+}
+
+type BuildSlot = dyn Fn(&mut ChildBuilder) + Send + Sync;
+
+/// The children spawned into a slot of a [`UiPrefab`].
+#[derive(Clone)]
+pub struct SlotContent(Arc<BuildSlot>);
+
+impl SlotContent {
+    pub fn new(build: impl Fn(&mut ChildBuilder) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(build))
+    }
+}
+
+impl Default for SlotContent {
+    fn default() -> Self {
+        Self::new(|_| {})
+    }
+}
+
+impl std::fmt::Debug for SlotContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SlotContent")
+    }
+}
+
+/// Named values and slot contents passed to a [`UiPrefab`] when it is instantiated.
+#[derive(Clone, Debug, Default)]
+pub struct PrefabParams {
+    values: HashMap<String, PrefabParam>,
+    slots: HashMap<String, SlotContent>,
+}
+
+impl PrefabParams {
+    pub fn with_color(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.values.insert(name.into(), PrefabParam::Color(color));
+        self
+    }
+
+    pub fn with_text(mut self, name: impl Into<String>, text: impl Into<String>) -> Self {
+        self.values
+            .insert(name.into(), PrefabParam::Text(text.into()));
+        self
+    }
+
+    pub fn with_count(mut self, name: impl Into<String>, count: usize) -> Self {
+        self.values.insert(name.into(), PrefabParam::Count(count));
+        self
+    }
+
+    /// Fill the slot `name` with the children spawned by `build`.
+    pub fn with_slot(
+        mut self,
+        name: impl Into<String>,
+        build: impl Fn(&mut ChildBuilder) + Send + Sync + 'static,
+    ) -> Self {
+        self.slots.insert(name.into(), SlotContent::new(build));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PrefabParam> {
+        self.values.get(name)
+    }
+
+    /// The color parameter `name`.
+    ///
+    /// Panics if the prefab doesn't declare a color named `name`.
+    pub fn color(&self, name: &str) -> Color {
+        match self.get(name) {
+            Some(PrefabParam::Color(color)) => *color,
+            _ => panic!("the prefab has no color parameter `{name}`"),
+        }
+    }
+
+    /// The text parameter `name`.
+    ///
+    /// Panics if the prefab doesn't declare a text named `name`.
+    pub fn text(&self, name: &str) -> &str {
+        match self.get(name) {
+            Some(PrefabParam::Text(text)) => text,
+            _ => panic!("the prefab has no text parameter `{name}`"),
+        }
+    }
+
+    /// The count parameter `name`.
+    ///
+    /// Panics if the prefab doesn't declare a count named `name`.
+    pub fn count(&self, name: &str) -> usize {
+        match self.get(name) {
+            Some(PrefabParam::Count(count)) => *count,
+            _ => panic!("the prefab has no count parameter `{name}`"),
+        }
+    }
+
+    /// Spawns the contents of the slot `name`, for use with `with_children`.
+    ///
+    /// Panics if the prefab doesn't declare a slot named `name`.
+    pub fn slot(&self, name: &str) -> impl Fn(&mut ChildBuilder) + '_ {
+        let Some(content) = self.slots.get(name) else {
+            panic!("the prefab has no slot `{name}`");
+        };
+        |builder| (content.0)(builder)
+    }
+}
+
+type BuildPrefab = dyn Fn(&mut Commands, &PrefabParams) -> Entity + Send + Sync;
+
+/// A template for a UI tree, spawned by a function of its parameters.
+///
+/// The parameters are declared with their default values. When the prefab is instantiated,
+/// the given parameters replace the defaults, and must match a declared parameter's name and kind.
+///
+/// Slots are places in the tree where the caller spawns its own children, so a prefab
+/// can be the frame around varying content. The prefab spawns them with
+/// `with_children(params.slot("content"))`.
+#[derive(Clone)]
+pub struct UiPrefab {
+    defaults: PrefabParams,
+    build: Arc<BuildPrefab>,
+}
+
+impl UiPrefab {
+    /// A prefab spawned by `build`, which returns the root entity of the tree.
+    pub fn new(
+        build: impl Fn(&mut Commands, &PrefabParams) -> Entity + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            defaults: PrefabParams::default(),
+            build: Arc::new(build),
+        }
+    }
+
+    /// Declare a color parameter.
+    pub fn with_color(mut self, name: impl Into<String>, default: Color) -> Self {
+        self.defaults = self.defaults.with_color(name, default);
+        self
+    }
+
+    /// Declare a text parameter.
+    pub fn with_text(mut self, name: impl Into<String>, default: impl Into<String>) -> Self {
+        self.defaults = self.defaults.with_text(name, default);
+        self
+    }
+
+    /// Declare a count parameter.
+    pub fn with_count(mut self, name: impl Into<String>, default: usize) -> Self {
+        self.defaults = self.defaults.with_count(name, default);
+        self
+    }
+
+    /// Declare a slot, which is left empty unless it is filled when the prefab is instantiated.
+    pub fn with_slot(mut self, name: impl Into<String>) -> Self {
+        self.defaults
+            .slots
+            .insert(name.into(), SlotContent::default());
+        self
+    }
+
+    /// The declared parameters with their default values.
+    pub fn defaults(&self) -> &PrefabParams {
+        &self.defaults
+    }
+
+    /// Spawn the tree with `params` in place of the defaults, and return its root entity.
+    pub fn instantiate(
+        &self,
+        commands: &mut Commands,
+        params: PrefabParams,
+    ) -> Result<Entity, PrefabError> {
+        let mut resolved = self.defaults.clone();
+        for (name, value) in params.values {
+            let Some(default) = self.defaults.get(&name) else {
+                return Err(PrefabError::UnknownParam(name));
+            };
+            if default.kind() != value.kind() {
+                return Err(PrefabError::WrongType {
+                    name,
+                    expected: default.kind(),
+                    found: value.kind(),
+                });
+            }
+            resolved.values.insert(name, value);
+        }
+        for (name, content) in params.slots {
+            if !self.defaults.slots.contains_key(&name) {
+                return Err(PrefabError::UnknownSlot(name));
+            }
+            resolved.slots.insert(name, content);
+        }
+        Ok((self.build)(commands, &resolved))
+    }
+}
+
+impl std::fmt::Debug for UiPrefab {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiPrefab")
+            .field("defaults", &self.defaults)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Prefabs registered by name, so they can be defined once at startup and spawned anywhere.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct UiPrefabs {
+    prefabs: HashMap<Cow<'static, str>, UiPrefab>,
+}
+
+impl UiPrefabs {
+    /// Register `prefab` as `name`, replacing any prefab with the same name.
+    pub fn register(&mut self, name: impl Into<Cow<'static, str>>, prefab: UiPrefab) -> &mut Self {
+        self.prefabs.insert(name.into(), prefab);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&UiPrefab> {
+        self.prefabs.get(name)
+    }
+
+    /// Spawn the prefab registered as `name` with `params`, and return its root entity.
+    pub fn instantiate(
+        &self,
+        name: &str,
+        commands: &mut Commands,
+        params: PrefabParams,
+    ) -> Result<Entity, PrefabError> {
+        self.get(name)
+            .ok_or_else(|| PrefabError::UnknownPrefab(name.to_string()))?
+            .instantiate(commands, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use bevy::ecs::system::CommandQueue;
+
+    fn card() -> UiPrefab {
+        UiPrefab::new(|commands, params| {
+            commands
+                .spawn(node().column().background_color(params.color("accent")))
+                .with_children(|card| {
+                    card.spawn(TextBundle::from_section(
+                        params.text("title"),
+                        TextStyle::default(),
+                    ));
+                    for _ in 0..params.count("rows") {
+                        card.spawn(node().height(Val::Px(20.)));
+                    }
+                })
+                .id()
+        })
+        .with_color("accent", Color::GRAY)
+        .with_text("title", "Card")
+        .with_count("rows", 1)
+    }
+
+    #[test]
+    fn parameters_override_the_defaults() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let mut prefabs = UiPrefabs::default();
+        prefabs.register("card", card());
+        let plain = prefabs
+            .instantiate("card", &mut commands, PrefabParams::default())
+            .unwrap();
+        let custom = prefabs
+            .instantiate(
+                "card",
+                &mut commands,
+                PrefabParams::default()
+                    .with_color("accent", Color::RED)
+                    .with_count("rows", 3),
+            )
+            .unwrap();
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<BackgroundColor>(plain).unwrap().0, Color::GRAY);
+        assert_eq!(world.get::<Children>(plain).unwrap().len(), 2);
+        assert_eq!(world.get::<BackgroundColor>(custom).unwrap().0, Color::RED);
+        let children = world.get::<Children>(custom).unwrap();
+        assert_eq!(children.len(), 4);
+        let title = world.get::<Text>(children[0]).unwrap();
+        assert_eq!(title.sections[0].value, "Card");
+    }
+
+    fn window_frame() -> UiPrefab {
+        UiPrefab::new(|commands, params| {
+            commands
+                .spawn(node().column())
+                .with_children(|frame| {
+                    frame.spawn(TextBundle::from_section(
+                        params.text("title"),
+                        TextStyle::default(),
+                    ));
+                    frame
+                        .spawn((node().column(), Name::new("body")))
+                        .with_children(params.slot("content"));
+                    frame
+                        .spawn((node().row(), Name::new("footer")))
+                        .with_children(params.slot("footer"));
+                })
+                .id()
+        })
+        .with_text("title", "Window")
+        .with_slot("content")
+        .with_slot("footer")
+    }
+
+    #[test]
+    fn slots_are_filled_with_the_given_children() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let frame = window_frame()
+            .instantiate(
+                &mut commands,
+                PrefabParams::default().with_slot("content", |content| {
+                    content.spawn(ButtonBundle::default());
+                    content.spawn(ButtonBundle::default());
+                }),
+            )
+            .unwrap();
+        queue.apply(&mut world);
+
+        let children = world.get::<Children>(frame).unwrap().to_vec();
+        assert_eq!(world.get::<Name>(children[1]).unwrap().as_str(), "body");
+        let body = world.get::<Children>(children[1]).unwrap();
+        assert_eq!(body.len(), 2);
+        assert!(world.get::<Button>(body[0]).is_some());
+        assert!(world
+            .get::<Children>(children[2])
+            .map_or(true, |footer| footer.is_empty()));
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let prefabs = UiPrefabs::default();
+        assert_eq!(
+            prefabs.instantiate("card", &mut commands, PrefabParams::default()),
+            Err(PrefabError::UnknownPrefab("card".to_string()))
+        );
+        let card = card();
+        assert_eq!(
+            card.instantiate(
+                &mut commands,
+                PrefabParams::default().with_count("columns", 2)
+            ),
+            Err(PrefabError::UnknownParam("columns".to_string()))
+        );
+        assert_eq!(
+            card.instantiate(
+                &mut commands,
+                PrefabParams::default().with_text("rows", "3")
+            ),
+            Err(PrefabError::WrongType {
+                name: "rows".to_string(),
+                expected: "count",
+                found: "text",
+            })
+        );
+        assert_eq!(
+            card.instantiate(
+                &mut commands,
+                PrefabParams::default().with_slot("body", |_| {})
+            ),
+            Err(PrefabError::UnknownSlot("body".to_string()))
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Ready-made styles declared as constant [`StyleDef`]s.
+//!
+//! Apply one with [`StyleBuilderExt::preset`](crate::StyleBuilderExt::preset),
+//! for example `node().preset(&preset::CARD)`, or declare your own with [`presets!`](crate::presets).
+
+use crate::def::StyleDef;
+use bevy::prelude::*;
+
+/// Declares constant [`StyleDef`] presets.
+///
+/// Each entry is `visibility NAME = definition;`, where the definition is a const expression
+/// such as `StyleDef::DEFAULT.column().grow(1.)`.
+#[macro_export]
+macro_rules! presets {
+    ($($(#[$attr:meta])* $vis:vis $name:ident = $def:expr;)*) => {
+        $(
+            $(#[$attr])*
+            $vis const $name: $crate::def::StyleDef = $def;
+        )*
+    };
+}
+
+presets! {
+    /// A column of content with padding.
+    pub CARD = StyleDef::DEFAULT
+        .column()
+        .padding_all(Val::Px(12.))
+        .margin_all(Val::Px(4.));
+    /// A full width row of vertically centered items.
+    pub TOOLBAR = StyleDef::DEFAULT
+        .row()
+        .align_items(AlignItems::Center)
+        .width(Val::Percent(100.))
+        .padding_all(Val::Px(4.));
+    /// A row of items.
+    pub ROW = StyleDef::DEFAULT.row();
+    /// A column of items.
+    pub COLUMN = StyleDef::DEFAULT.column();
+    /// Centers its children on both axes.
+    pub CENTER = StyleDef::DEFAULT
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center);
+    /// Grows to fill the remaining space along its parent's main axis.
+    pub FILL = StyleDef::DEFAULT.grow(1.);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    presets! {
+        SIDEBAR = StyleDef::DEFAULT.column().width(Val::Px(200.));
+    }
+
+    #[test]
+    fn presets_are_applied_by_reference() {
+        let toolbar = node().preset(&TOOLBAR);
+        assert_eq!(toolbar.style, TOOLBAR.to_style());
+        assert_eq!(toolbar.style.align_items, AlignItems::Center);
+        let sidebar = style().preset(&SIDEBAR).grow(1.);
+        assert_eq!(sidebar.size.width, Val::Px(200.));
+        assert_eq!(sidebar.flex_direction, FlexDirection::Column);
+        assert_eq!(sidebar.flex_grow, 1.);
+    }
+}
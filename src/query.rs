@@ -0,0 +1,82 @@
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+
+/// Fluent writer for the [`Style`] of a node found by [`UiQueryExt`].
+pub struct StyleWriter<'w>(pub Mut<'w, Style>);
+
+impl<'w> StyleBuilderExt for StyleWriter<'w> {
+    fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
+        s(&mut self.0);
+        self
+    }
+}
+
+/// Locate UI nodes by their [`Name`]s.
+///
+/// A path is a list of names separated by `/`, starting from a root UI node,
+/// for example `"hud/healthbar/fill"`.
+pub trait UiQueryExt {
+    /// Find the UI node at the end of `path`.
+    fn find_ui_node(&mut self, path: &str) -> Option<Entity>;
+
+    /// Find the UI node at the end of `path` and return a writer for its [`Style`].
+    fn ui_style(&mut self, path: &str) -> Option<StyleWriter<'_>>;
+}
+
+impl UiQueryExt for World {
+    fn find_ui_node(&mut self, path: &str) -> Option<Entity> {
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+        let root = segments.next()?;
+        let mut current = self
+            .query_filtered::<(Entity, &Name), (With<Node>, Without<Parent>)>()
+            .iter(self)
+            .find(|(_, name)| name.as_str() == root)
+            .map(|(entity, _)| entity)?;
+        for segment in segments {
+            current = self
+                .get::<Children>(current)?
+                .iter()
+                .copied()
+                .find(|child| {
+                    self.get::<Name>(*child)
+                        .is_some_and(|name| name.as_str() == segment)
+                })?;
+        }
+        Some(current)
+    }
+
+    fn ui_style(&mut self, path: &str) -> Option<StyleWriter<'_>> {
+        let entity = self.find_ui_node(path)?;
+        self.get_mut::<Style>(entity).map(StyleWriter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn restyle_by_path() {
+        let mut world = World::new();
+        let fill = world.spawn(node().named("fill")).id();
+        let healthbar = world
+            .spawn(node().named("healthbar"))
+            .push_children(&[fill])
+            .id();
+        world.spawn(node().named("hud")).push_children(&[healthbar]);
+
+        assert_eq!(world.find_ui_node("hud/healthbar/fill"), Some(fill));
+        assert_eq!(world.find_ui_node("healthbar"), None);
+        assert_eq!(world.find_ui_node("hud/fill"), None);
+
+        world
+            .ui_style("hud/healthbar/fill")
+            .unwrap()
+            .width(Val::Percent(50.));
+        assert_eq!(
+            world.get::<Style>(fill).unwrap().size.width,
+            Val::Percent(50.)
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use bevy::ecs::system::Command;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type ListItems<R, T> = dyn Fn(&R) -> Vec<T> + Send + Sync;
+type SpawnItem<T> = dyn Fn(&mut EntityCommands, &T) + Send + Sync;
+
+/// Keeps the children of a node in step with a collection read from the resource `R`,
+/// with one child for each item.
+///
+/// When `R` changes, the new items are compared with the old ones. Children of items that are
+/// still present are kept and moved into place, children of removed items are despawned,
+/// and only new or changed items are spawned. The list's children are managed by the list,
+/// so don't add other children to it.
+#[derive(Component)]
+pub struct ReactiveList<R: Resource, T> {
+    items: Arc<ListItems<R, T>>,
+    spawn: Arc<SpawnItem<T>>,
+    current: Vec<(T, Entity)>,
+}
+
+/// A list of children for the items returned by `items`, each spawned by `spawn`,
+/// which inserts the child's components with the given [`EntityCommands`].
+///
+/// Needs the [`ReactiveListPlugin`] for `R` and `T`.
+pub fn reactive_list<R: Resource, T>(
+    items: impl Fn(&R) -> Vec<T> + Send + Sync + 'static,
+    spawn: impl Fn(&mut EntityCommands, &T) + Send + Sync + 'static,
+) -> ReactiveList<R, T> {
+    ReactiveList {
+        items: Arc::new(items),
+        spawn: Arc::new(spawn),
+        current: Vec::new(),
+    }
+}
+
+impl<R: Resource, T> ReactiveList<R, T> {
+    /// The items the children were last spawned for, with their entities.
+    pub fn items(&self) -> impl Iterator<Item = (&T, Entity)> {
+        self.current.iter().map(|(item, entity)| (item, *entity))
+    }
+}
+
+/// Moves the children in `order` to the front of the parent's children, in that order.
+struct OrderChildren {
+    parent: Entity,
+    order: Vec<Entity>,
+}
+
+impl Command for OrderChildren {
+    fn write(self, world: &mut World) {
+        let Some(mut children) = world.get_mut::<Children>(self.parent) else {
+            return;
+        };
+        for (index, entity) in self.order.iter().enumerate() {
+            if let Some(position) = children.iter().position(|child| child == entity) {
+                if position != index {
+                    children.swap(index, position);
+                }
+            }
+        }
+    }
+}
+
+/// Updates the children of each [`ReactiveList`] when `R` changes.
+pub fn reactive_list_system<R: Resource, T: PartialEq + Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Res<R>,
+    mut lists: Query<(Entity, &mut ReactiveList<R, T>)>,
+) {
+    for (parent, mut list) in lists.iter_mut() {
+        if !resource.is_changed() && !list.is_added() {
+            continue;
+        }
+        let list = &mut *list;
+        let mut old = std::mem::take(&mut list.current)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        for item in (list.items)(&resource) {
+            let kept = old
+                .iter_mut()
+                .find(|entry| entry.as_ref().is_some_and(|(old, _)| *old == item))
+                .and_then(Option::take);
+            let entity = match kept {
+                Some((_, entity)) => entity,
+                None => {
+                    let mut child = commands.spawn_empty();
+                    (list.spawn)(&mut child, &item);
+                    let child = child.id();
+                    commands.entity(parent).add_child(child);
+                    child
+                }
+            };
+            list.current.push((item, entity));
+        }
+        for (_, entity) in old.into_iter().flatten() {
+            commands.entity(entity).despawn_recursive();
+        }
+        commands.add(OrderChildren {
+            parent,
+            order: list.current.iter().map(|(_, entity)| *entity).collect(),
+        });
+    }
+}
+
+/// Adds the system that updates each [`ReactiveList`] of items `T` read from the resource `R`.
+pub struct ReactiveListPlugin<R, T>(PhantomData<(R, T)>);
+
+impl<R, T> Default for ReactiveListPlugin<R, T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: Resource, T: PartialEq + Send + Sync + 'static> Plugin for ReactiveListPlugin<R, T> {
+    fn build(&self, app: &mut App) {
+        app.add_system(reactive_list_system::<R, T>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Inventory {
+        items: Vec<String>,
+    }
+
+    fn labels(app: &mut App, list: Entity) -> Vec<String> {
+        app.world
+            .get::<Children>(list)
+            .map(|children| children.to_vec())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| app.world.get::<Name>(child).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn children_follow_the_collection() {
+        let mut app = App::new();
+        app.insert_resource(Inventory {
+            items: vec!["sword".into(), "shield".into()],
+        })
+        .add_plugin(ReactiveListPlugin::<Inventory, String>::default());
+        let list = app
+            .world
+            .spawn((
+                node().column(),
+                reactive_list(
+                    |inventory: &Inventory| inventory.items.clone(),
+                    |child, item: &String| {
+                        child.insert((node().height(Val::Px(20.)), Name::new(item.clone())));
+                    },
+                ),
+            ))
+            .id();
+        app.update();
+        assert_eq!(labels(&mut app, list), vec!["sword", "shield"]);
+        let shield = app.world.get::<Children>(list).unwrap()[1];
+
+        app.world.resource_mut::<Inventory>().items =
+            vec!["potion".into(), "shield".into(), "bow".into()];
+        app.update();
+        assert_eq!(labels(&mut app, list), vec!["potion", "shield", "bow"]);
+        assert_eq!(app.world.get::<Children>(list).unwrap()[1], shield);
+        assert_eq!(app.world.entities().len(), 4);
+
+        app.world.resource_mut::<Inventory>().items = vec![];
+        app.update();
+        assert!(labels(&mut app, list).is_empty());
+        assert_eq!(app.world.entities().len(), 1);
+    }
+}
@@ -0,0 +1,1337 @@
+use crate::fields::StyleFields;
+use crate::interaction::DisabledVisuals;
+use crate::navigation::UiFocus;
+use crate::restyle::StylePatch;
+use crate::theme::ThemeTokens;
+use crate::viewport::viewport_size;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::utils::HashMap;
+use bevy::utils::HashSet;
+#[cfg(feature = "serialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+/// Named [`StylePatch`]es that can be applied to nodes with a [`StyleClasses`] component.
+///
+/// A style registered as `name:hover`, `name:pressed`, `name:disabled` or `name:focused` is applied
+/// on top of the `name` style while a node with the class `name` is in that state.
+/// The states are read from the node's [`Interaction`], whether it is [`Disabled`](crate::interaction::Disabled),
+/// and whether it has the [`UiFocus`].
+///
+/// Styles registered with [`register_media`](Self::register_media) are applied on top of
+/// the `name` style while the window matches their [`MediaQuery`].
+#[derive(Resource, Default)]
+pub struct StyleRegistry {
+    styles: HashMap<Cow<'static, str>, (StylePriority, StylePatch)>,
+    media: HashMap<Cow<'static, str>, Vec<(MediaQuery, StylePatch)>>,
+    viewport: Option<Vec2>,
+    themed: HashSet<Cow<'static, str>>,
+    tokens: Arc<RwLock<Arc<ThemeTokens>>>,
+    changed: HashSet<Cow<'static, str>>,
+}
+
+impl StyleRegistry {
+    /// Register a named style with [`StylePriority::Class`],
+    /// replacing any existing style with the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        patch: impl Fn(&mut Style) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_with_priority(name, StylePriority::Class, patch)
+    }
+
+    /// Register a named style that is applied in the `priority` tier,
+    /// replacing any existing style with the same name.
+    pub fn register_with_priority(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        priority: StylePriority,
+        patch: impl Fn(&mut Style) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_patch(name, priority, StylePatch::new(patch))
+    }
+
+    /// Register `patch` as a named style that is applied in the `priority` tier,
+    /// replacing any existing style with the same name.
+    pub fn register_patch(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        priority: StylePriority,
+        patch: StylePatch,
+    ) -> &mut Self {
+        let name = name.into();
+        self.mark_changed(&name);
+        if !self.media.contains_key(&name) {
+            self.themed.remove(&name);
+        }
+        self.styles.insert(name, (priority, patch));
+        self
+    }
+
+    /// Register a named style with [`StylePriority::Theme`] that reads the [`ThemeTokens`],
+    /// replacing any existing style with the same name.
+    ///
+    /// The style is applied again with the new tokens whenever they change,
+    /// and any class style on the same node wins over it.
+    pub fn register_themed(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        patch: impl Fn(&mut Style, &ThemeTokens) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let patch = self.bind_tokens(patch);
+        self.register_bound(name, StylePriority::Theme, None, patch)
+    }
+
+    /// Like [`register_media`](Self::register_media), for a variant that reads the [`ThemeTokens`].
+    pub fn register_themed_media(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        query: MediaQuery,
+        patch: impl Fn(&mut Style, &ThemeTokens) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let patch = self.bind_tokens(patch);
+        self.register_bound(name, StylePriority::Theme, Some(query), patch)
+    }
+
+    /// A patch that calls `patch` with the registry's current tokens.
+    pub(crate) fn bind_tokens(
+        &self,
+        patch: impl Fn(&mut Style, &ThemeTokens) + Send + Sync + 'static,
+    ) -> StylePatch {
+        let tokens = self.tokens.clone();
+        StylePatch::new(move |style| {
+            let tokens = tokens.read().unwrap().clone();
+            patch(style, &tokens)
+        })
+    }
+
+    /// Register a patch from [`bind_tokens`](Self::bind_tokens) as the named style in the `priority` tier,
+    /// or as a media variant of it, that is restyled whenever the tokens change.
+    pub(crate) fn register_bound(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        priority: StylePriority,
+        media: Option<MediaQuery>,
+        patch: StylePatch,
+    ) -> &mut Self {
+        let name = name.into();
+        match media {
+            Some(query) => self.register_media_patch(name.clone(), query, patch),
+            None => self.register_patch(name.clone(), priority, patch),
+        };
+        self.themed.insert(name);
+        self
+    }
+
+    /// Set the tokens read by the themed styles, marking every themed style as changed.
+    pub fn set_tokens(&mut self, tokens: ThemeTokens) {
+        *self.tokens.write().unwrap() = Arc::new(tokens);
+        let themed = self.themed.iter().cloned().collect::<Vec<_>>();
+        for name in themed {
+            self.mark_changed(&name);
+        }
+    }
+
+    /// Register a variant of the named style that is applied while the window matches `query`,
+    /// after the style itself and any earlier variants, in the [`StylePriority::Class`] tier.
+    pub fn register_media(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        query: MediaQuery,
+        patch: impl Fn(&mut Style) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_media_patch(name, query, StylePatch::new(patch))
+    }
+
+    /// Register `patch` as a variant of the named style that is applied while the window matches `query`.
+    pub fn register_media_patch(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        query: MediaQuery,
+        patch: StylePatch,
+    ) -> &mut Self {
+        let name = name.into();
+        self.mark_changed(&name);
+        self.media.entry(name).or_default().push((query, patch));
+        self
+    }
+
+    /// Set the size of the window that media styles are matched against,
+    /// marking the styles whose queries start or stop matching as changed.
+    pub fn set_viewport(&mut self, size: Vec2) {
+        let previous = self.viewport.replace(size);
+        let flipped = self
+            .media
+            .iter()
+            .filter(|(_, variants)| {
+                variants.iter().any(|(query, _)| {
+                    previous.map_or(true, |previous| {
+                        query.matches(previous) != query.matches(size)
+                    })
+                })
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        self.changed.extend(flipped);
+    }
+
+    /// The size of the window that media styles are matched against.
+    pub fn viewport(&self) -> Option<Vec2> {
+        self.viewport
+    }
+
+    /// Register a style for every node with the component `C`, replacing any existing style for `C`.
+    ///
+    /// Selector styles have [`StylePriority::Base`], so a node's classes take precedence.
+    /// They take effect once a [`StyleSelectorPlugin`] for `C` is added.
+    pub fn select<C: Component>(
+        &mut self,
+        patch: impl Fn(&mut Style) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_with_priority(selector_name::<C>(), StylePriority::Base, patch)
+    }
+
+    /// Remove the named style and its media variants. Nodes using it are restyled without it.
+    pub fn unregister(&mut self, name: &str) -> Option<StylePatch> {
+        self.themed.remove(name);
+        let media = self.media.remove(name);
+        let patch = self.styles.remove(name).map(|(_, patch)| patch);
+        if patch.is_some() || media.is_some() {
+            self.mark_changed(name);
+        }
+        patch
+    }
+
+    /// Remove the named style or media variant if it is `patch`,
+    /// leaving a style registered under the same name by anything else in place.
+    /// Returns whether anything was removed.
+    pub fn unregister_patch(&mut self, name: &str, patch: &StylePatch) -> bool {
+        let mut removed = false;
+        if self
+            .styles
+            .get(name)
+            .is_some_and(|(_, existing)| existing.ptr_eq(patch))
+        {
+            self.styles.remove(name);
+            removed = true;
+        }
+        if let Some(variants) = self.media.get_mut(name) {
+            let count = variants.len();
+            variants.retain(|(_, existing)| !existing.ptr_eq(patch));
+            removed |= variants.len() != count;
+            if variants.is_empty() {
+                self.media.remove(name);
+            }
+        }
+        if removed {
+            if !self.styles.contains_key(name) && !self.media.contains_key(name) {
+                self.themed.remove(name);
+            }
+            self.mark_changed(name);
+        }
+        removed
+    }
+
+    /// Mark `name`, and the class it is a state of, as changed.
+    fn mark_changed(&mut self, name: &str) {
+        if let Some((class, _)) = name.split_once(':') {
+            self.changed.insert(Cow::Owned(class.to_string()));
+        }
+        self.changed.insert(Cow::Owned(name.to_string()));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&StylePatch> {
+        self.styles.get(name).map(|(_, patch)| patch)
+    }
+
+    /// The tier the named style is applied in.
+    pub fn priority(&self, name: &str) -> Option<StylePriority> {
+        self.styles.get(name).map(|(priority, _)| *priority)
+    }
+
+    /// Apply the named styles to a copy of `base`, lowest [`StylePriority`] first,
+    /// and in order within each tier. Unregistered names are ignored.
+    pub fn resolve<'a>(
+        &self,
+        base: &Style,
+        names: impl IntoIterator<Item = &'a str> + Clone,
+    ) -> Style {
+        self.resolve_state(base, names, None)
+    }
+
+    /// Like [`resolve`](Self::resolve), also applying the styles for the names in `state`,
+    /// such as `button:hover` for the name `button` and the state [`PseudoClass::Hover`].
+    /// Each state style is applied after the other styles in its tier.
+    pub fn resolve_state<'a>(
+        &self,
+        base: &Style,
+        names: impl IntoIterator<Item = &'a str> + Clone,
+        state: Option<PseudoClass>,
+    ) -> Style {
+        self.resolve_with(base, names, state, None, &[])
+    }
+
+    /// `scope` if it has a style or media variant with the name, otherwise this registry.
+    fn owner<'r>(&'r self, scope: Option<&'r StyleRegistry>, name: &str) -> &'r StyleRegistry {
+        scope
+            .filter(|scope| scope.styles.contains_key(name) || scope.media.contains_key(name))
+            .unwrap_or(self)
+    }
+
+    /// The named style, from `scope` if it has one with that name.
+    fn lookup<'r>(
+        &'r self,
+        scope: Option<&'r StyleRegistry>,
+        name: &str,
+    ) -> Option<(StylePriority, &'r StylePatch)> {
+        self.owner(scope, name)
+            .styles
+            .get(name)
+            .map(|(priority, patch)| (*priority, patch))
+    }
+
+    /// The media variants of the named style that match the viewport.
+    fn matching_media<'r>(
+        &'r self,
+        scope: Option<&'r StyleRegistry>,
+        name: &str,
+    ) -> impl Iterator<Item = (StylePriority, &'r StylePatch)> {
+        let viewport = self.viewport;
+        self.owner(scope, name)
+            .media
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter(move |(query, _)| viewport.is_some_and(|size| query.matches(size)))
+            .map(|(_, patch)| (StylePriority::Class, patch))
+    }
+
+    fn resolve_with<'a>(
+        &self,
+        base: &Style,
+        names: impl IntoIterator<Item = &'a str> + Clone,
+        state: Option<PseudoClass>,
+        scope: Option<&StyleRegistry>,
+        node_patches: &[(StylePriority, &StylePatch)],
+    ) -> Style {
+        let mut patches = names
+            .clone()
+            .into_iter()
+            .filter_map(|name| self.lookup(scope, name))
+            .collect::<Vec<_>>();
+        for name in names.clone() {
+            patches.extend(self.matching_media(scope, name));
+        }
+        if let Some(state) = state {
+            patches.extend(
+                names
+                    .into_iter()
+                    .filter_map(|name| self.lookup(scope, &format!("{name}{}", state.suffix()))),
+            );
+        }
+        patches.extend_from_slice(node_patches);
+        patches.sort_by_key(|(priority, _)| *priority);
+        let mut style = base.clone();
+        for (_, patch) in patches {
+            patch.apply(&mut style);
+        }
+        style
+    }
+
+    /// Whether any of `names` were registered or unregistered since the classes were last applied.
+    pub fn any_changed<'a>(&self, mut names: impl Iterator<Item = &'a str>) -> bool {
+        names.any(|name| self.changed.contains(name))
+    }
+}
+
+/// The window sizes a media style applies at, like a CSS `@media` query.
+///
+/// The bounds are inclusive and in UI pixels. A query without bounds matches every size.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize), serde(default))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MediaQuery {
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+}
+
+impl MediaQuery {
+    pub fn min_width(mut self, width: f32) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub fn min_height(mut self, height: f32) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    pub fn max_height(mut self, height: f32) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Whether a window of `size` is within the query's bounds.
+    pub fn matches(&self, size: Vec2) -> bool {
+        let above = |min: Option<f32>, value: f32| min.map_or(true, |min| min <= value);
+        let below = |max: Option<f32>, value: f32| max.map_or(true, |max| value <= max);
+        above(self.min_width, size.x)
+            && below(self.max_width, size.x)
+            && above(self.min_height, size.y)
+            && below(self.max_height, size.y)
+    }
+}
+
+/// Styles that apply only to the entity and its descendants,
+/// such as the classes of a debug console or mod menu shipped by a plugin.
+///
+/// Classes used within the subtree are looked up in the nearest `StyleScope` first,
+/// so they can't collide with the app's [`StyleRegistry`].
+/// Names the scope doesn't register fall back to the global registry.
+///
+/// A scope can also be filled from a file with a [`ScopedStyleSheet`](crate::stylesheet::ScopedStyleSheet).
+#[derive(Component, Default)]
+pub struct StyleScope(pub StyleRegistry);
+
+impl std::ops::Deref for StyleScope {
+    type Target = StyleRegistry;
+
+    fn deref(&self) -> &StyleRegistry {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for StyleScope {
+    fn deref_mut(&mut self) -> &mut StyleRegistry {
+        &mut self.0
+    }
+}
+
+/// The nearest [`StyleScope`] of `entity` or its ancestors.
+fn nearest_scope<'s>(
+    entity: Entity,
+    scopes: &'s Query<&mut StyleScope>,
+    parents: &Query<&Parent>,
+) -> Option<&'s StyleRegistry> {
+    let mut current = entity;
+    loop {
+        if let Ok(scope) = scopes.get(current) {
+            return Some(&scope.0);
+        }
+        current = parents.get(current).ok()?.get();
+    }
+}
+
+/// The tiers styles are applied in, lowest first, so a style in a higher tier
+/// always wins over one in a lower tier, whatever the order of a node's classes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StylePriority {
+    /// Styles selected by component type with [`StyleRegistry::select`].
+    Base,
+    /// Styles from [`StyleRegistry::register_themed`] that read the [`ThemeTokens`],
+    /// the defaults of a theme that class styles build on.
+    Theme,
+    /// Styles for class names, the default for [`StyleRegistry::register`].
+    #[default]
+    Class,
+    /// A node's own [`StyleClasses::set_inline`] patch.
+    Inline,
+    /// Styles that override everything else, such as a node's [`StyleClasses::set_important`] patch.
+    Important,
+}
+
+/// An interaction state with its own variant of a registry style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PseudoClass {
+    Hover,
+    Pressed,
+    Disabled,
+    Focused,
+}
+
+impl PseudoClass {
+    pub const ALL: [PseudoClass; 4] = [Self::Hover, Self::Pressed, Self::Disabled, Self::Focused];
+
+    /// The state of a node with `interaction`, which takes precedence if the node is `disabled`.
+    pub fn from_interaction(interaction: Option<&Interaction>, disabled: bool) -> Option<Self> {
+        Self::from_node(interaction, disabled, false)
+    }
+
+    /// Like [`from_interaction`](Self::from_interaction), with the focus taking effect
+    /// only while the node is neither hovered nor pressed.
+    pub fn from_node(
+        interaction: Option<&Interaction>,
+        disabled: bool,
+        focused: bool,
+    ) -> Option<Self> {
+        match (disabled, interaction) {
+            (true, _) => Some(Self::Disabled),
+            (false, Some(Interaction::Clicked)) => Some(Self::Pressed),
+            (false, Some(Interaction::Hovered)) => Some(Self::Hover),
+            _ => focused.then_some(Self::Focused),
+        }
+    }
+
+    /// The suffix naming the state's variant of a style, such as `:hover`.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Hover => ":hover",
+            Self::Pressed => ":pressed",
+            Self::Disabled => ":disabled",
+            Self::Focused => ":focused",
+        }
+    }
+}
+
+/// The registry name of the style selected by the component `C`.
+fn selector_name<C: Component>() -> Cow<'static, str> {
+    Cow::Borrowed(std::any::type_name::<C>())
+}
+
+/// The names of the [`StyleRegistry`] styles applied to a node.
+///
+/// The node's style at the time the classes are first applied is kept as the base
+/// that the classes are re-resolved against.
+///
+/// Only the names are reflected, so inline and important patches are not saved in scenes.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct StyleClasses {
+    names: Vec<Cow<'static, str>>,
+    #[reflect(ignore)]
+    base: Option<Style>,
+    #[reflect(ignore)]
+    resolved: Option<Style>,
+    #[reflect(ignore)]
+    state: Option<PseudoClass>,
+    #[reflect(ignore)]
+    inline: Option<StylePatch>,
+    #[reflect(ignore)]
+    important: Option<StylePatch>,
+}
+
+impl StyleClasses {
+    pub fn new<N: Into<Cow<'static, str>>>(names: impl IntoIterator<Item = N>) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            base: None,
+            resolved: None,
+            state: None,
+            inline: None,
+            important: None,
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.names.iter().map(|name| name.as_ref())
+    }
+
+    pub fn add(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.names.push(name.into());
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.names.retain(|existing| existing != name);
+    }
+
+    /// Set the node's own patch, applied after its classes.
+    pub fn set_inline(&mut self, patch: impl Fn(&mut Style) + Send + Sync + 'static) {
+        self.inline = Some(StylePatch::new(patch));
+    }
+
+    pub fn clear_inline(&mut self) {
+        self.inline = None;
+    }
+
+    /// Force properties of the node's style, for example to flash it while it takes damage,
+    /// until [`clear_important`](Self::clear_important) is called.
+    /// The patch is applied last each time the node's classes are resolved.
+    pub fn set_important(&mut self, patch: impl Fn(&mut Style) + Send + Sync + 'static) {
+        self.important = Some(StylePatch::new(patch));
+    }
+
+    pub fn clear_important(&mut self) {
+        self.important = None;
+    }
+
+    fn node_patches(&self) -> Vec<(StylePriority, &StylePatch)> {
+        [
+            (StylePriority::Inline, &self.inline),
+            (StylePriority::Important, &self.important),
+        ]
+        .into_iter()
+        .filter_map(|(priority, patch)| Some((priority, patch.as_ref()?)))
+        .collect()
+    }
+}
+
+pub trait StyleClassesExt: Bundle + Sized {
+    /// Style the node with named styles from the [`StyleRegistry`].
+    fn classes<N: Into<Cow<'static, str>>>(
+        self,
+        names: impl IntoIterator<Item = N>,
+    ) -> (Self, StyleClasses) {
+        (self, StyleClasses::new(names))
+    }
+}
+
+impl<T: Bundle> StyleClassesExt for T {}
+
+/// Writes each field of `style` that differs between the `previous` and `next` resolved styles,
+/// leaving the other fields, and the style's change tick, untouched.
+///
+/// Returns `true` if any field was written.
+pub fn write_changed_fields(style: &mut Mut<Style>, previous: &Style, next: &Style) -> bool {
+    let mut written = false;
+    macro_rules! write_fields {
+        ($($($field:ident).+),*) => {
+            $(
+                if previous.$($field).+ != next.$($field).+
+                    && style.$($field).+ != next.$($field).+
+                {
+                    style.$($field).+ = next.$($field).+;
+                    written = true;
+                }
+            )*
+        };
+    }
+    macro_rules! write_vals {
+        ($($val:ident => $mut:ident),*) => {
+            $(
+                if previous.$val() != next.$val() && style.$val() != next.$val() {
+                    *style.$mut() = next.$val();
+                    written = true;
+                }
+            )*
+        };
+    }
+    write_fields!(
+        display,
+        position_type,
+        direction,
+        flex_direction,
+        flex_wrap,
+        align_items,
+        align_self,
+        align_content,
+        justify_content,
+        margin.left,
+        margin.right,
+        margin.top,
+        margin.bottom,
+        padding.left,
+        padding.right,
+        padding.top,
+        padding.bottom,
+        border.left,
+        border.right,
+        border.top,
+        border.bottom,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        aspect_ratio,
+        overflow
+    );
+    write_vals!(
+        left_val => left_mut,
+        right_val => right_mut,
+        top_val => top_mut,
+        bottom_val => bottom_mut,
+        width_val => width_mut,
+        height_val => height_mut,
+        min_width_val => min_width_mut,
+        min_height_val => min_height_mut,
+        max_width_val => max_width_mut,
+        max_height_val => max_height_mut
+    );
+    written
+}
+
+/// The number of classed nodes resolved together by each task.
+const CLASS_BATCH_SIZE: usize = 64;
+
+/// Re-resolves the styles of nodes whose classes or [`PseudoClass`] state changed,
+/// or that use a style registered or unregistered in the [`StyleRegistry`]
+/// or their [`StyleScope`] since the last run.
+/// Nodes that don't use any of the edited styles are skipped.
+///
+/// The nodes are resolved in parallel, so restyling a large UI at once doesn't stall the frame.
+/// When only the registry changed, just the fields whose resolved values differ are written,
+/// so editing one class leaves unrelated nodes unchanged and keeps any fields set by other systems.
+#[allow(clippy::type_complexity)]
+pub fn apply_style_classes_system(
+    mut registry: ResMut<StyleRegistry>,
+    focus: Option<Res<UiFocus>>,
+    mut scopes: Query<&mut StyleScope>,
+    parents: Query<&Parent>,
+    mut query: Query<(
+        Entity,
+        &mut StyleClasses,
+        &mut Style,
+        Option<&Interaction>,
+        Option<&DisabledVisuals>,
+    )>,
+) {
+    let registry_changed = registry.is_changed();
+    let focus = focus.as_deref();
+    let registry = registry.bypass_change_detection();
+    let shared = &*registry;
+    let scopes_changed = scopes.iter().any(|scope| !scope.changed.is_empty());
+    let scope_reader = &scopes;
+    let parents = &parents;
+    query.par_for_each_mut(
+        CLASS_BATCH_SIZE,
+        |(entity, mut classes, mut style, interaction, disabled)| {
+            let focused = focus.is_some_and(|focus| focus.0 == Some(entity));
+            let state = PseudoClass::from_node(interaction, disabled.is_some(), focused);
+            let scope = || nearest_scope(entity, scope_reader, parents);
+            if !(classes.is_changed()
+                || classes.state != state
+                || registry_changed && shared.any_changed(classes.names())
+                || scopes_changed
+                    && scope().is_some_and(|scope| scope.any_changed(classes.names())))
+            {
+                return;
+            }
+            let names_changed = classes.is_changed();
+            let classes = classes.bypass_change_detection();
+            if classes.base.is_none() {
+                classes.base = Some(style.clone());
+            }
+            let base = classes.base.as_ref().unwrap();
+            let resolved = shared.resolve_with(
+                base,
+                classes.names(),
+                state,
+                scope(),
+                &classes.node_patches(),
+            );
+            classes.state = state;
+            match &classes.resolved {
+                Some(previous) if !names_changed => {
+                    write_changed_fields(&mut style, previous, &resolved);
+                }
+                _ => {
+                    if *style != resolved {
+                        *style = resolved.clone();
+                    }
+                }
+            }
+            classes.resolved = Some(resolved);
+        },
+    );
+    registry.changed.clear();
+    for mut scope in scopes.iter_mut() {
+        if !scope.changed.is_empty() {
+            scope.bypass_change_detection().changed.clear();
+        }
+    }
+}
+
+/// Matches the media styles of the [`StyleRegistry`] and each [`StyleScope`]
+/// against the size of the primary window.
+pub fn media_query_system(
+    windows: Option<Res<Windows>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut registry: ResMut<StyleRegistry>,
+    mut scopes: Query<&mut StyleScope>,
+) {
+    let Some(viewport) = windows.and_then(|windows| viewport_size(&windows, ui_scale.as_deref()))
+    else {
+        return;
+    };
+    if registry.viewport == Some(viewport) {
+        return;
+    }
+    registry.set_viewport(viewport);
+    for mut scope in scopes.iter_mut() {
+        scope.bypass_change_detection().set_viewport(viewport);
+    }
+}
+
+/// Passes changes to the [`ThemeTokens`] on to the themed styles of the [`StyleRegistry`]
+/// and each [`StyleScope`].
+pub fn theme_tokens_system(
+    tokens: Res<ThemeTokens>,
+    mut registry: ResMut<StyleRegistry>,
+    mut scopes: Query<&mut StyleScope>,
+) {
+    if !tokens.is_changed() {
+        return;
+    }
+    registry.set_tokens(tokens.clone());
+    for mut scope in scopes.iter_mut() {
+        scope.bypass_change_detection().set_tokens(tokens.clone());
+    }
+}
+
+/// Adds the style selected by `C` to the classes of nodes that gain a `C` component,
+/// and removes it from nodes that lose one.
+///
+/// Nodes without [`StyleClasses`] are given them, with the selected style applied straight away.
+pub fn apply_style_selector_system<C: Component>(
+    mut commands: Commands,
+    registry: Res<StyleRegistry>,
+    mut added: Query<(Entity, &mut Style), Added<C>>,
+    mut classes: Query<&mut StyleClasses>,
+    removed: RemovedComponents<C>,
+) {
+    let name = selector_name::<C>();
+    for (entity, mut style) in added.iter_mut() {
+        if let Ok(mut classes) = classes.get_mut(entity) {
+            if !classes.names.contains(&name) {
+                classes.names.insert(0, name.clone());
+            }
+            continue;
+        }
+        let base = style.clone();
+        let resolved = registry.resolve(&base, [name.as_ref()]);
+        // The class system resolves any state variant on the next run.
+        if *style != resolved {
+            *style = resolved.clone();
+        }
+        commands.entity(entity).insert(StyleClasses {
+            names: vec![name.clone()],
+            base: Some(base),
+            resolved: Some(resolved),
+            ..default()
+        });
+    }
+    for entity in removed.iter() {
+        if let Ok(mut classes) = classes.get_mut(entity) {
+            classes.remove(&name);
+        }
+    }
+}
+
+/// Applies the style selected by the component `C` with [`StyleRegistry::select`].
+/// Requires the [`StyleRegistryPlugin`].
+pub struct StyleSelectorPlugin<C>(PhantomData<C>);
+
+impl<C> Default for StyleSelectorPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: Component> Plugin for StyleSelectorPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            apply_style_selector_system::<C>.before(apply_style_classes_system),
+        );
+    }
+}
+
+/// Adds the [`StyleRegistry`] resource and the system that applies [`StyleClasses`].
+/// Classed nodes are resolved on the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool),
+/// so the app needs the `CorePlugin` (included in `DefaultPlugins`).
+pub struct StyleRegistryPlugin;
+
+impl Plugin for StyleRegistryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StyleRegistry>()
+            .init_resource::<ThemeTokens>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                theme_tokens_system.before(apply_style_classes_system),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                media_query_system.before(apply_style_classes_system),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                apply_style_classes_system.before(UiSystem::Flex),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    #[test]
+    fn registry_change_restyles_classed_nodes() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.width(Val::Px(10.));
+            });
+        let entity = app
+            .world
+            .spawn(node().height(Val::Px(5.)).classes(["card", "missing"]))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<Style>(entity).unwrap().size,
+            Size::new(Val::Px(10.), Val::Px(5.))
+        );
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.padding(Breadth::Px(2.));
+            });
+        app.update();
+        let style = app.world.get::<Style>(entity).unwrap();
+        assert_eq!(style.size, Size::new(Val::Auto, Val::Px(5.)));
+        assert_eq!(style.padding, UiRect::all(Val::Px(2.)));
+    }
+
+    #[derive(Resource, Default)]
+    struct Restyled(Vec<Entity>);
+
+    fn record_restyled(mut restyled: ResMut<Restyled>, query: Query<Entity, Changed<Style>>) {
+        restyled.0 = query.iter().collect();
+    }
+
+    #[test]
+    fn registry_change_only_writes_changed_fields() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .init_resource::<Restyled>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                record_restyled.after(apply_style_classes_system),
+            );
+        let mut registry = app.world.resource_mut::<StyleRegistry>();
+        registry.register("card", |style| {
+            style.width(Val::Px(10.));
+        });
+        registry.register("label", |style| {
+            style.grow(1.);
+        });
+        let card = app.world.spawn(node().classes(["card"])).id();
+        let label = app.world.spawn(node().classes(["label"])).id();
+        app.update();
+        app.world.get_mut::<Style>(card).unwrap().size.height = Val::Px(7.);
+        app.update();
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.width(Val::Px(20.));
+            });
+        app.update();
+        assert_eq!(app.world.resource::<Restyled>().0, vec![card]);
+        assert_eq!(
+            app.world.get::<Style>(card).unwrap().size,
+            Size::new(Val::Px(20.), Val::Px(7.))
+        );
+        assert_eq!(app.world.get::<Style>(label).unwrap().flex_grow, 1.);
+    }
+
+    #[test]
+    fn only_nodes_using_an_edited_style_are_resolved() {
+        let resolved = Arc::new(AtomicUsize::new(0));
+        let count = |resolved: &Arc<AtomicUsize>| {
+            let resolved = resolved.clone();
+            move |_: &mut Style| {
+                resolved.fetch_add(1, Ordering::Relaxed);
+            }
+        };
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("button", count(&resolved))
+            .register("label", |style| {
+                style.grow(1.);
+            });
+        for _ in 0..3 {
+            app.world.spawn(node().classes(["button"]));
+        }
+        let label = app.world.spawn(node().classes(["label"])).id();
+        app.update();
+        assert_eq!(resolved.swap(0, Ordering::Relaxed), 3);
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("label", |style| {
+                style.grow(2.);
+            });
+        app.update();
+        assert_eq!(resolved.load(Ordering::Relaxed), 0);
+        assert_eq!(app.world.get::<Style>(label).unwrap().flex_grow, 2.);
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .unregister("label");
+        app.update();
+        assert_eq!(resolved.load(Ordering::Relaxed), 0);
+        assert_eq!(app.world.get::<Style>(label).unwrap().flex_grow, 0.);
+    }
+
+    #[test]
+    fn focused_variants_follow_the_ui_focus() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .init_resource::<UiFocus>();
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("button:focused", |style| {
+                style.padding(Breadth::Px(2.));
+            })
+            .register("button:hover", |style| {
+                style.padding(Breadth::Px(6.));
+            });
+        let button = app
+            .world
+            .spawn(node().interactive().classes(["button"]))
+            .id();
+        app.update();
+        let padding = |app: &App| app.world.get::<Style>(button).unwrap().padding;
+        assert_eq!(padding(&app), UiRect::default());
+
+        app.world.resource_mut::<UiFocus>().0 = Some(button);
+        app.update();
+        assert_eq!(padding(&app), UiRect::all(Val::Px(2.)));
+
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(padding(&app), UiRect::all(Val::Px(6.)));
+
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::None;
+        app.world.resource_mut::<UiFocus>().0 = None;
+        app.update();
+        assert_eq!(padding(&app), UiRect::default());
+    }
+
+    #[test]
+    fn state_variants_follow_interaction() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleInteractionPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("button", |style| {
+                style.padding(Breadth::Px(4.));
+            })
+            .register("button:hover", |style| {
+                style.padding(Breadth::Px(6.));
+            })
+            .register("button:disabled", |style| {
+                style.disable();
+            });
+        let button = app
+            .world
+            .spawn(node().interactive().classes(["button"]))
+            .id();
+        app.update();
+        let style = |app: &App| app.world.get::<Style>(button).unwrap().clone();
+        assert_eq!(style(&app).padding, UiRect::all(Val::Px(4.)));
+
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(style(&app).padding, UiRect::all(Val::Px(6.)));
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("button:hover", |style| {
+                style.padding(Breadth::Px(8.));
+            });
+        app.update();
+        assert_eq!(style(&app).padding, UiRect::all(Val::Px(8.)));
+
+        *app.world.get_mut::<Interaction>(button).unwrap() = Interaction::Clicked;
+        app.update();
+        assert_eq!(style(&app).padding, UiRect::all(Val::Px(4.)));
+
+        app.world.entity_mut(button).insert(Disabled);
+        app.update();
+        assert_eq!(style(&app).display, Display::None);
+        app.world.entity_mut(button).remove::<Disabled>();
+        app.update();
+        app.update();
+        assert_eq!(style(&app).display, Display::Flex);
+    }
+
+    #[test]
+    fn higher_priorities_win() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.width(Val::Px(10.));
+            })
+            .register_with_priority("theme", StylePriority::Theme, |style| {
+                style.width(Val::Px(20.)).height(Val::Px(20.));
+            });
+        let card = app.world.spawn(node().classes(["card", "theme"])).id();
+        app.update();
+        let size = |app: &App| app.world.get::<Style>(card).unwrap().size;
+        assert_eq!(size(&app), Size::new(Val::Px(10.), Val::Px(20.)));
+
+        let mut classes = app.world.get_mut::<StyleClasses>(card).unwrap();
+        classes.set_important(|style| {
+            style.width(Val::Px(40.));
+        });
+        classes.set_inline(|style| {
+            style.width(Val::Px(30.)).height(Val::Px(30.));
+        });
+        app.update();
+        assert_eq!(size(&app), Size::new(Val::Px(40.), Val::Px(30.)));
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.width(Val::Px(15.));
+            });
+        app.update();
+        assert_eq!(size(&app), Size::new(Val::Px(40.), Val::Px(30.)));
+
+        let mut classes = app.world.get_mut::<StyleClasses>(card).unwrap();
+        classes.clear_important();
+        classes.clear_inline();
+        app.update();
+        assert_eq!(size(&app), Size::new(Val::Px(15.), Val::Px(20.)));
+    }
+
+    #[test]
+    fn scoped_styles_only_apply_within_their_subtree() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("button", |style| {
+                style.width(Val::Px(10.));
+            })
+            .register("label", |style| {
+                style.grow(1.);
+            });
+        let mut scope = StyleScope::default();
+        scope.register("button", |style| {
+            style.width(Val::Px(50.));
+        });
+        let console = app.world.spawn((node(), scope)).id();
+        let console_button = app.world.spawn(node().classes(["button", "label"])).id();
+        let game_button = app.world.spawn(node().classes(["button"])).id();
+        app.world
+            .entity_mut(console)
+            .push_children(&[console_button]);
+        app.update();
+        let style = |app: &App, entity| app.world.get::<Style>(entity).unwrap().clone();
+        assert_eq!(style(&app, console_button).size.width, Val::Px(50.));
+        assert_eq!(style(&app, console_button).flex_grow, 1.);
+        assert_eq!(style(&app, game_button).size.width, Val::Px(10.));
+
+        app.world
+            .get_mut::<StyleScope>(console)
+            .unwrap()
+            .register("button", |style| {
+                style.width(Val::Px(60.));
+            });
+        app.update();
+        assert_eq!(style(&app, console_button).size.width, Val::Px(60.));
+        assert_eq!(style(&app, game_button).size.width, Val::Px(10.));
+    }
+
+    #[test]
+    fn media_styles_follow_the_viewport() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        let mut registry = app.world.resource_mut::<StyleRegistry>();
+        registry
+            .register("sidebar", |style| {
+                style.width(Val::Px(200.));
+            })
+            .register_media("sidebar", MediaQuery::default().max_width(799.), |style| {
+                style.disable();
+            })
+            .register_media("sidebar", MediaQuery::default().min_width(1600.), |style| {
+                style.width(Val::Px(400.));
+            });
+        registry.set_viewport(Vec2::new(1024., 768.));
+        let sidebar = app.world.spawn(node().classes(["sidebar"])).id();
+        app.update();
+        let style = |app: &App| app.world.get::<Style>(sidebar).unwrap().clone();
+        assert_eq!(style(&app).size.width, Val::Px(200.));
+        assert_eq!(style(&app).display, Display::Flex);
+
+        let mut registry = app.world.resource_mut::<StyleRegistry>();
+        registry.set_viewport(Vec2::new(640., 480.));
+        app.update();
+        assert_eq!(style(&app).display, Display::None);
+
+        let mut registry = app.world.resource_mut::<StyleRegistry>();
+        registry.set_viewport(Vec2::new(1920., 1080.));
+        app.update();
+        assert_eq!(style(&app).display, Display::Flex);
+        assert_eq!(style(&app).size.width, Val::Px(400.));
+    }
+
+    #[test]
+    fn themed_styles_follow_the_tokens() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(8.));
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register_themed("panel", |style, tokens| {
+                style.margin(tokens.val("$spacing-2"));
+            })
+            .register("label", |style| {
+                style.grow(1.);
+            });
+        let panel = app.world.spawn(node().classes(["panel"])).id();
+        let label = app.world.spawn(node().classes(["label"])).id();
+        app.update();
+        let margin = |app: &App| app.world.get::<Style>(panel).unwrap().margin;
+        assert_eq!(margin(&app), UiRect::all(Val::Px(8.)));
+
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(12.));
+        app.update();
+        assert_eq!(margin(&app), UiRect::all(Val::Px(12.)));
+        assert_eq!(app.world.get::<Style>(label).unwrap().flex_grow, 1.);
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("panel", |style| {
+                style.margin(Val::Px(2.));
+            });
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(16.));
+        app.update();
+        assert_eq!(margin(&app), UiRect::all(Val::Px(2.)));
+    }
+
+    #[test]
+    fn themed_styles_are_under_class_styles() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(8.));
+        let mut registry = app.world.resource_mut::<StyleRegistry>();
+        registry
+            .register("compact", |style| {
+                style.margin(Val::Px(2.));
+            })
+            .register_themed("panel", |style, tokens| {
+                style.margin(tokens.val("$spacing-2")).grow(1.);
+            });
+        assert_eq!(registry.priority("panel"), Some(StylePriority::Theme));
+        let panel = app.world.spawn(node().classes(["compact", "panel"])).id();
+        app.update();
+        let style = app.world.get::<Style>(panel).unwrap();
+        assert_eq!(style.margin, UiRect::all(Val::Px(2.)));
+        assert_eq!(style.flex_grow, 1.);
+    }
+
+    #[test]
+    fn media_queries_match_inclusive_bounds() {
+        let query = MediaQuery::default().min_width(800.).max_height(600.);
+        assert!(query.matches(Vec2::new(800., 600.)));
+        assert!(!query.matches(Vec2::new(799., 600.)));
+        assert!(!query.matches(Vec2::new(800., 601.)));
+        assert!(MediaQuery::default().matches(Vec2::ZERO));
+    }
+
+    #[derive(Component)]
+    struct HealthBarFill;
+
+    #[test]
+    fn selectors_style_nodes_by_component() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleSelectorPlugin::<HealthBarFill>::default());
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .select::<HealthBarFill>(|style| {
+                style.height(Val::Px(8.)).grow(1.);
+            })
+            .register("low", |style| {
+                style.grow(0.25);
+            });
+        let fill = app.world.spawn((node(), HealthBarFill)).id();
+        let low = app
+            .world
+            .spawn((node().classes(["low"]), HealthBarFill))
+            .id();
+        app.update();
+        let style = |app: &App, entity| app.world.get::<Style>(entity).unwrap().clone();
+        assert_eq!(style(&app, fill).size.height, Val::Px(8.));
+        assert_eq!(style(&app, fill).flex_grow, 1.);
+        assert_eq!(style(&app, low).size.height, Val::Px(8.));
+        assert_eq!(style(&app, low).flex_grow, 0.25);
+
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .select::<HealthBarFill>(|style| {
+                style.height(Val::Px(12.));
+            });
+        app.update();
+        assert_eq!(style(&app, fill).size.height, Val::Px(12.));
+        assert_eq!(style(&app, fill).flex_grow, 0.);
+
+        app.world.entity_mut(low).remove::<HealthBarFill>();
+        app.update();
+        assert_eq!(style(&app, low).size.height, Val::Auto);
+        assert_eq!(style(&app, low).flex_grow, 0.25);
+    }
+
+    #[test]
+    fn many_nodes_are_restyled_together() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(StyleRegistryPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("cell", |style| {
+                style.width(Val::Px(1.));
+            });
+        app.world
+            .spawn_batch((0..500).map(|_| node().classes(["cell"])));
+        app.update();
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("cell", |style| {
+                style.width(Val::Px(2.));
+            });
+        app.update();
+        let mut styles = app.world.query::<&Style>();
+        assert_eq!(styles.iter(&app.world).len(), 500);
+        assert!(styles
+            .iter(&app.world)
+            .all(|style| style.size.width == Val::Px(2.)));
+    }
+}
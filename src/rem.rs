@@ -0,0 +1,211 @@
+use crate::fields::StyleFields;
+use crate::fields::ValField;
+use bevy::prelude::*;
+use bevy::ui::widget::text_system;
+use bevy::ui::UiSystem;
+
+/// The size in pixels of one rem, the root font size that [`RemStyle`] values are multiples of.
+///
+/// Changing the base re-evaluates every [`RemStyle`] on the next frame,
+/// for example to apply a text size accessibility setting.
+#[derive(Resource, Copy, Clone, Debug, PartialEq)]
+pub struct RemBase {
+    pub font_size: f32,
+}
+
+impl Default for RemBase {
+    fn default() -> Self {
+        Self { font_size: 16. }
+    }
+}
+
+/// Style values authored in rems, kept so they can be evaluated again when the [`RemBase`] changes.
+///
+/// The edge values set all four edges.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RemStyle {
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub min_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_width: Option<f32>,
+    pub max_height: Option<f32>,
+    pub margin: Option<f32>,
+    pub padding: Option<f32>,
+    pub border: Option<f32>,
+    /// The font size of every section of the node's [`Text`].
+    pub font_size: Option<f32>,
+}
+
+impl RemStyle {
+    pub fn width(mut self, rem: f32) -> Self {
+        self.width = Some(rem);
+        self
+    }
+
+    pub fn height(mut self, rem: f32) -> Self {
+        self.height = Some(rem);
+        self
+    }
+
+    pub fn min_width(mut self, rem: f32) -> Self {
+        self.min_width = Some(rem);
+        self
+    }
+
+    pub fn min_height(mut self, rem: f32) -> Self {
+        self.min_height = Some(rem);
+        self
+    }
+
+    pub fn max_width(mut self, rem: f32) -> Self {
+        self.max_width = Some(rem);
+        self
+    }
+
+    pub fn max_height(mut self, rem: f32) -> Self {
+        self.max_height = Some(rem);
+        self
+    }
+
+    pub fn margin(mut self, rem: f32) -> Self {
+        self.margin = Some(rem);
+        self
+    }
+
+    pub fn padding(mut self, rem: f32) -> Self {
+        self.padding = Some(rem);
+        self
+    }
+
+    pub fn border(mut self, rem: f32) -> Self {
+        self.border = Some(rem);
+        self
+    }
+
+    pub fn font_size(mut self, rem: f32) -> Self {
+        self.font_size = Some(rem);
+        self
+    }
+
+    /// Write the rem values to `style`, with one rem being `base` pixels.
+    pub fn apply(&self, style: &mut Style, base: f32) {
+        let px = |rem: f32| Val::Px(rem * base);
+        let fields: [(Option<f32>, ValField); 6] = [
+            (self.width, Style::width_mut),
+            (self.height, Style::height_mut),
+            (self.min_width, Style::min_width_mut),
+            (self.min_height, Style::min_height_mut),
+            (self.max_width, Style::max_width_mut),
+            (self.max_height, Style::max_height_mut),
+        ];
+        for (rem, field) in fields {
+            if let Some(rem) = rem {
+                *field(style) = px(rem);
+            }
+        }
+        let rects = [
+            (self.margin, &mut style.margin),
+            (self.padding, &mut style.padding),
+            (self.border, &mut style.border),
+        ];
+        for (rem, rect) in rects {
+            if let Some(rem) = rem {
+                *rect = UiRect::all(px(rem));
+            }
+        }
+    }
+}
+
+pub trait RemStyleExt: Bundle + Sized {
+    /// Set some of the node's style in rems.
+    fn rem(self, rem: RemStyle) -> (Self, RemStyle) {
+        (self, rem)
+    }
+}
+
+impl<T: Bundle> RemStyleExt for T {}
+
+/// Evaluates each new or changed [`RemStyle`], or every one when the [`RemBase`] has changed.
+pub fn rem_style_system(
+    base: Res<RemBase>,
+    mut query: Query<(
+        &RemStyle,
+        ChangeTrackers<RemStyle>,
+        &mut Style,
+        Option<&mut Text>,
+    )>,
+) {
+    for (rem, tracker, mut style, text) in query.iter_mut() {
+        if !(base.is_changed() || tracker.is_changed()) {
+            continue;
+        }
+        rem.apply(&mut style, base.font_size);
+        if let (Some(font_size), Some(mut text)) = (rem.font_size, text) {
+            for section in text.sections.iter_mut() {
+                section.style.font_size = font_size * base.font_size;
+            }
+        }
+    }
+}
+
+/// Adds [`RemBase`] and the system that evaluates rem values.
+pub struct StyleRemPlugin;
+
+impl Plugin for StyleRemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RemBase>().add_system_to_stage(
+            CoreStage::PostUpdate,
+            rem_style_system.before(text_system).before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn rem_values_are_written_in_pixels() {
+        let mut style = style().height(Val::Percent(50.));
+        RemStyle::default()
+            .width(2.)
+            .padding(0.5)
+            .apply(&mut style, 10.);
+        assert_eq!(style.size, Size::new(Val::Px(20.), Val::Percent(50.)));
+        assert_eq!(style.padding, UiRect::all(Val::Px(5.)));
+        assert_eq!(style.margin, UiRect::default());
+    }
+
+    #[test]
+    fn changing_the_base_rescales_the_tree() {
+        let mut app = App::new();
+        app.add_plugin(StyleRemPlugin);
+        let panel = app
+            .world
+            .spawn(node().rem(RemStyle::default().width(10.)))
+            .id();
+        let label = app
+            .world
+            .spawn(
+                TextBundle::from_section("label", TextStyle::default())
+                    .rem(RemStyle::default().font_size(1.5)),
+            )
+            .id();
+        app.update();
+        let width = |app: &App| app.world.get::<Style>(panel).unwrap().size.width;
+        let font_size = |app: &App| {
+            app.world.get::<Text>(label).unwrap().sections[0]
+                .style
+                .font_size
+        };
+        assert_eq!(width(&app), Val::Px(160.));
+        assert_eq!(font_size(&app), 24.);
+        app.world.resource_mut::<RemBase>().font_size = 20.;
+        app.update();
+        assert_eq!(width(&app), Val::Px(200.));
+        assert_eq!(font_size(&app), 30.);
+    }
+}
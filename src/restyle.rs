@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use std::sync::Arc;
+
+/// A reusable modification of a [`Style`].
+#[derive(Clone)]
+pub struct StylePatch(Arc<dyn Fn(&mut Style) + Send + Sync>);
+
+impl StylePatch {
+    pub fn new(patch: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(patch))
+    }
+
+    /// Apply the patch to `style`.
+    pub fn apply(&self, style: &mut Style) {
+        (self.0)(style);
+    }
+
+    /// Whether `self` and `other` are clones of the same patch.
+    pub fn ptr_eq(&self, other: &StylePatch) -> bool {
+        std::ptr::eq(
+            Arc::as_ptr(&self.0) as *const (),
+            Arc::as_ptr(&other.0) as *const (),
+        )
+    }
+
+    /// A patch that applies `self` and then `next`.
+    pub fn then(self, next: StylePatch) -> Self {
+        Self::new(move |style| {
+            self.apply(style);
+            next.apply(style);
+        })
+    }
+}
+
+impl Default for StylePatch {
+    fn default() -> Self {
+        Self::new(|_| {})
+    }
+}
+
+impl std::fmt::Debug for StylePatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StylePatch").finish_non_exhaustive()
+    }
+}
+
+/// Request that `patch` is applied to the [`Style`] of `target`.
+#[derive(Clone, Debug)]
+pub struct RestyleEvent {
+    pub target: Entity,
+    pub patch: StylePatch,
+}
+
+impl RestyleEvent {
+    pub fn new(target: Entity, patch: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        Self {
+            target,
+            patch: StylePatch::new(patch),
+        }
+    }
+}
+
+/// Applies every [`RestyleEvent`] sent since the last run.
+pub fn restyle_event_system(mut events: EventReader<RestyleEvent>, mut query: Query<&mut Style>) {
+    for RestyleEvent { target, patch } in events.iter() {
+        if let Ok(mut style) = query.get_mut(*target) {
+            patch.apply(&mut style);
+        }
+    }
+}
+
+/// Adds [`RestyleEvent`] and the system that consumes it.
+pub struct RestylePlugin;
+
+impl Plugin for RestylePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RestyleEvent>().add_system_to_stage(
+            CoreStage::PostUpdate,
+            restyle_event_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn restyle_event_applies_patch() {
+        let mut app = App::new();
+        app.add_plugin(RestylePlugin);
+        let target = app.world.spawn(node()).id();
+        app.world.send_event(RestyleEvent::new(target, |style| {
+            style.width(Val::Px(10.)).column();
+        }));
+        app.update();
+        let style = app.world.get::<Style>(target).unwrap();
+        assert_eq!(style.size.width, Val::Px(10.));
+        assert_eq!(style.flex_direction, FlexDirection::Column);
+    }
+
+    #[test]
+    fn patches_compose() {
+        let patch = StylePatch::new(|style| {
+            style.width(Val::Px(1.));
+        })
+        .then(StylePatch::new(|style| {
+            style.width(Val::Px(2.)).height(Val::Px(3.));
+        }));
+        let mut style = style();
+        patch.apply(&mut style);
+        assert_eq!(style.size, Size::new(Val::Px(2.), Val::Px(3.)));
+    }
+}
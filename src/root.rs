@@ -0,0 +1,324 @@
+use crate::fields::set_if_neq;
+use crate::fields::StyleFields;
+use crate::scale::scaled_style_system;
+use crate::scale::UiStyleScale;
+use crate::viewport::viewport_size;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::ui::UiSystem;
+
+/// Keeps a root node sized in pixels to exactly cover the primary window,
+/// following it as the window is resized or its scale factor changes.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct FullscreenRoot;
+
+/// A transparent root node covering the primary window that lets interaction pass through.
+pub fn fullscreen_root() -> (NodeBundle, FullscreenRoot) {
+    (
+        NodeBundle {
+            style: crate::style().absolute().left(Val::Px(0.)).top(Val::Px(0.)),
+            background_color: Color::NONE.into(),
+            focus_policy: FocusPolicy::Pass,
+            ..default()
+        },
+        FullscreenRoot,
+    )
+}
+
+/// Sets the size of each [`FullscreenRoot`] to the size of the primary window.
+pub fn fullscreen_root_system(
+    windows: Option<Res<Windows>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut query: Query<&mut Style, With<FullscreenRoot>>,
+) {
+    let Some(viewport) = windows.and_then(|windows| viewport_size(&windows, ui_scale.as_deref()))
+    else {
+        return;
+    };
+    for mut style in query.iter_mut() {
+        set_if_neq(&mut style, Style::width_mut, Val::Px(viewport.x));
+        set_if_neq(&mut style, Style::height_mut, Val::Px(viewport.y));
+    }
+}
+
+/// Lays out the children of a [`FullscreenRoot`] in the largest centered area with the
+/// aspect ratio `aspect`, covering the rest of the window with bars of `bar_color`.
+///
+/// The bars are [`LetterboxBar`] child nodes, so the area itself shows whatever is beneath the root.
+///
+/// With a `design_height`, the [`UiStyleScale`] is set so that styles authored at the design
+/// resolution with [`ScaledStyleExt::scaled`](crate::scale::ScaledStyleExt::scaled) fill the area.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Letterbox {
+    /// The width of the area divided by its height.
+    pub aspect: f32,
+    /// The height in pixels of the area the UI was designed for.
+    pub design_height: Option<f32>,
+    pub bar_color: Color,
+}
+
+impl FromWorld for Letterbox {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(1.)
+    }
+}
+
+impl Letterbox {
+    pub fn new(aspect: f32) -> Self {
+        Self {
+            aspect,
+            design_height: None,
+            bar_color: Color::BLACK,
+        }
+    }
+
+    pub fn with_design_height(mut self, design_height: f32) -> Self {
+        self.design_height = Some(design_height);
+        self
+    }
+
+    pub fn with_bar_color(mut self, bar_color: Color) -> Self {
+        self.bar_color = bar_color;
+        self
+    }
+}
+
+/// One of the bars a [`Letterbox`] adds around its area, sized to the space on its side.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum LetterboxBar {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl LetterboxBar {
+    const ALL: [Self; 4] = [Self::Left, Self::Right, Self::Top, Self::Bottom];
+
+    /// The part of a viewport of `size` outside `area` on this bar's side.
+    pub fn rect(self, size: Vec2, area: Rect) -> Rect {
+        match self {
+            Self::Left => Rect::new(0., 0., area.min.x, size.y),
+            Self::Right => Rect::new(area.max.x, 0., size.x, size.y),
+            Self::Top => Rect::new(0., 0., size.x, area.min.y),
+            Self::Bottom => Rect::new(0., area.max.y, size.x, size.y),
+        }
+    }
+}
+
+/// A transparent root node covering the primary window with black bars around a centered area of `aspect`.
+pub fn letterboxed_root(aspect: f32) -> (NodeBundle, FullscreenRoot, Letterbox) {
+    let (root, marker) = fullscreen_root();
+    (root, marker, Letterbox::new(aspect))
+}
+
+/// Adds the [`LetterboxBar`]s to each new [`Letterbox`] that doesn't have them yet.
+pub fn spawn_letterbox_bars_system(
+    mut commands: Commands,
+    letterboxes: Query<(Entity, &Letterbox, Option<&Children>), Added<Letterbox>>,
+    bars: Query<(), With<LetterboxBar>>,
+) {
+    for (entity, letterbox, children) in letterboxes.iter() {
+        if children.is_some_and(|children| children.iter().any(|&child| bars.contains(child))) {
+            continue;
+        }
+        commands.entity(entity).with_children(|parent| {
+            for bar in LetterboxBar::ALL {
+                parent.spawn((
+                    NodeBundle {
+                        style: crate::style().absolute(),
+                        background_color: letterbox.bar_color.into(),
+                        focus_policy: FocusPolicy::Pass,
+                        ..default()
+                    },
+                    bar,
+                ));
+            }
+        });
+    }
+}
+
+/// The largest area with the aspect ratio `aspect` centered in a viewport of `size`.
+pub fn letterbox_area(size: Vec2, aspect: f32) -> Rect {
+    let area = if size.y * aspect <= size.x {
+        Vec2::new(size.y * aspect, size.y)
+    } else {
+        Vec2::new(size.x, size.x / aspect)
+    };
+    Rect::from_center_size(0.5 * size, area)
+}
+
+/// Pads each [`Letterbox`] to its area of the window, fits its bars around the area,
+/// and updates the [`UiStyleScale`].
+#[allow(clippy::type_complexity)]
+pub fn letterbox_system(
+    windows: Option<Res<Windows>>,
+    ui_scale: Option<Res<UiScale>>,
+    style_scale: Option<ResMut<UiStyleScale>>,
+    mut query: Query<(&Letterbox, &mut Style)>,
+    mut bars: Query<(&LetterboxBar, &Parent, &mut Style, &mut BackgroundColor), Without<Letterbox>>,
+) {
+    let Some(viewport) = windows.and_then(|windows| viewport_size(&windows, ui_scale.as_deref()))
+    else {
+        return;
+    };
+    let mut scale = None;
+    for (letterbox, mut style) in query.iter_mut() {
+        let area = letterbox_area(viewport, letterbox.aspect);
+        let padding = UiRect::new(
+            Val::Px(area.min.x),
+            Val::Px(viewport.x - area.max.x),
+            Val::Px(area.min.y),
+            Val::Px(viewport.y - area.max.y),
+        );
+        if style.padding != padding {
+            style.padding = padding;
+        }
+        if let Some(design_height) = letterbox.design_height {
+            scale = Some(area.height() / design_height);
+        }
+    }
+    for (bar, parent, mut style, mut color) in bars.iter_mut() {
+        let Ok((letterbox, _)) = query.get(parent.get()) else {
+            continue;
+        };
+        let rect = bar.rect(viewport, letterbox_area(viewport, letterbox.aspect));
+        set_if_neq(&mut style, Style::left_mut, Val::Px(rect.min.x));
+        set_if_neq(&mut style, Style::top_mut, Val::Px(rect.min.y));
+        set_if_neq(&mut style, Style::width_mut, Val::Px(rect.width()));
+        set_if_neq(&mut style, Style::height_mut, Val::Px(rect.height()));
+        if color.0 != letterbox.bar_color {
+            color.0 = letterbox.bar_color;
+        }
+    }
+    if let (Some(scale), Some(mut style_scale)) = (scale, style_scale) {
+        if style_scale.scale != scale {
+            style_scale.scale = scale;
+        }
+    }
+}
+
+/// Adds the systems that size root nodes to the window.
+pub struct StyleRootPlugin;
+
+impl Plugin for StyleRootPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            fullscreen_root_system.before(UiSystem::Flex),
+        )
+        .add_system_to_stage(CoreStage::PostUpdate, spawn_letterbox_bars_system)
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            letterbox_system
+                .before(scaled_style_system)
+                .before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::window::WindowId;
+
+    fn window(width: f32, height: f32, scale_factor: f64) -> Windows {
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor {
+                width,
+                height,
+                ..default()
+            },
+            (width as f64 * scale_factor) as u32,
+            (height as f64 * scale_factor) as u32,
+            scale_factor,
+            None,
+            None,
+        ));
+        windows
+    }
+
+    #[test]
+    fn root_follows_the_window() {
+        let mut app = App::new();
+        app.insert_resource(window(800., 600., 1.))
+            .add_plugin(StyleRootPlugin);
+        let root = app.world.spawn(fullscreen_root()).id();
+        app.update();
+        let size = |app: &App| app.world.get::<Style>(root).unwrap().size;
+        assert_eq!(size(&app), Size::new(Val::Px(800.), Val::Px(600.)));
+        app.insert_resource(window(1280., 720., 2.));
+        app.update();
+        assert_eq!(size(&app), Size::new(Val::Px(1280.), Val::Px(720.)));
+    }
+
+    #[test]
+    fn area_is_centered_with_bars() {
+        let wide = letterbox_area(Vec2::new(1000., 450.), 16. / 9.);
+        assert_eq!(wide, Rect::new(100., 0., 900., 450.));
+        let tall = letterbox_area(Vec2::new(800., 600.), 16. / 9.);
+        assert_eq!(tall, Rect::new(0., 75., 800., 525.));
+    }
+
+    #[test]
+    fn letterbox_pads_the_root_and_scales_styles() {
+        let mut app = App::new();
+        app.insert_resource(window(1000., 450., 1.))
+            .add_plugin(StyleRootPlugin)
+            .add_plugin(crate::scale::StyleScalePlugin);
+        let (root, marker, letterbox) = letterboxed_root(16. / 9.);
+        let root = app
+            .world
+            .spawn((root, marker, letterbox.with_design_height(900.)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<Style>(root).unwrap().padding,
+            UiRect::new(Val::Px(100.), Val::Px(100.), Val::Px(0.), Val::Px(0.))
+        );
+        assert_eq!(app.world.resource::<UiStyleScale>().scale, 0.5);
+    }
+
+    #[test]
+    fn bars_leave_the_area_transparent() {
+        let mut app = App::new();
+        app.insert_resource(window(1000., 450., 1.))
+            .add_plugin(StyleRootPlugin);
+        let root = app.world.spawn(letterboxed_root(16. / 9.)).id();
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world.get::<BackgroundColor>(root).unwrap().0,
+            Color::NONE
+        );
+        let area = Rect::new(100., 0., 900., 450.);
+        let children = app.world.get::<Children>(root).unwrap();
+        assert_eq!(children.len(), 4);
+        let px = |val: Val| match val {
+            Val::Px(value) => value,
+            val => panic!("{val:?}"),
+        };
+        let mut covered = 0.;
+        for &child in children.iter() {
+            assert!(app.world.get::<LetterboxBar>(child).is_some());
+            assert_eq!(
+                app.world.get::<BackgroundColor>(child).unwrap().0,
+                Color::BLACK
+            );
+            let style = app.world.get::<Style>(child).unwrap();
+            let min = Vec2::new(px(style.position.left), px(style.position.top));
+            let size = Vec2::new(px(style.size.width), px(style.size.height));
+            let bar = Rect::from_corners(min, min + size);
+            assert!(bar.intersect(area).is_empty(), "{bar:?}");
+            covered += size.x * size.y;
+        }
+        assert_eq!(covered, 2. * 100. * 450.);
+    }
+}
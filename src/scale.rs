@@ -0,0 +1,234 @@
+use crate::InteractiveExt;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// A factor applied to the pixel values of every [`ScaledStyle`], such as an in-game UI size setting.
+///
+/// Unlike Bevy's [`UiScale`], only the pixel values of the opted-in styles are scaled,
+/// percentages, text and images are left as they are.
+#[derive(Resource, Copy, Clone, Debug, PartialEq)]
+pub struct UiStyleScale {
+    pub scale: f32,
+}
+
+impl Default for UiStyleScale {
+    fn default() -> Self {
+        Self { scale: 1. }
+    }
+}
+
+/// The style of a node as authored, before its pixel values are multiplied by the [`UiStyleScale`].
+///
+/// Make changes to the authored style here rather than to the node's [`Style`],
+/// which is overwritten whenever the authored style or the scale changes.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ScaledStyle(pub Style);
+
+/// `val` with its pixel value multiplied by `factor`.
+pub fn scale_val(val: Val, factor: f32) -> Val {
+    match val {
+        Val::Px(value) => Val::Px(value * factor),
+        val => val,
+    }
+}
+
+fn scale_rect(rect: UiRect, factor: f32) -> UiRect {
+    UiRect::new(
+        scale_val(rect.left, factor),
+        scale_val(rect.right, factor),
+        scale_val(rect.top, factor),
+        scale_val(rect.bottom, factor),
+    )
+}
+
+fn scale_size(size: Size, factor: f32) -> Size {
+    Size::new(
+        scale_val(size.width, factor),
+        scale_val(size.height, factor),
+    )
+}
+
+/// `style` with each of its pixel values multiplied by `factor`.
+pub fn scale_style(style: &Style, factor: f32) -> Style {
+    Style {
+        position: scale_rect(style.position, factor),
+        margin: scale_rect(style.margin, factor),
+        padding: scale_rect(style.padding, factor),
+        border: scale_rect(style.border, factor),
+        flex_basis: scale_val(style.flex_basis, factor),
+        size: scale_size(style.size, factor),
+        min_size: scale_size(style.min_size, factor),
+        max_size: scale_size(style.max_size, factor),
+        ..style.clone()
+    }
+}
+
+/// A node bundle whose pixel values are multiplied by the [`UiStyleScale`], returned by [`ScaledStyleExt::scaled`].
+///
+/// Builder calls made on it update both the node's [`Style`] and its authored [`ScaledStyle`],
+/// so they can come before or after `.scaled()`.
+#[derive(Bundle, Clone, Debug)]
+pub struct ScaledBundle<T: Bundle> {
+    pub node: T,
+    pub authored: ScaledStyle,
+}
+
+impl<T: StyleBuilderExt + Bundle> StyleBuilderExt for ScaledBundle<T> {
+    fn update_style(mut self, s: impl FnOnce(&mut Style)) -> Self {
+        s(&mut self.authored.0);
+        Self {
+            node: self.node.style(self.authored.0.clone()),
+            authored: self.authored,
+        }
+    }
+}
+
+impl<T: NodeColorExt + Bundle> NodeColorExt for ScaledBundle<T> {
+    fn background_color(self, color: Color) -> Self {
+        Self {
+            node: self.node.background_color(color),
+            authored: self.authored,
+        }
+    }
+}
+
+impl<T: InteractiveExt> InteractiveExt for ScaledBundle<T> {
+    fn interactive(self) -> (Self, Interaction) {
+        let (node, interaction) = self.node.interactive();
+        let authored = self.authored;
+        (Self { node, authored }, interaction)
+    }
+}
+
+pub trait ScaledStyleExt: Bundle + Sized {
+    /// Multiply the node's pixel values by the [`UiStyleScale`].
+    fn scaled(self) -> ScaledBundle<Self>;
+}
+
+macro_rules! impl_scaled_style_for_bundles {
+    ($($bundle:ty),* $(,)?) => {
+        $(
+            impl ScaledStyleExt for $bundle {
+                fn scaled(self) -> ScaledBundle<Self> {
+                    let authored = ScaledStyle(self.style.clone());
+                    ScaledBundle {
+                        node: self,
+                        authored,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scaled_style_for_bundles!(NodeBundle, TextBundle, ImageBundle, ButtonBundle);
+
+impl<T: ScaledStyleExt, B: Bundle> ScaledStyleExt for (T, B) {
+    fn scaled(self) -> ScaledBundle<Self> {
+        let ScaledBundle { node, authored } = self.0.scaled();
+        ScaledBundle {
+            node: (node, self.1),
+            authored,
+        }
+    }
+}
+
+/// Writes each changed [`ScaledStyle`] to its node, multiplied by the current [`UiStyleScale`].
+pub fn scaled_style_system(
+    scale: Res<UiStyleScale>,
+    mut query: Query<(&ScaledStyle, ChangeTrackers<ScaledStyle>, &mut Style)>,
+) {
+    for (authored, tracker, mut style) in query.iter_mut() {
+        if scale.is_changed() || tracker.is_changed() {
+            *style = scale_style(&authored.0, scale.scale);
+        }
+    }
+}
+
+/// Adds [`UiStyleScale`] and the system that applies it.
+pub struct StyleScalePlugin;
+
+impl Plugin for StyleScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiStyleScale>().add_system_to_stage(
+            CoreStage::PostUpdate,
+            scaled_style_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn only_pixel_values_are_scaled() {
+        let style = style()
+            .width(Val::Px(100.))
+            .height(Val::Percent(50.))
+            .margin(Val::Auto)
+            .padding(Breadth::Px(4.))
+            .grow(1.);
+        let scaled = scale_style(&style, 1.5);
+        assert_eq!(scaled.size, Size::new(Val::Px(150.), Val::Percent(50.)));
+        assert_eq!(scaled.margin, UiRect::all(Val::Auto));
+        assert_eq!(scaled.padding, UiRect::all(Val::Px(6.)));
+        assert_eq!(scaled.flex_grow, 1.);
+    }
+
+    #[test]
+    fn changing_the_scale_rescales_nodes() {
+        let mut app = App::new();
+        app.add_plugin(StyleScalePlugin);
+        let scaled = app.world.spawn(node().width(Val::Px(10.)).scaled()).id();
+        let fixed = app.world.spawn(node().width(Val::Px(10.))).id();
+        app.update();
+        let width = |app: &App, entity| app.world.get::<Style>(entity).unwrap().size.width;
+        assert_eq!(width(&app, scaled), Val::Px(10.));
+        app.world.resource_mut::<UiStyleScale>().scale = 2.;
+        app.update();
+        assert_eq!(width(&app, scaled), Val::Px(20.));
+        assert_eq!(width(&app, fixed), Val::Px(10.));
+        app.world
+            .get_mut::<ScaledStyle>(scaled)
+            .unwrap()
+            .0
+            .size
+            .width = Val::Px(4.);
+        app.update();
+        assert_eq!(width(&app, scaled), Val::Px(8.));
+    }
+
+    #[test]
+    fn builder_calls_after_scaled_are_kept() {
+        let mut app = App::new();
+        app.add_plugin(StyleScalePlugin);
+        app.world.resource_mut::<UiStyleScale>().scale = 2.;
+        let scaled = app
+            .world
+            .spawn(
+                node()
+                    .width(Val::Px(10.))
+                    .scaled()
+                    .height(Val::Px(5.))
+                    .background_color(Color::RED)
+                    .named("scaled"),
+            )
+            .id();
+        app.update();
+        let style = app.world.get::<Style>(scaled).unwrap();
+        assert_eq!(style.size, Size::new(Val::Px(20.), Val::Px(10.)));
+        assert_eq!(
+            app.world.get::<ScaledStyle>(scaled).unwrap().0.size,
+            Size::new(Val::Px(10.), Val::Px(5.))
+        );
+        assert_eq!(
+            app.world.get::<BackgroundColor>(scaled).unwrap().0,
+            Color::RED
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::anchor::Anchor;
+use crate::anchor::Anchored;
+use crate::constraint::MatchSize;
+use crate::interaction::ColorStates;
+use crate::interaction::Disabled;
+use crate::interaction::DisabledVisuals;
+use crate::registry::StyleClasses;
+use crate::rem::RemStyle;
+use crate::root::FullscreenRoot;
+use crate::root::Letterbox;
+use crate::root::LetterboxBar;
+use crate::scale::ScaledStyle;
+use crate::snap::SnapToPixels;
+use crate::text::FitText;
+use crate::text::TruncateText;
+use crate::viewport::ViewportLength;
+use crate::viewport::ViewportSize;
+use crate::viewport::ViewportUnit;
+use crate::widgets::backdrop::Backdrop;
+use crate::widgets::badge::Pill;
+use crate::widgets::badge::PillColors;
+use crate::widgets::breadcrumbs::BreadcrumbSegment;
+use crate::widgets::breadcrumbs::Breadcrumbs;
+use crate::widgets::canvas::Canvas;
+use crate::widgets::canvas::CanvasPosition;
+use crate::widgets::collapsible::Collapsible;
+use crate::widgets::collapsible::CollapsibleContent;
+use crate::widgets::collapsible::CollapsibleHeader;
+use crate::widgets::collapsible::CollapsibleIndicator;
+use crate::widgets::dock::DockArea;
+use crate::widgets::dock::DockGroup;
+use crate::widgets::dock::DockLayout;
+use crate::widgets::dock::DockNode;
+use crate::widgets::dock::DockPanel;
+use crate::widgets::dock::DockTab;
+use crate::widgets::floating_panel::FloatingPanel;
+use crate::widgets::floating_panel::PanelCloseButton;
+use crate::widgets::floating_panel::PanelTitleBar;
+use crate::widgets::image_fallback::Fallback;
+use crate::widgets::image_fallback::ImageWithFallback;
+use crate::widgets::menu_bar::Menu;
+use crate::widgets::menu_bar::MenuBar;
+use crate::widgets::menu_bar::MenuButton;
+use crate::widgets::menu_bar::MenuItem;
+use crate::widgets::menu_bar::MenuPopup;
+use crate::widgets::menu_bar::MenuState;
+use crate::widgets::pager::Pager;
+use crate::widgets::pager::PagerDot;
+use crate::widgets::pager::PagerDots;
+use crate::widgets::pager::PagerNext;
+use crate::widgets::pager::PagerPrevious;
+use crate::widgets::pager::PagerTrack;
+use crate::widgets::pager::PagerViewport;
+use crate::widgets::reorderable_list::ReorderableList;
+use crate::widgets::skeleton::Skeleton;
+use crate::widgets::skeleton::SkeletonHighlight;
+use crate::widgets::spinner::CircularProgress;
+use crate::widgets::stepper::Stepper;
+use crate::widgets::stepper::StepperButton;
+use crate::widgets::stepper::StepperValue;
+use crate::widgets::table::ColumnResizeHandle;
+use crate::widgets::table::TableCell;
+use crate::widgets::table::TableHeader;
+use crate::widgets::table::TableRow;
+use bevy::prelude::*;
+#[cfg(feature = "scene")]
+use bevy::scene::DynamicScene;
+use std::borrow::Cow;
+
+/// Registers the crate's components for reflection, so nodes built with them
+/// can be saved to and loaded from scenes.
+///
+/// Components holding closures, such as [`ContentMeasure`](crate::measure::ContentMeasure)
+/// and the animation components, can't be reflected and are left out of scenes.
+/// Of the widgets, only [`Table`](crate::widgets::table::Table), which holds its cell factory,
+/// is left out. The [`Pager`]'s timing may hold a custom easing function, so it isn't saved.
+/// A widget's drag or hold in progress isn't saved either.
+pub struct StyleReflectPlugin;
+
+impl Plugin for StyleReflectPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Anchor>()
+            .register_type::<Anchored>()
+            .register_type::<Backdrop>()
+            .register_type::<BreadcrumbSegment>()
+            .register_type::<Breadcrumbs>()
+            .register_type::<Canvas>()
+            .register_type::<CanvasPosition>()
+            .register_type::<CircularProgress>()
+            .register_type::<Collapsible>()
+            .register_type::<CollapsibleContent>()
+            .register_type::<CollapsibleHeader>()
+            .register_type::<CollapsibleIndicator>()
+            .register_type::<ColorStates>()
+            .register_type::<ColumnResizeHandle>()
+            .register_type::<Disabled>()
+            .register_type::<DisabledVisuals>()
+            .register_type::<DockArea>()
+            .register_type::<DockGroup>()
+            .register_type::<DockLayout>()
+            .register_type::<DockNode>()
+            .register_type::<DockPanel>()
+            .register_type::<DockTab>()
+            .register_type::<Fallback>()
+            .register_type::<FitText>()
+            .register_type::<FloatingPanel>()
+            .register_type::<FullscreenRoot>()
+            .register_type::<ImageWithFallback>()
+            .register_type::<Letterbox>()
+            .register_type::<LetterboxBar>()
+            .register_type::<MatchSize>()
+            .register_type::<Menu>()
+            .register_type::<MenuBar>()
+            .register_type::<MenuButton>()
+            .register_type::<MenuItem>()
+            .register_type::<MenuPopup>()
+            .register_type::<MenuState>()
+            .register_type::<Pager>()
+            .register_type::<PagerDot>()
+            .register_type::<PagerDots>()
+            .register_type::<PagerNext>()
+            .register_type::<PagerPrevious>()
+            .register_type::<PagerTrack>()
+            .register_type::<PagerViewport>()
+            .register_type::<PanelCloseButton>()
+            .register_type::<PanelTitleBar>()
+            .register_type::<Pill>()
+            .register_type::<PillColors>()
+            .register_type::<RemStyle>()
+            .register_type::<ReorderableList>()
+            .register_type::<ScaledStyle>()
+            .register_type::<Skeleton>()
+            .register_type::<SkeletonHighlight>()
+            .register_type::<SnapToPixels>()
+            .register_type::<Stepper>()
+            .register_type::<StepperButton>()
+            .register_type::<StepperValue>()
+            .register_type::<StyleClasses>()
+            .register_type::<TableCell>()
+            .register_type::<TableHeader>()
+            .register_type::<TableRow>()
+            .register_type::<TruncateText>()
+            .register_type::<ViewportLength>()
+            .register_type::<ViewportSize>()
+            .register_type::<ViewportUnit>()
+            .register_type::<Cow<'static, str>>()
+            .register_type::<Vec<Cow<'static, str>>>()
+            .register_type::<Vec<String>>()
+            .register_type::<Vec<Color>>()
+            .register_type::<Option<Color>>()
+            .register_type::<Option<f32>>()
+            .register_type::<Option<ViewportLength>>()
+            .register_type::<Option<String>>()
+            .register_type::<Option<usize>>()
+            .register_type::<Vec<usize>>()
+            .register_type::<Vec<Menu>>()
+            .register_type::<Vec<MenuItem>>();
+    }
+}
+
+/// A scene of the nodes spawned by `build` into an empty [`World`],
+/// so a UI can be saved without spawning it in the app.
+///
+/// Only components registered in `type_registry` are included.
+#[cfg(feature = "scene")]
+pub fn ui_scene(type_registry: &AppTypeRegistry, build: impl FnOnce(&mut World)) -> DynamicScene {
+    let mut world = World::new();
+    build(&mut world);
+    DynamicScene::from_world(&world, type_registry)
+}
+
+#[cfg(all(test, feature = "scene"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::viewport::ViewportSizeExt;
+    use bevy::ecs::entity::EntityMap;
+
+    fn type_registry() -> AppTypeRegistry {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(HierarchyPlugin)
+            .add_plugin(StyleReflectPlugin)
+            .register_type::<Style>()
+            .register_type::<Display>()
+            .register_type::<PositionType>()
+            .register_type::<Direction>()
+            .register_type::<FlexDirection>()
+            .register_type::<FlexWrap>()
+            .register_type::<AlignItems>()
+            .register_type::<AlignSelf>()
+            .register_type::<AlignContent>()
+            .register_type::<JustifyContent>()
+            .register_type::<Overflow>()
+            .register_type::<UiRect>()
+            .register_type::<Size>()
+            .register_type::<Val>()
+            .register_type::<Color>()
+            .register_type::<TextStyle>()
+            .register_type::<Handle<Font>>()
+            .register_type::<Handle<Image>>()
+            .register_type::<bevy::asset::HandleId>()
+            .register_type::<std::time::Duration>();
+        app.world.resource::<AppTypeRegistry>().clone()
+    }
+
+    fn build_panel(world: &mut World) {
+        let header = world
+            .spawn((node().classes(["header"]), Name::new("header")))
+            .id();
+        let body = world
+            .spawn((
+                node().viewport_height(ViewportUnit::Vh(50.)),
+                MatchSize::width_of(header),
+            ))
+            .id();
+        world
+            .spawn((node().width(Val::Px(200.)), Anchored::new(Anchor::Center)))
+            .push_children(&[header, body]);
+    }
+
+    fn load(scene: &DynamicScene, type_registry: &AppTypeRegistry) -> World {
+        let mut world = World::new();
+        world.insert_resource(type_registry.clone());
+        scene
+            .write_to_world(&mut world, &mut EntityMap::default())
+            .unwrap();
+        world
+    }
+
+    fn check_panel(world: &mut World) {
+        let (panel, anchored, style) = world
+            .query_filtered::<(Entity, &Anchored, &Style), Without<Parent>>()
+            .single(world);
+        assert_eq!(*anchored, Anchored::new(Anchor::Center));
+        assert_eq!(style.size.width, Val::Px(200.));
+        let children = world.get::<Children>(panel).unwrap().to_vec();
+        let header = world.get::<StyleClasses>(children[0]).unwrap();
+        assert_eq!(header.names().collect::<Vec<_>>(), vec!["header"]);
+        assert_eq!(
+            world.get::<MatchSize>(children[1]).unwrap().target,
+            children[0]
+        );
+        assert_eq!(
+            world.get::<ViewportSize>(children[1]).unwrap().height,
+            Some(ViewportUnit::Vh(50.).into())
+        );
+    }
+
+    #[test]
+    fn builder_trees_round_trip_through_scenes() {
+        let type_registry = type_registry();
+        let scene = ui_scene(&type_registry, build_panel);
+        assert_eq!(scene.entities.len(), 3);
+        check_panel(&mut load(&scene, &type_registry));
+    }
+
+    fn build_widgets(world: &mut World) {
+        let pager = world.spawn(Pager::new()).id();
+        world.spawn(PagerDot { pager, index: 2 });
+        world.spawn(Pill::new("3", PillColors::BADGE));
+        world.spawn(MenuBar {
+            menus: vec![Menu::new("File")
+                .item("Open", "open")
+                .submenu(Menu::new("Recent").item("a.txt", "recent"))],
+            ..default()
+        });
+        world.spawn(DockArea::new(DockLayout::Tabs {
+            panels: vec!["files".to_string()],
+            active: 0,
+        }));
+    }
+
+    fn check_widgets(world: &mut World) {
+        let pager = world.query_filtered::<Entity, With<Pager>>().single(world);
+        let dot = world.query::<&PagerDot>().single(world);
+        assert_eq!((dot.pager, dot.index), (pager, 2));
+        let pill = world.query::<&Pill>().single(world);
+        assert_eq!(pill.text, "3");
+        assert_eq!(pill.colors, PillColors::BADGE);
+        let bar = world.query::<&MenuBar>().single(world);
+        assert!(matches!(
+            &bar.menus[0].items[1],
+            MenuItem::Submenu(menu) if menu.label == "Recent"
+        ));
+        let dock = world.query::<&DockArea>().single(world);
+        assert!(matches!(&dock.layout, DockLayout::Tabs { panels, .. } if panels == &["files"]));
+    }
+
+    #[test]
+    fn widgets_round_trip_through_scenes() {
+        let type_registry = type_registry();
+        let scene = ui_scene(&type_registry, build_widgets);
+        check_widgets(&mut load(&scene, &type_registry));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn builder_scenes_round_trip_through_ron() {
+        use bevy::scene::serde::SceneDeserializer;
+        use serde::de::DeserializeSeed;
+
+        let type_registry = type_registry();
+        let ron = ui_scene(&type_registry, build_panel)
+            .serialize_ron(&type_registry)
+            .unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &type_registry.read(),
+        }
+        .deserialize(&mut ron::Deserializer::from_str(&ron).unwrap())
+        .unwrap();
+        check_panel(&mut load(&scene, &type_registry));
+        let ron = ui_scene(&type_registry, build_widgets)
+            .serialize_ron(&type_registry)
+            .unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &type_registry.read(),
+        }
+        .deserialize(&mut ron::Deserializer::from_str(&ron).unwrap())
+        .unwrap();
+        check_widgets(&mut load(&scene, &type_registry));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::restyle::StylePatch;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use std::sync::Arc;
+
+/// A style shared between many nodes, such as the cells of a grid or the rows of a list.
+///
+/// Cloning a `SharedStyle` only clones the pointer. The layout reads each node's own [`Style`],
+/// so the shared style is copied into it whenever the `SharedStyle` or the node's
+/// [`StyleOverride`] changes, or the override is removed. Replace a node's `SharedStyle` to restyle it.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SharedStyle(Arc<Style>);
+
+impl SharedStyle {
+    pub fn new(style: Style) -> Self {
+        Self(Arc::new(style))
+    }
+
+    /// Whether both handles share the same style.
+    pub fn ptr_eq(&self, other: &SharedStyle) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// A mutable reference to the style, cloned first if it is shared with any other handle.
+    pub fn make_mut(&mut self) -> &mut Style {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl std::ops::Deref for SharedStyle {
+    type Target = Style;
+
+    fn deref(&self) -> &Style {
+        &self.0
+    }
+}
+
+impl From<Style> for SharedStyle {
+    fn from(style: Style) -> Self {
+        Self::new(style)
+    }
+}
+
+/// Changes applied to an individual node on top of its [`SharedStyle`].
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleOverride(pub StylePatch);
+
+impl StyleOverride {
+    pub fn new(patch: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        Self(StylePatch::new(patch))
+    }
+}
+
+pub trait SharedStyleExt: Bundle + Sized {
+    /// Style the node with `shared`.
+    fn shared_style(self, shared: &SharedStyle) -> (Self, SharedStyle) {
+        (self, shared.clone())
+    }
+}
+
+impl<T: Bundle> SharedStyleExt for T {}
+
+/// Copies each new or changed [`SharedStyle`] into its node's [`Style`], then applies any [`StyleOverride`].
+/// Nodes whose override was removed are reset to their shared style.
+#[allow(clippy::type_complexity)]
+pub fn shared_style_system(
+    changed: Query<Entity, Or<(Changed<SharedStyle>, Changed<StyleOverride>)>>,
+    removed: RemovedComponents<StyleOverride>,
+    mut query: Query<(&SharedStyle, Option<&StyleOverride>, &mut Style)>,
+) {
+    for entity in changed.iter().chain(removed.iter()) {
+        let Ok((shared, style_override, mut style)) = query.get_mut(entity) else {
+            continue;
+        };
+        let mut resolved = Style::clone(shared);
+        if let Some(StyleOverride(patch)) = style_override {
+            patch.apply(&mut resolved);
+        }
+        if *style != resolved {
+            *style = resolved;
+        }
+    }
+}
+
+/// Adds the system that applies [`SharedStyle`]s.
+pub struct StyleSharedPlugin;
+
+impl Plugin for StyleSharedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            shared_style_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn overrides_copy_on_write() {
+        let mut shared = SharedStyle::new(style().width(Val::Px(10.)));
+        let handle = shared.clone();
+        assert!(shared.ptr_eq(&handle));
+        shared.make_mut().size.height = Val::Px(5.);
+        assert!(!shared.ptr_eq(&handle));
+        assert_eq!(handle.size.height, Val::Auto);
+    }
+
+    #[test]
+    fn shared_style_is_applied_with_overrides() {
+        let mut app = App::new();
+        app.add_plugin(StyleSharedPlugin);
+        let cell = SharedStyle::new(style().size_all(Val::Px(16.)));
+        let plain = app.world.spawn(node().shared_style(&cell)).id();
+        let wide = app
+            .world
+            .spawn((
+                node().shared_style(&cell),
+                StyleOverride::new(|style| {
+                    style.width(Val::Px(32.));
+                }),
+            ))
+            .id();
+        app.update();
+        let size = |app: &App, entity| app.world.get::<Style>(entity).unwrap().size;
+        assert_eq!(size(&app, plain), Size::new(Val::Px(16.), Val::Px(16.)));
+        assert_eq!(size(&app, wide), Size::new(Val::Px(32.), Val::Px(16.)));
+        let mut shared_styles = app.world.query::<&mut SharedStyle>();
+        for mut shared in shared_styles.iter_mut(&mut app.world) {
+            *shared = SharedStyle::new(style().size_all(Val::Px(20.)));
+        }
+        app.update();
+        assert_eq!(size(&app, plain), Size::new(Val::Px(20.), Val::Px(20.)));
+        assert_eq!(size(&app, wide), Size::new(Val::Px(32.), Val::Px(20.)));
+
+        app.world.entity_mut(wide).remove::<StyleOverride>();
+        app.update();
+        assert_eq!(size(&app, wide), Size::new(Val::Px(20.), Val::Px(20.)));
+    }
+}
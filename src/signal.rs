@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+#[derive(Debug)]
+struct SignalState<T> {
+    value: T,
+    version: u64,
+}
+
+/// A shared value that UI nodes can be bound to.
+///
+/// Clones of a signal share the same value, so it can be set from any system,
+/// callback or thread, and every node bound to it is updated on the next frame.
+#[derive(Debug)]
+pub struct Signal<T>(Arc<RwLock<SignalState<T>>>);
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Default> Default for Signal<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Signal<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(RwLock::new(SignalState { value, version: 0 })))
+    }
+
+    pub fn set(&self, value: T) {
+        let mut state = self.0.write().unwrap();
+        state.value = value;
+        state.version += 1;
+    }
+
+    /// Change the value in place.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut state = self.0.write().unwrap();
+        f(&mut state.value);
+        state.version += 1;
+    }
+}
+
+impl<T: Clone> Signal<T> {
+    pub fn get(&self) -> T {
+        self.0.read().unwrap().value.clone()
+    }
+
+    /// The value, if it has been set since the version in `seen`, which is then updated.
+    fn changed_since(&self, seen: &mut Option<u64>) -> Option<T> {
+        let state = self.0.read().unwrap();
+        if *seen == Some(state.version) {
+            return None;
+        }
+        *seen = Some(state.version);
+        Some(state.value.clone())
+    }
+}
+
+/// Sets the value of the first section of the node's [`Text`] from a signal.
+#[derive(Component, Clone, Debug)]
+pub struct BoundText {
+    signal: Signal<String>,
+    seen: Option<u64>,
+}
+
+/// Sets the node's [`BackgroundColor`] from a signal.
+#[derive(Component, Clone, Debug)]
+pub struct BoundBackground {
+    signal: Signal<Color>,
+    seen: Option<u64>,
+}
+
+pub trait SignalBindingExt: Bundle + Sized {
+    /// Show the value of `signal` as the node's text.
+    fn bind_text(self, signal: &Signal<String>) -> (Self, BoundText) {
+        (
+            self,
+            BoundText {
+                signal: signal.clone(),
+                seen: None,
+            },
+        )
+    }
+
+    /// Use the value of `signal` as the node's background color.
+    fn bind_background(self, signal: &Signal<Color>) -> (Self, BoundBackground) {
+        (
+            self,
+            BoundBackground {
+                signal: signal.clone(),
+                seen: None,
+            },
+        )
+    }
+}
+
+impl<T: Bundle> SignalBindingExt for T {}
+
+/// Writes the signals of [`BoundText`]s that have been set since they were last read.
+pub fn bound_text_system(mut query: Query<(&mut BoundText, &mut Text)>) {
+    for (mut binding, mut text) in query.iter_mut() {
+        let binding = &mut *binding;
+        if let Some(value) = binding.signal.changed_since(&mut binding.seen) {
+            if let Some(section) = text.sections.first_mut() {
+                section.value = value;
+            }
+        }
+    }
+}
+
+/// Writes the signals of [`BoundBackground`]s that have been set since they were last read.
+pub fn bound_background_system(mut query: Query<(&mut BoundBackground, &mut BackgroundColor)>) {
+    for (mut binding, mut background) in query.iter_mut() {
+        let binding = &mut *binding;
+        if let Some(color) = binding.signal.changed_since(&mut binding.seen) {
+            background.0 = color;
+        }
+    }
+}
+
+/// Adds the systems that update nodes bound to [`Signal`]s.
+pub struct StyleSignalPlugin;
+
+impl Plugin for StyleSignalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            bound_text_system.before(UiSystem::Flex),
+        )
+        .add_system_to_stage(CoreStage::PostUpdate, bound_background_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn bound_nodes_follow_their_signals() {
+        let mut app = App::new();
+        app.add_plugin(StyleSignalPlugin);
+        let score = Signal::new("0".to_string());
+        let alert = Signal::new(Color::NONE);
+        let label = app
+            .world
+            .spawn(TextBundle::from_section("", TextStyle::default()).bind_text(&score))
+            .id();
+        let panel = app.world.spawn(node().bind_background(&alert)).id();
+        let text = |app: &App| {
+            app.world.get::<Text>(label).unwrap().sections[0]
+                .value
+                .clone()
+        };
+        app.update();
+        assert_eq!(text(&app), "0");
+        assert_eq!(
+            app.world.get::<BackgroundColor>(panel).unwrap().0,
+            Color::NONE
+        );
+
+        let handle = score.clone();
+        handle.set("10".to_string());
+        score.update(|score| score.push('0'));
+        alert.set(Color::RED);
+        app.update();
+        assert_eq!(text(&app), "100");
+        assert_eq!(
+            app.world.get::<BackgroundColor>(panel).unwrap().0,
+            Color::RED
+        );
+    }
+}
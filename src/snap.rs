@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+/// Aligns a node's edges to the physical pixel grid after layout,
+/// so that thin borders and hairlines are drawn sharply rather than blurred across two pixels.
+///
+/// Only the node's position is snapped. Give it a whole-pixel size for both edges to align.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SnapToPixels;
+
+pub trait SnapToPixelsExt: Bundle + Sized {
+    /// Align the node to the physical pixel grid.
+    fn snap_to_pixels(self) -> (Self, SnapToPixels) {
+        (self, SnapToPixels)
+    }
+}
+
+impl<T: Bundle> SnapToPixelsExt for T {}
+
+/// The center of a node of `size` centered at `center`, moved so its top left corner
+/// lies on a pixel boundary when there are `scale` physical pixels to a UI pixel.
+pub fn snapped_center(center: Vec2, size: Vec2, scale: f32) -> Vec2 {
+    let corner = center - 0.5 * size;
+    (corner * scale).round() / scale + 0.5 * size
+}
+
+/// Moves each [`SnapToPixels`] node onto the physical pixel grid of the primary window.
+pub fn snap_to_pixels_system(
+    windows: Option<Res<Windows>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut query: Query<(&Node, &mut GlobalTransform), With<SnapToPixels>>,
+) {
+    let scale_factor = windows
+        .and_then(|windows| windows.get_primary().map(|window| window.scale_factor()))
+        .unwrap_or(1.);
+    let scale = (scale_factor * ui_scale.map_or(1., |ui_scale| ui_scale.scale)) as f32;
+    for (node, mut transform) in query.iter_mut() {
+        let translation = transform.translation();
+        let snapped = snapped_center(translation.truncate(), node.size(), scale);
+        if snapped != translation.truncate() {
+            *transform.translation_mut() = snapped.extend(translation.z).into();
+        }
+    }
+}
+
+/// Adds the system that snaps nodes to the pixel grid.
+pub struct StyleSnapPlugin;
+
+impl Plugin for StyleSnapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            snap_to_pixels_system.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner_is_moved_onto_the_grid() {
+        assert_eq!(
+            snapped_center(Vec2::new(10.3, 4.), Vec2::new(5., 2.), 1.),
+            Vec2::new(10.5, 4.)
+        );
+        assert_eq!(
+            snapped_center(Vec2::new(10.3, 4.), Vec2::new(5., 2.), 2.),
+            Vec2::new(10.5, 4.)
+        );
+        assert_eq!(
+            snapped_center(Vec2::new(10.1, 4.), Vec2::new(5., 2.), 2.),
+            Vec2::new(10., 4.)
+        );
+    }
+
+    #[test]
+    fn system_snaps_marked_nodes() {
+        let mut app = App::new();
+        app.add_system(snap_to_pixels_system);
+        let bundle = || NodeBundle {
+            global_transform: GlobalTransform::from_translation(Vec3::new(10.3, 20.2, 1.)),
+            ..default()
+        };
+        let snapped = app.world.spawn(bundle().snap_to_pixels()).id();
+        let free = app.world.spawn(bundle()).id();
+        app.update();
+        let translation = |entity| {
+            app.world
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation()
+        };
+        assert_eq!(translation(snapped), Vec3::new(10., 20., 1.));
+        assert_eq!(translation(free), Vec3::new(10.3, 20.2, 1.));
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+
+/// A saved copy of the [`Style`] and [`BackgroundColor`] of one or more nodes.
+///
+/// Restoring a snapshot writes the saved values back, skipping any entities
+/// that have been despawned since the snapshot was taken.
+/// A snapshot can also be restored with `commands.add(snapshot)`.
+#[derive(Clone, Debug, Default)]
+pub struct StyleSnapshot {
+    entries: Vec<(Entity, Style, Option<BackgroundColor>)>,
+}
+
+impl StyleSnapshot {
+    /// Capture the style of a single node.
+    pub fn capture(world: &World, entity: Entity) -> Self {
+        let mut snapshot = Self::default();
+        snapshot.push(world, entity);
+        snapshot
+    }
+
+    /// Capture the styles of a node and all of its descendants.
+    pub fn capture_tree(world: &World, root: Entity) -> Self {
+        let mut snapshot = Self::default();
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            snapshot.push(world, entity);
+            if let Some(children) = world.get::<Children>(entity) {
+                stack.extend(children.iter().rev());
+            }
+        }
+        snapshot
+    }
+
+    fn push(&mut self, world: &World, entity: Entity) {
+        if let Some(style) = world.get::<Style>(entity) {
+            let background_color = world.get::<BackgroundColor>(entity).copied();
+            self.entries.push((entity, style.clone(), background_color));
+        }
+    }
+
+    /// The entities whose styles are saved in this snapshot.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entries.iter().map(|(entity, ..)| *entity)
+    }
+
+    /// Write the saved styles back to their entities.
+    pub fn restore(&self, world: &mut World) {
+        for (entity, style, background_color) in &self.entries {
+            if let Some(mut current) = world.get_mut::<Style>(*entity) {
+                *current = style.clone();
+            }
+            if let Some(background_color) = background_color {
+                if let Some(mut current) = world.get_mut::<BackgroundColor>(*entity) {
+                    *current = *background_color;
+                }
+            }
+        }
+    }
+}
+
+impl Command for StyleSnapshot {
+    fn write(self, world: &mut World) {
+        self.restore(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn capture_and_restore_tree() {
+        let mut world = World::new();
+        let child = world
+            .spawn(node().width(Val::Px(1.)).background_color(Color::RED))
+            .id();
+        let root = world
+            .spawn(node().width(Val::Px(2.)))
+            .push_children(&[child])
+            .id();
+        let snapshot = StyleSnapshot::capture_tree(&world, root);
+        assert_eq!(snapshot.entities().collect::<Vec<_>>(), vec![root, child]);
+
+        world.get_mut::<Style>(child).unwrap().size.width = Val::Px(10.);
+        world.get_mut::<BackgroundColor>(child).unwrap().0 = Color::BLUE;
+        world.get_mut::<Style>(root).unwrap().size.width = Val::Px(20.);
+        snapshot.restore(&mut world);
+
+        assert_eq!(world.get::<Style>(child).unwrap().size.width, Val::Px(1.));
+        assert_eq!(world.get::<BackgroundColor>(child).unwrap().0, Color::RED);
+        assert_eq!(world.get::<Style>(root).unwrap().size.width, Val::Px(2.));
+    }
+}
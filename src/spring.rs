@@ -0,0 +1,227 @@
+use crate::fields::StyleFields;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A damped spring, used as an alternative to a duration and easing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170., 26.)
+    }
+}
+
+/// Progress below this distance from the target counts as settled.
+const SETTLE_EPSILON: f32 = 1e-3;
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.,
+        }
+    }
+
+    /// A gentle spring without overshoot.
+    pub fn gentle() -> Self {
+        Self::new(120., 22.)
+    }
+
+    /// A quick spring that overshoots slightly.
+    pub fn bouncy() -> Self {
+        Self::new(300., 12.)
+    }
+
+    fn angular_frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn damping_ratio(&self) -> f32 {
+        self.damping / (2. * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// The position of a spring released at rest from `0` towards `1`, `elapsed` later.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        let t = elapsed.as_secs_f32();
+        let w0 = self.angular_frequency();
+        let zeta = self.damping_ratio();
+        if zeta < 1. {
+            let wd = w0 * (1. - zeta * zeta).sqrt();
+            let envelope = (-zeta * w0 * t).exp();
+            1. - envelope * ((wd * t).cos() + zeta * w0 / wd * (wd * t).sin())
+        } else if zeta == 1. {
+            1. - (-w0 * t).exp() * (1. + w0 * t)
+        } else {
+            let root = (zeta * zeta - 1.).sqrt();
+            let r1 = -w0 * (zeta - root);
+            let r2 = -w0 * (zeta + root);
+            1. + (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r1 - r2)
+        }
+    }
+
+    /// The time after which [`Spring::progress`] stays close enough to `1` to stop animating.
+    pub fn settle_duration(&self) -> Duration {
+        let w0 = self.angular_frequency();
+        let zeta = self.damping_ratio();
+        let decay = if zeta <= 1. {
+            zeta * w0
+        } else {
+            w0 * (zeta - (zeta * zeta - 1.).sqrt())
+        };
+        if decay <= 0. || !decay.is_finite() {
+            return Duration::MAX;
+        }
+        Duration::try_from_secs_f32(-SETTLE_EPSILON.ln() / decay).unwrap_or(Duration::MAX)
+    }
+
+    /// Advance `state` towards `target` by `delta` seconds.
+    pub fn step(&self, state: &mut SpringState, target: f32, delta: f32) {
+        const MAX_STEP: f32 = 1. / 240.;
+        let mut remaining = delta;
+        while 0. < remaining {
+            let dt = remaining.min(MAX_STEP);
+            let force = -self.stiffness * (state.value - target) - self.damping * state.velocity;
+            state.velocity += force / self.mass * dt;
+            state.value += state.velocity * dt;
+            remaining -= dt;
+        }
+    }
+}
+
+/// The value and velocity of a value driven by a [`Spring`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SpringState {
+    pub value: f32,
+    pub velocity: f32,
+}
+
+impl SpringState {
+    pub fn is_settled(&self, target: f32) -> bool {
+        (self.value - target).abs() < SETTLE_EPSILON && self.velocity.abs() < SETTLE_EPSILON
+    }
+}
+
+/// Springs a node's `left` and `top` position towards [`SpringPosition::target`] in pixels.
+///
+/// Velocity is kept when the target changes, so moving the target while dragging
+/// or snapping stays smooth.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SpringPosition {
+    pub spring: Spring,
+    pub target: Vec2,
+    x: SpringState,
+    y: SpringState,
+}
+
+impl SpringPosition {
+    /// Start at rest at `position`.
+    pub fn new(spring: Spring, position: Vec2) -> Self {
+        Self {
+            spring,
+            target: position,
+            x: SpringState {
+                value: position.x,
+                velocity: 0.,
+            },
+            y: SpringState {
+                value: position.y,
+                velocity: 0.,
+            },
+        }
+    }
+
+    /// The current position.
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.x.value, self.y.value)
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.x.is_settled(self.target.x) && self.y.is_settled(self.target.y)
+    }
+
+    /// Advance the spring by `delta` seconds and return the new position.
+    pub fn step(&mut self, delta: f32) -> Vec2 {
+        if self.is_settled() {
+            self.x = SpringState {
+                value: self.target.x,
+                velocity: 0.,
+            };
+            self.y = SpringState {
+                value: self.target.y,
+                velocity: 0.,
+            };
+        } else {
+            self.spring.step(&mut self.x, self.target.x, delta);
+            self.spring.step(&mut self.y, self.target.y, delta);
+        }
+        self.position()
+    }
+}
+
+/// Moves nodes with a [`SpringPosition`] towards their targets.
+pub fn spring_position_system(
+    time: Res<Time>,
+    mut query: Query<(&mut SpringPosition, &mut Style)>,
+) {
+    for (mut spring, mut style) in query.iter_mut() {
+        let position = spring.step(time.delta_seconds());
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if style.left_val() != left || style.top_val() != top {
+            *style.left_mut() = left;
+            *style.top_mut() = top;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analytic_progress_settles() {
+        for spring in [
+            Spring::default(),
+            Spring::gentle(),
+            Spring::bouncy(),
+            Spring::new(100., 20.),
+            Spring::new(100., 40.),
+        ] {
+            assert_eq!(spring.progress(Duration::ZERO), 0.);
+            let settled = spring.progress(spring.settle_duration());
+            assert!((settled - 1.).abs() < 0.01, "{spring:?} {settled}");
+        }
+        assert!(1. < Spring::bouncy().progress(Duration::from_millis(150)));
+        assert_eq!(Spring::new(1., 1e-30).settle_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn numeric_step_matches_analytic() {
+        let spring = Spring::default();
+        let mut state = SpringState::default();
+        spring.step(&mut state, 1., 0.2);
+        let expected = spring.progress(Duration::from_secs_f32(0.2));
+        assert!((state.value - expected).abs() < 0.02);
+    }
+
+    #[test]
+    fn spring_position_keeps_velocity() {
+        let mut position = SpringPosition::new(Spring::default(), Vec2::ZERO);
+        position.target = Vec2::new(100., 0.);
+        position.step(0.1);
+        let velocity = position.x.velocity;
+        position.target = Vec2::new(200., 0.);
+        position.step(0.);
+        assert_eq!(position.x.velocity, velocity);
+        for _ in 0..200 {
+            position.step(1. / 60.);
+        }
+        assert!(position.is_settled());
+        assert_eq!(position.position(), Vec2::new(200., 0.));
+    }
+}
@@ -0,0 +1,898 @@
+//! Stylesheet assets that register class styles in the [`StyleRegistry`].
+//!
+//! With the `ron`, `json` or `yaml` features, sheets are loaded from `.style.ron`,
+//! `.style.json` or `.style.yaml` files, for example:
+//!
+//! ```ron
+//! (
+//!     rules: [
+//!         (class: "card", css: "flex-direction: column; padding: 8px;"),
+//!         (class: "card:hover", css: "padding: 10px;"),
+//!         (class: "panel", css: "margin: $spacing-2;"),
+//!         (class: "card", css: "display: none;", media: Some((max_width: Some(600.)))),
+//!     ],
+//! )
+//! ```
+
+use crate::css::apply_themed_css;
+use crate::css::parse_css;
+use crate::css::themed_css_errors;
+use crate::css::ParseCssError;
+use crate::registry::apply_style_classes_system;
+use crate::registry::MediaQuery;
+use crate::registry::PseudoClass;
+use crate::registry::StylePriority;
+use crate::registry::StyleRegistry;
+use crate::registry::StyleScope;
+use crate::restyle::StylePatch;
+use crate::theme::ThemeTokens;
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use bevy::asset::AssetLoader;
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use bevy::asset::BoxedFuture;
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use bevy::asset::LoadContext;
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+use bevy::asset::LoadedAsset;
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::utils::HashMap;
+use bevy::utils::HashSet;
+#[cfg(feature = "serialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum StyleSheetError {
+    #[error("invalid rule for `{class}`: {source}")]
+    InvalidRule {
+        class: String,
+        source: ParseCssError,
+    },
+    #[error("`{class}` has an unknown state `{state}`, expected `hover`, `pressed`, `disabled` or `focused`")]
+    UnknownState { class: String, state: String },
+    #[error("`{class}` uses an unknown theme token `${token}`")]
+    UnknownToken { class: String, token: String },
+    #[error("unknown class `{class}`{}", did_you_mean(.suggestion))]
+    UnknownClass {
+        class: String,
+        suggestion: Option<String>,
+    },
+}
+
+fn did_you_mean(suggestion: &Option<String>) -> String {
+    suggestion
+        .as_ref()
+        .map(|suggestion| format!(", did you mean `{suggestion}`?"))
+        .unwrap_or_default()
+}
+
+/// The number of single character insertions, deletions and substitutions between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The CSS declarations of a class in a [`StyleSheet`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StyleRule {
+    /// The name of the class, which may end with a state such as `:hover`.
+    pub class: String,
+    /// Declarations such as `"margin: 4px 8px; flex: 1 0 auto;"`.
+    ///
+    /// Lengths can be [`ThemeTokens`] such as `$spacing-2`,
+    /// which are looked up whenever the tokens change.
+    pub css: String,
+    /// Only apply the rule while the window matches this query.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub media: Option<MediaQuery>,
+}
+
+/// Class styles loaded from a file.
+///
+/// The rules for a class are combined in order. Once loaded, a sheet registers its classes
+/// in the [`StyleRegistry`], replacing any earlier styles with the same names,
+/// and registers them again whenever the file changes.
+#[derive(TypeUuid, Clone, Debug, Default, PartialEq)]
+#[uuid = "d126bab7-b0cd-4e03-bb2b-0f2cf2ed7f89"]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StyleSheet {
+    pub rules: Vec<StyleRule>,
+}
+
+impl StyleSheet {
+    pub fn rule(mut self, class: impl Into<String>, css: impl Into<String>) -> Self {
+        self.rules.push(StyleRule {
+            class: class.into(),
+            css: css.into(),
+            media: None,
+        });
+        self
+    }
+
+    pub fn media_rule(
+        mut self,
+        class: impl Into<String>,
+        media: MediaQuery,
+        css: impl Into<String>,
+    ) -> Self {
+        self.rules.push(StyleRule {
+            class: class.into(),
+            css: css.into(),
+            media: Some(media),
+        });
+        self
+    }
+
+    /// A JSON Schema describing the stylesheet format, for editors to validate
+    /// and autocomplete `.style.json` and `.style.yaml` files.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema() -> schemars::schema::RootSchema {
+        schemars::schema_for!(StyleSheet)
+    }
+
+    /// Write the sheet in the format read by [`RonStyleSheetLoader`].
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Write the sheet in the format read by [`JsonStyleSheetLoader`].
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Write the sheet in the format read by [`YamlStyleSheetLoader`].
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// The names of the classes the sheet styles, in the order they first appear.
+    pub fn classes(&self) -> Vec<&str> {
+        let mut classes = Vec::new();
+        for rule in &self.rules {
+            if !classes.contains(&rule.class.as_str()) {
+                classes.push(rule.class.as_str());
+            }
+        }
+        classes
+    }
+
+    /// Every problem with the sheet: declarations with unknown properties or invalid values,
+    /// and classes with an unknown state such as `button:hovered`.
+    ///
+    /// With `tokens`, lengths using a token that isn't set, such as a typo of `$spacing-2`,
+    /// are reported too. Without them, any token is accepted.
+    pub fn validate(&self, tokens: Option<&ThemeTokens>) -> Vec<StyleSheetError> {
+        let mut errors = Vec::new();
+        for rule in &self.rules {
+            if let Some((_, state)) = rule.class.split_once(':') {
+                let known = PseudoClass::ALL
+                    .iter()
+                    .any(|pseudo_class| pseudo_class.suffix().strip_prefix(':') == Some(state));
+                if !known {
+                    errors.push(StyleSheetError::UnknownState {
+                        class: rule.class.clone(),
+                        state: state.to_string(),
+                    });
+                }
+            }
+            errors.extend(themed_css_errors(&rule.css, tokens).into_iter().map(
+                |source| match source {
+                    ParseCssError::UnknownToken { token, .. } => StyleSheetError::UnknownToken {
+                        class: rule.class.clone(),
+                        token,
+                    },
+                    source => StyleSheetError::InvalidRule {
+                        class: rule.class.clone(),
+                        source,
+                    },
+                },
+            ));
+        }
+        errors
+    }
+
+    /// Like [`validate`](Self::validate), also reporting the classes that aren't in `classes`,
+    /// such as typos of the class names used by the app, with the closest known name.
+    pub fn validate_classes(
+        &self,
+        classes: &[&str],
+        tokens: Option<&ThemeTokens>,
+    ) -> Vec<StyleSheetError> {
+        let mut errors = self.validate(tokens);
+        for class in self.classes() {
+            let name = class.split_once(':').map_or(class, |(name, _)| name);
+            if classes.contains(&name) {
+                continue;
+            }
+            let suggestion = classes
+                .iter()
+                .map(|known| (edit_distance(name, known), known))
+                .filter(|(distance, _)| *distance <= 2)
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(_, known)| known.to_string());
+            errors.push(StyleSheetError::UnknownClass {
+                class: class.to_string(),
+                suggestion,
+            });
+        }
+        errors
+    }
+
+    /// Register the sheet's classes in `registry`.
+    ///
+    /// Nothing is registered if any rule fails to parse.
+    /// Classes with rules that use [`ThemeTokens`] are registered as themed styles.
+    pub fn register(&self, registry: &mut StyleRegistry) -> Result<(), StyleSheetError> {
+        for mut entry in self.entries()? {
+            entry.register(registry);
+        }
+        Ok(())
+    }
+
+    /// The styles the sheet registers, with the rules for each class combined.
+    fn entries(&self) -> Result<Vec<SheetEntry>, StyleSheetError> {
+        let mut styles: Vec<SheetEntry> = Vec::new();
+        let mut media = Vec::new();
+        for rule in &self.rules {
+            let invalid = |source| StyleSheetError::InvalidRule {
+                class: rule.class.clone(),
+                source,
+            };
+            let patch = if rule.css.contains('$') {
+                if let Some(source) = themed_css_errors(&rule.css, None).into_iter().next() {
+                    return Err(invalid(source));
+                }
+                RulePatch::Themed(rule.css.as_str().into())
+            } else {
+                RulePatch::Parsed(parse_css(&rule.css).map_err(invalid)?)
+            };
+            match rule.media {
+                Some(query) => media.push(SheetEntry::new(&rule.class, Some(query), patch)),
+                None => match styles.iter_mut().find(|entry| entry.class == rule.class) {
+                    Some(entry) => entry.rules.push(patch),
+                    None => styles.push(SheetEntry::new(&rule.class, None, patch)),
+                },
+            }
+        }
+        Ok(styles.into_iter().chain(media).collect())
+    }
+}
+
+/// The declarations of a [`StyleRule`], parsed up front unless they use [`ThemeTokens`].
+#[derive(Clone)]
+enum RulePatch {
+    Parsed(StylePatch),
+    Themed(Arc<str>),
+}
+
+impl RulePatch {
+    fn apply(&self, style: &mut Style, tokens: &ThemeTokens) {
+        match self {
+            RulePatch::Parsed(patch) => patch.apply(style),
+            RulePatch::Themed(css) => apply_themed_css(css, tokens, style),
+        }
+    }
+}
+
+/// A style or media variant registered by a [`StyleSheet`].
+pub struct SheetEntry {
+    class: String,
+    media: Option<MediaQuery>,
+    rules: Vec<RulePatch>,
+    /// The patch in the registry, once registered.
+    registered: Option<StylePatch>,
+}
+
+impl SheetEntry {
+    fn new(class: &str, media: Option<MediaQuery>, rule: RulePatch) -> Self {
+        Self {
+            class: class.to_string(),
+            media,
+            rules: vec![rule],
+            registered: None,
+        }
+    }
+
+    fn register(&mut self, registry: &mut StyleRegistry) {
+        let class = self.class.clone();
+        let patch = if self
+            .rules
+            .iter()
+            .all(|rule| matches!(rule, RulePatch::Parsed(_)))
+        {
+            let patch = self
+                .rules
+                .iter()
+                .filter_map(|rule| match rule {
+                    RulePatch::Parsed(patch) => Some(patch.clone()),
+                    RulePatch::Themed(_) => None,
+                })
+                .reduce(StylePatch::then)
+                .unwrap_or_default();
+            match self.media {
+                Some(query) => registry.register_media_patch(class, query, patch.clone()),
+                None => registry.register_patch(class, StylePriority::Class, patch.clone()),
+            };
+            patch
+        } else {
+            let rules = self.rules.clone();
+            let patch = registry.bind_tokens(move |style, tokens| {
+                for rule in &rules {
+                    rule.apply(style, tokens);
+                }
+            });
+            registry.register_bound(class, StylePriority::Class, self.media, patch.clone());
+            patch
+        };
+        self.registered = Some(patch);
+    }
+
+    fn unregister(&self, registry: &mut StyleRegistry) {
+        if let Some(patch) = &self.registered {
+            registry.unregister_patch(&self.class, patch);
+        }
+    }
+}
+
+#[cfg(any(feature = "ron", feature = "json", feature = "yaml"))]
+fn load_sheet(load_context: &mut LoadContext, sheet: StyleSheet) {
+    load_context.set_default_asset(LoadedAsset::new(sheet));
+}
+
+/// Loads [`StyleSheet`]s from `.style.ron` files.
+#[cfg(feature = "ron")]
+#[derive(Default)]
+pub struct RonStyleSheetLoader;
+
+#[cfg(feature = "ron")]
+impl AssetLoader for RonStyleSheetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            load_sheet(load_context, ron::de::from_bytes(bytes)?);
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["style.ron"]
+    }
+}
+
+/// Loads [`StyleSheet`]s from `.style.json` files.
+#[cfg(feature = "json")]
+#[derive(Default)]
+pub struct JsonStyleSheetLoader;
+
+#[cfg(feature = "json")]
+impl AssetLoader for JsonStyleSheetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            load_sheet(load_context, serde_json::from_slice(bytes)?);
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["style.json"]
+    }
+}
+
+/// Loads [`StyleSheet`]s from `.style.yaml` and `.style.yml` files.
+#[cfg(feature = "yaml")]
+#[derive(Default)]
+pub struct YamlStyleSheetLoader;
+
+#[cfg(feature = "yaml")]
+impl AssetLoader for YamlStyleSheetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            load_sheet(load_context, serde_yaml::from_slice(bytes)?);
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["style.yaml", "style.yml"]
+    }
+}
+
+/// Registers the classes of each loaded or changed [`StyleSheet`],
+/// except the sheets used by a [`ScopedStyleSheet`], which are only registered in their scopes.
+///
+/// A changed sheet replaces the styles registered by its previous version once all of its rules parse.
+/// If any rule fails, the previous version stays registered.
+/// Styles with the same names registered by other sheets or by the app are left in place.
+pub fn apply_style_sheets_system(
+    mut events: EventReader<AssetEvent<StyleSheet>>,
+    sheets: Res<Assets<StyleSheet>>,
+    mut registry: ResMut<StyleRegistry>,
+    scoped: Query<&ScopedStyleSheet>,
+    changed: Query<&ScopedStyleSheet, Changed<ScopedStyleSheet>>,
+    mut registered: Local<HashMap<Handle<StyleSheet>, Vec<SheetEntry>>>,
+) {
+    for sheet in changed.iter() {
+        for entry in registered.remove(&sheet.0).into_iter().flatten() {
+            entry.unregister(&mut registry);
+        }
+    }
+    for event in events.iter() {
+        let handle = match event {
+            AssetEvent::Created { handle }
+            | AssetEvent::Modified { handle }
+            | AssetEvent::Removed { handle } => handle,
+        };
+        if scoped.iter().any(|sheet| sheet.0 == *handle) {
+            continue;
+        }
+        let mut entries = match sheets.get(handle).map(StyleSheet::entries) {
+            Some(Ok(entries)) => entries,
+            Some(Err(error)) => {
+                warn!("style sheet not applied: {error}");
+                continue;
+            }
+            None => Vec::new(),
+        };
+        for entry in registered.remove(handle).into_iter().flatten() {
+            entry.unregister(&mut registry);
+        }
+        for entry in &mut entries {
+            entry.register(&mut registry);
+        }
+        if !entries.is_empty() {
+            registered.insert(handle.clone_weak(), entries);
+        }
+    }
+}
+
+/// Registers the classes of a [`StyleSheet`] in the entity's [`StyleScope`],
+/// so they only apply to the entity and its descendants.
+///
+/// The scope is added if the entity doesn't have one. Like a global sheet,
+/// the scope is updated whenever the sheet changes, keeping the previous version if it fails to parse.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopedStyleSheet(pub Handle<StyleSheet>);
+
+/// Registers the classes of each entity's [`ScopedStyleSheet`] in its [`StyleScope`]
+/// when the component is added or changed, or its sheet is loaded or changed.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_scoped_style_sheets_system(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<StyleSheet>>,
+    sheets: Res<Assets<StyleSheet>>,
+    registry: Res<StyleRegistry>,
+    tokens: Res<ThemeTokens>,
+    scoped: Query<(Entity, &ScopedStyleSheet)>,
+    changed: Query<Entity, Changed<ScopedStyleSheet>>,
+    mut scopes: Query<&mut StyleScope>,
+    removed: RemovedComponents<ScopedStyleSheet>,
+    mut registered: Local<HashMap<Entity, Vec<SheetEntry>>>,
+) {
+    for entity in removed.iter() {
+        let entries = registered.remove(&entity).into_iter().flatten();
+        if let Ok(mut scope) = scopes.get_mut(entity) {
+            for entry in entries {
+                entry.unregister(&mut scope.bypass_change_detection().0);
+            }
+        }
+    }
+    let mut reload = changed.iter().collect::<HashSet<_>>();
+    for event in events.iter() {
+        let (AssetEvent::Created { handle }
+        | AssetEvent::Modified { handle }
+        | AssetEvent::Removed { handle }) = event;
+        reload.extend(
+            scoped
+                .iter()
+                .filter(|(_, sheet)| sheet.0 == *handle)
+                .map(|(entity, _)| entity),
+        );
+    }
+    for entity in reload {
+        let Ok((_, sheet)) = scoped.get(entity) else {
+            continue;
+        };
+        let mut entries = match sheets.get(&sheet.0).map(StyleSheet::entries) {
+            Some(Ok(entries)) => entries,
+            Some(Err(error)) => {
+                warn!("scoped style sheet not applied: {error}");
+                continue;
+            }
+            None => Vec::new(),
+        };
+        let previous = registered.remove(&entity).into_iter().flatten();
+        match scopes.get_mut(entity) {
+            Ok(mut scope) => {
+                let scope = &mut scope.bypass_change_detection().0;
+                for entry in previous {
+                    entry.unregister(scope);
+                }
+                for entry in &mut entries {
+                    entry.register(scope);
+                }
+            }
+            Err(_) => {
+                let mut scope = StyleScope::default();
+                scope.set_tokens(tokens.clone());
+                if let Some(viewport) = registry.viewport() {
+                    scope.set_viewport(viewport);
+                }
+                for entry in &mut entries {
+                    entry.register(&mut scope);
+                }
+                commands.entity(entity).insert(scope);
+            }
+        }
+        if !entries.is_empty() {
+            registered.insert(entity, entries);
+        }
+    }
+}
+
+/// Adds the [`StyleSheet`] asset, its loaders for the enabled formats,
+/// and the systems that register loaded sheets globally and in [`ScopedStyleSheet`]s. Requires the [`StyleRegistryPlugin`](crate::registry::StyleRegistryPlugin).
+pub struct StyleSheetPlugin;
+
+impl Plugin for StyleSheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<StyleSheet>()
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                apply_style_sheets_system.before(apply_style_classes_system),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                apply_scoped_style_sheets_system.before(apply_style_classes_system),
+            );
+        #[cfg(feature = "ron")]
+        app.init_asset_loader::<RonStyleSheetLoader>();
+        #[cfg(feature = "json")]
+        app.init_asset_loader::<JsonStyleSheetLoader>();
+        #[cfg(feature = "yaml")]
+        app.init_asset_loader::<YamlStyleSheetLoader>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn sheets_register_their_classes_and_reload() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleSheetPlugin);
+        let sheet = StyleSheet::default()
+            .rule("card", "padding: 8px")
+            .rule("card", "flex-direction: column")
+            .rule("badge", "width: 16px");
+        let handle = app.world.resource_mut::<Assets<StyleSheet>>().add(sheet);
+        let card = app.world.spawn(node().classes(["card"])).id();
+        let badge = app.world.spawn(node().classes(["badge"])).id();
+        app.update();
+        app.update();
+        let style = |app: &App, entity| app.world.get::<Style>(entity).unwrap().clone();
+        assert_eq!(style(&app, card).padding, UiRect::all(Val::Px(8.)));
+        assert_eq!(style(&app, card).flex_direction, FlexDirection::Column);
+        assert_eq!(style(&app, badge).size.width, Val::Px(16.));
+
+        *app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .get_mut(&handle)
+            .unwrap() = StyleSheet::default().rule("card", "padding: 4px");
+        app.update();
+        app.update();
+        assert_eq!(style(&app, card).padding, UiRect::all(Val::Px(4.)));
+        assert_eq!(style(&app, card).flex_direction, FlexDirection::Row);
+        assert_eq!(style(&app, badge).size.width, Val::Auto);
+    }
+
+    #[test]
+    fn reloads_keep_the_previous_version_on_error_and_leave_other_styles() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleSheetPlugin);
+        let sheet = StyleSheet::default()
+            .rule("card", "padding: 8px")
+            .media_rule("badge", MediaQuery::default(), "width: 16px");
+        let handle = app.world.resource_mut::<Assets<StyleSheet>>().add(sheet);
+        app.update();
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("badge", |style| {
+                style.height(Val::Px(4.));
+            });
+        let card = app.world.spawn(node().classes(["card"])).id();
+        let badge = app.world.spawn(node().classes(["badge"])).id();
+        app.update();
+
+        *app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .get_mut(&handle)
+            .unwrap() = StyleSheet::default().rule("card", "padding: 4");
+        app.update();
+        app.update();
+        let style = |app: &App, entity| app.world.get::<Style>(entity).unwrap().clone();
+        assert_eq!(style(&app, card).padding, UiRect::all(Val::Px(8.)));
+
+        app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .remove(&handle);
+        app.update();
+        app.update();
+        assert_eq!(style(&app, card).padding, UiRect::default());
+        assert_eq!(style(&app, badge).size.width, Val::Auto);
+        assert_eq!(style(&app, badge).size.height, Val::Px(4.));
+    }
+
+    #[test]
+    fn scoped_sheets_style_their_subtree_and_reload() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleSheetPlugin);
+        app.world
+            .resource_mut::<StyleRegistry>()
+            .register("card", |style| {
+                style.width(Val::Px(10.));
+            });
+        let sheet = StyleSheet::default().rule("card", "width: 50px; padding: 8px");
+        let handle = app.world.resource_mut::<Assets<StyleSheet>>().add(sheet);
+        let console = app
+            .world
+            .spawn((node(), ScopedStyleSheet(handle.clone())))
+            .id();
+        let console_card = app.world.spawn(node().classes(["card"])).id();
+        let game_card = app.world.spawn(node().classes(["card"])).id();
+        app.world.entity_mut(console).push_children(&[console_card]);
+        app.update();
+        app.update();
+        let style = |app: &App, entity| app.world.get::<Style>(entity).unwrap().clone();
+        assert!(app.world.get::<StyleScope>(console).is_some());
+        assert_eq!(style(&app, console_card).size.width, Val::Px(50.));
+        assert_eq!(style(&app, console_card).padding, UiRect::all(Val::Px(8.)));
+        assert_eq!(style(&app, game_card).size.width, Val::Px(10.));
+        assert_eq!(style(&app, game_card).padding, UiRect::default());
+
+        *app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .get_mut(&handle)
+            .unwrap() = StyleSheet::default().rule("card", "width: 60px");
+        app.update();
+        app.update();
+        assert_eq!(style(&app, console_card).size.width, Val::Px(60.));
+        assert_eq!(style(&app, console_card).padding, UiRect::default());
+
+        *app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .get_mut(&handle)
+            .unwrap() = StyleSheet::default().rule("card", "width: 60");
+        app.update();
+        app.update();
+        assert_eq!(style(&app, console_card).size.width, Val::Px(60.));
+
+        app.world.entity_mut(console).remove::<ScopedStyleSheet>();
+        app.update();
+        app.update();
+        assert_eq!(style(&app, console_card).size.width, Val::Px(10.));
+    }
+
+    #[test]
+    fn token_rules_follow_the_theme() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(StyleRegistryPlugin)
+            .add_plugin(StyleSheetPlugin);
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(8.));
+        let sheet = StyleSheet::default()
+            .rule("card", "margin: $spacing-2")
+            .rule("card", "width: 16px");
+        let handle = app.world.resource_mut::<Assets<StyleSheet>>().add(sheet);
+        let card = app.world.spawn(node().classes(["card"])).id();
+        app.update();
+        app.update();
+        let style = |app: &App| app.world.get::<Style>(card).unwrap().clone();
+        assert_eq!(style(&app).margin, UiRect::all(Val::Px(8.)));
+        assert_eq!(style(&app).size.width, Val::Px(16.));
+
+        app.world
+            .resource_mut::<ThemeTokens>()
+            .set("spacing-2", Val::Px(12.));
+        app.update();
+        assert_eq!(style(&app).margin, UiRect::all(Val::Px(12.)));
+
+        app.world
+            .resource_mut::<Assets<StyleSheet>>()
+            .remove(&handle);
+        app.update();
+        app.update();
+        assert_eq!(style(&app).margin, UiRect::default());
+    }
+
+    #[test]
+    fn unknown_tokens_are_reported_with_the_theme() {
+        let sheet = StyleSheet::default().rule("card", "margin: $spacing-3; padding: $spacing-2");
+        assert!(sheet.validate(None).is_empty());
+        let tokens = ThemeTokens::default().with("spacing-2", Val::Px(8.));
+        assert_eq!(
+            sheet.validate(Some(&tokens)),
+            [StyleSheetError::UnknownToken {
+                class: "card".to_string(),
+                token: "spacing-3".to_string(),
+            }]
+        );
+        assert!(matches!(
+            StyleSheet::default()
+                .rule("card", "margin: $spacing-2 4em")
+                .register(&mut StyleRegistry::default()),
+            Err(StyleSheetError::InvalidRule { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_sheets_are_not_registered() {
+        let mut registry = StyleRegistry::default();
+        let sheet = StyleSheet::default()
+            .rule("card", "padding: 8px")
+            .rule("badge", "width: 16");
+        assert_eq!(
+            sheet.register(&mut registry),
+            Err(StyleSheetError::InvalidRule {
+                class: "badge".to_string(),
+                source: ParseCssError::InvalidLength {
+                    property: "width".to_string(),
+                    source: crate::ParseBreadthError::MissingUnit("16".to_string()),
+                },
+            })
+        );
+        assert!(registry.get("card").is_none());
+    }
+
+    #[test]
+    fn validation_reports_every_problem() {
+        let sheet = StyleSheet::default()
+            .rule("card", "padding: 8px; colour: red; width: 4em")
+            .rule("card:hovered", "padding: 10px")
+            .rule("badge", "width: 16px")
+            .rule("tooltip", "display: none");
+        let errors = sheet.validate(None);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[0],
+            StyleSheetError::InvalidRule {
+                class: "card".to_string(),
+                source: ParseCssError::UnknownProperty("colour".to_string()),
+            }
+        );
+        assert!(matches!(
+            &errors[1],
+            StyleSheetError::InvalidRule {
+                source: ParseCssError::InvalidLength { .. },
+                ..
+            }
+        ));
+        assert_eq!(
+            errors[2],
+            StyleSheetError::UnknownState {
+                class: "card:hovered".to_string(),
+                state: "hovered".to_string(),
+            }
+        );
+
+        let errors = sheet.validate_classes(&["card", "bagde"], None);
+        assert_eq!(
+            errors[3..],
+            [
+                StyleSheetError::UnknownClass {
+                    class: "badge".to_string(),
+                    suggestion: Some("bagde".to_string()),
+                },
+                StyleSheetError::UnknownClass {
+                    class: "tooltip".to_string(),
+                    suggestion: None,
+                },
+            ]
+        );
+        assert_eq!(
+            errors[3].to_string(),
+            "unknown class `badge`, did you mean `bagde`?"
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn schema_describes_the_rules() {
+        let schema = StyleSheet::json_schema();
+        let object = schema.schema.object.unwrap();
+        assert!(object.required.contains("rules"));
+        let rule = schema.definitions["StyleRule"].clone().into_object();
+        let properties = &rule.object.unwrap().properties;
+        assert!(["class", "css", "media"]
+            .iter()
+            .all(|property| properties.contains_key(*property)));
+        assert!(schema.definitions.contains_key("MediaQuery"));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn sheets_are_read_from_ron() {
+        let sheet: StyleSheet = ron::from_str(
+            r#"(rules: [
+                (class: "card", css: "padding: 8px;"),
+                (class: "card", css: "display: none;", media: Some((max_width: Some(600.)))),
+            ])"#,
+        )
+        .unwrap();
+        assert_eq!(sheet.classes(), vec!["card"]);
+        assert_eq!(
+            sheet.rules[1].media,
+            Some(MediaQuery::default().max_width(600.))
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn sheets_are_read_from_json() {
+        let sheet: StyleSheet =
+            serde_json::from_str(r#"{"rules": [{"class": "card", "css": "padding: 8px;"}]}"#)
+                .unwrap();
+        assert_eq!(sheet, StyleSheet::default().rule("card", "padding: 8px;"));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn sheets_are_read_from_yaml() {
+        let sheet: StyleSheet = serde_yaml::from_str(
+            "rules:\n  - class: card\n    css: 'padding: 8px;'\n    media:\n      min_width: 800\n",
+        )
+        .unwrap();
+        assert_eq!(
+            sheet,
+            StyleSheet::default().media_rule(
+                "card",
+                MediaQuery::default().min_width(800.),
+                "padding: 8px;"
+            )
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Conversion of styles built with the crate's builder into [`taffy`] styles,
+//! for laying out nodes outside of Bevy UI.
+
+use bevy::prelude::*;
+use taffy::geometry;
+use taffy::number::Number;
+use taffy::style::Dimension;
+
+/// The taffy equivalent of `val`, with pixels multiplied by `scale_factor`.
+pub fn to_taffy_dimension(val: Val, scale_factor: f64) -> Dimension {
+    match val {
+        Val::Undefined => Dimension::Undefined,
+        Val::Auto => Dimension::Auto,
+        Val::Px(value) => Dimension::Points((scale_factor * value as f64) as f32),
+        Val::Percent(value) => Dimension::Percent(value / 100.),
+    }
+}
+
+fn to_taffy_rect(rect: UiRect, scale_factor: f64) -> geometry::Rect<Dimension> {
+    geometry::Rect {
+        start: to_taffy_dimension(rect.left, scale_factor),
+        end: to_taffy_dimension(rect.right, scale_factor),
+        top: to_taffy_dimension(rect.top, scale_factor),
+        bottom: to_taffy_dimension(rect.bottom, scale_factor),
+    }
+}
+
+fn to_taffy_size(size: Size, scale_factor: f64) -> geometry::Size<Dimension> {
+    geometry::Size {
+        width: to_taffy_dimension(size.width, scale_factor),
+        height: to_taffy_dimension(size.height, scale_factor),
+    }
+}
+
+/// The taffy equivalent of `style`, converted the same way Bevy UI converts it for layout.
+///
+/// `direction` and `overflow` have no taffy equivalent and are ignored.
+pub fn to_taffy_style(style: &Style, scale_factor: f64) -> taffy::style::Style {
+    taffy::style::Style {
+        display: style.display.into(),
+        position_type: style.position_type.into(),
+        flex_direction: style.flex_direction.into(),
+        flex_wrap: style.flex_wrap.into(),
+        align_items: style.align_items.into(),
+        align_self: style.align_self.into(),
+        align_content: style.align_content.into(),
+        justify_content: style.justify_content.into(),
+        position: to_taffy_rect(style.position, scale_factor),
+        margin: to_taffy_rect(style.margin, scale_factor),
+        padding: to_taffy_rect(style.padding, scale_factor),
+        border: to_taffy_rect(style.border, scale_factor),
+        flex_grow: style.flex_grow,
+        flex_shrink: style.flex_shrink,
+        flex_basis: to_taffy_dimension(style.flex_basis, scale_factor),
+        size: to_taffy_size(style.size, scale_factor),
+        min_size: to_taffy_size(style.min_size, scale_factor),
+        max_size: to_taffy_size(style.max_size, scale_factor),
+        aspect_ratio: match style.aspect_ratio {
+            Some(aspect_ratio) => Number::Defined(aspect_ratio),
+            None => Number::Undefined,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn builder_styles_lay_out_in_taffy() {
+        let mut taffy = taffy::Taffy::new();
+        let child_style = style().size((Val::Percent(50.), Val::Px(20.)));
+        let child = taffy
+            .new_node(to_taffy_style(&child_style, 2.), &[])
+            .unwrap();
+        let root_style = style()
+            .size((Val::Px(100.), Val::Px(100.)))
+            .padding(Breadth::Px(10.));
+        let root = taffy
+            .new_node(to_taffy_style(&root_style, 2.), &[child])
+            .unwrap();
+        taffy
+            .compute_layout(root, geometry::Size::undefined())
+            .unwrap();
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.location, geometry::Point { x: 20., y: 20. });
+        assert_eq!(layout.size.height, 40.);
+    }
+}
@@ -0,0 +1,338 @@
+use ab_glyph::Font as _;
+use ab_glyph::PxScale;
+use ab_glyph::ScaleFont as _;
+use bevy::prelude::*;
+use bevy::ui::widget::text_system;
+use bevy::ui::UiSystem;
+
+/// The width in logical pixels of `text` set on a single line in `font` at `font_size`.
+pub fn text_width(font: &Font, font_size: f32, text: &str) -> f32 {
+    let font = font.font.as_scaled(PxScale::from(font_size));
+    let mut width = 0.;
+    let mut previous = None;
+    for c in text.chars() {
+        let glyph = font.glyph_id(c);
+        if let Some(previous) = previous {
+            width += font.kern(previous, glyph);
+        }
+        width += font.h_advance(glyph);
+        previous = Some(glyph);
+    }
+    width
+}
+
+/// Shorten `sections` to fit in `max_width`, ending them with `ellipsis`.
+///
+/// `measure` returns the width of a string set in the style of the section at an index.
+/// Sections after the one that is cut are emptied.
+pub fn truncate_sections(
+    sections: &[String],
+    ellipsis: &str,
+    max_width: f32,
+    measure: impl Fn(usize, &str) -> f32,
+) -> Vec<String> {
+    let total: f32 = sections
+        .iter()
+        .enumerate()
+        .map(|(index, section)| measure(index, section))
+        .sum();
+    if total <= max_width {
+        return sections.to_vec();
+    }
+    let mut used = 0.;
+    let mut truncated = Vec::with_capacity(sections.len());
+    for (index, section) in sections.iter().enumerate() {
+        let available = max_width - used - measure(index, ellipsis);
+        let width = measure(index, section);
+        if width <= available {
+            used += width;
+            truncated.push(section.clone());
+            continue;
+        }
+        let cut = section
+            .char_indices()
+            .map(|(i, _)| i)
+            .rev()
+            .find(|&i| measure(index, &section[..i]) <= available)
+            .unwrap_or(0);
+        truncated.push(format!("{}{ellipsis}", section[..cut].trim_end()));
+        truncated.resize(sections.len(), String::new());
+        break;
+    }
+    truncated
+}
+
+/// The width available to a child of a node, inside the node's padding.
+fn content_width(node: &Node, style: &Style) -> f32 {
+    let padding = |val: Val| match val {
+        Val::Px(px) => px,
+        Val::Percent(percent) => node.size().x * percent / 100.,
+        _ => 0.,
+    };
+    (node.size().x - padding(style.padding.left) - padding(style.padding.right)).max(0.)
+}
+
+/// Shortens a text that is too wide for its parent, ending it with an ellipsis.
+///
+/// The full text is kept and the text is truncated again when the parent is resized.
+/// Changing the text's sections replaces the full text.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TruncateText {
+    pub ellipsis: String,
+    original: Vec<String>,
+    written: Vec<String>,
+    width: Option<f32>,
+}
+
+impl Default for TruncateText {
+    fn default() -> Self {
+        Self {
+            ellipsis: "...".to_string(),
+            original: vec![],
+            written: vec![],
+            width: None,
+        }
+    }
+}
+
+impl TruncateText {
+    pub fn with_ellipsis(ellipsis: impl Into<String>) -> Self {
+        Self {
+            ellipsis: ellipsis.into(),
+            ..default()
+        }
+    }
+
+    /// The text before truncation.
+    pub fn original(&self) -> &[String] {
+        &self.original
+    }
+}
+
+pub trait TruncateTextExt {
+    /// Shorten the text with an ellipsis when it is too wide for its parent.
+    fn truncate(self) -> (TextBundle, TruncateText);
+}
+
+impl TruncateTextExt for TextBundle {
+    fn truncate(self) -> (TextBundle, TruncateText) {
+        (self, TruncateText::default())
+    }
+}
+
+/// Truncates each text with a [`TruncateText`] to fit the width of its parent.
+pub fn truncate_text_system(
+    fonts: Option<Res<Assets<Font>>>,
+    mut texts: Query<(&mut TruncateText, &mut Text, &Parent)>,
+    parents: Query<(&Node, &Style)>,
+) {
+    let Some(fonts) = fonts else {
+        return;
+    };
+    for (mut truncate, mut text, parent) in texts.iter_mut() {
+        let current = text
+            .sections
+            .iter()
+            .map(|section| section.value.clone())
+            .collect::<Vec<_>>();
+        if current != truncate.written {
+            truncate.original = current.clone();
+            truncate.written = current;
+            truncate.width = None;
+        }
+        let Ok((node, style)) = parents.get(parent.get()) else {
+            continue;
+        };
+        let width = content_width(node, style);
+        if truncate.width == Some(width) {
+            continue;
+        }
+        let Some(section_fonts) = text
+            .sections
+            .iter()
+            .map(|section| fonts.get(&section.style.font))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let truncated =
+            truncate_sections(&truncate.original, &truncate.ellipsis, width, |index, s| {
+                text_width(
+                    section_fonts[index],
+                    text.sections[index].style.font_size,
+                    s,
+                )
+            });
+        for (section, value) in text.sections.iter_mut().zip(&truncated) {
+            if section.value != *value {
+                section.value = value.clone();
+            }
+        }
+        truncate.written = truncated;
+        truncate.width = Some(width);
+    }
+}
+
+/// Scales the font size of a text so that it fills the width of its parent,
+/// within `min_size` and `max_size`. The size is fitted again when the text or the parent's
+/// width changes.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FitText {
+    pub min_size: f32,
+    pub max_size: f32,
+    width: Option<f32>,
+}
+
+impl FromWorld for FitText {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(0., f32::MAX)
+    }
+}
+
+impl FitText {
+    pub fn new(min_size: f32, max_size: f32) -> Self {
+        Self {
+            min_size,
+            max_size,
+            width: None,
+        }
+    }
+
+    /// The font size fitting a text that is `unit_width` wide at a font size of one
+    /// to `available` pixels.
+    pub fn font_size(&self, unit_width: f32, available: f32) -> f32 {
+        if unit_width <= 0. {
+            return self.max_size;
+        }
+        (available / unit_width).clamp(self.min_size, self.max_size)
+    }
+}
+
+pub trait FitTextExt {
+    /// Scale the font size between `min_size` and `max_size` to fit the text to its parent's width.
+    fn fit_text(self, min_size: f32, max_size: f32) -> (TextBundle, FitText);
+}
+
+impl FitTextExt for TextBundle {
+    fn fit_text(self, min_size: f32, max_size: f32) -> (TextBundle, FitText) {
+        (self, FitText::new(min_size, max_size))
+    }
+}
+
+/// Sets the font size of each text with a [`FitText`] to fit the width of its parent.
+///
+/// Every section is given the same size.
+pub fn fit_text_system(
+    fonts: Option<Res<Assets<Font>>>,
+    mut texts: Query<(&mut FitText, &mut Text, &Parent)>,
+    parents: Query<(&Node, &Style)>,
+) {
+    let Some(fonts) = fonts else {
+        return;
+    };
+    for (mut fit, mut text, parent) in texts.iter_mut() {
+        let Ok((node, style)) = parents.get(parent.get()) else {
+            continue;
+        };
+        let width = content_width(node, style);
+        if fit.width == Some(width) && !text.is_changed() {
+            continue;
+        }
+        let Some(unit_width) = text
+            .sections
+            .iter()
+            .map(|section| {
+                fonts
+                    .get(&section.style.font)
+                    .map(|font| text_width(font, 1., &section.value))
+            })
+            .sum::<Option<f32>>()
+        else {
+            continue;
+        };
+        let font_size = fit.font_size(unit_width, width);
+        for section in text.sections.iter_mut() {
+            if section.style.font_size != font_size {
+                section.style.font_size = font_size;
+            }
+        }
+        fit.width = Some(width);
+    }
+}
+
+/// Adds the systems that fit text to the size of its node.
+pub struct StyleTextPlugin;
+
+impl Plugin for StyleTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            fit_text_system
+                .before(truncate_text_system)
+                .before(text_system)
+                .before(UiSystem::Flex),
+        )
+        .add_system_to_stage(
+            CoreStage::PostUpdate,
+            truncate_text_system
+                .before(text_system)
+                .before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monospace(_: usize, s: &str) -> f32 {
+        10. * s.chars().count() as f32
+    }
+
+    #[test]
+    fn text_that_fits_is_unchanged() {
+        let sections = vec!["Start".to_string(), " game".to_string()];
+        assert_eq!(
+            truncate_sections(&sections, "...", 100., monospace),
+            sections
+        );
+    }
+
+    #[test]
+    fn overflowing_text_is_cut_with_ellipsis() {
+        let sections = vec!["Einstellungen".to_string()];
+        assert_eq!(
+            truncate_sections(&sections, "...", 80., monospace),
+            vec!["Einst...".to_string()]
+        );
+        let sections = vec!["Volume: ".to_string(), "100%".to_string()];
+        assert_eq!(
+            truncate_sections(&sections, "...", 110., monospace),
+            vec!["Volume: ".to_string(), "...".to_string()]
+        );
+        assert_eq!(
+            truncate_sections(&sections, "...", 100., monospace),
+            vec!["Volume:...".to_string(), String::new()]
+        );
+    }
+
+    #[test]
+    fn fitted_font_size_is_clamped() {
+        let fit = FitText::new(12., 48.);
+        assert_eq!(fit.font_size(5., 100.), 20.);
+        assert_eq!(fit.font_size(5., 1000.), 48.);
+        assert_eq!(fit.font_size(5., 10.), 12.);
+        assert_eq!(fit.font_size(0., 10.), 48.);
+    }
+
+    #[test]
+    fn trailing_whitespace_is_trimmed_before_the_ellipsis() {
+        let sections = vec!["Load game".to_string()];
+        assert_eq!(
+            truncate_sections(&sections, "...", 80., monospace),
+            vec!["Load...".to_string()]
+        );
+    }
+}
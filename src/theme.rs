@@ -0,0 +1,87 @@
+use crate::parse_val;
+use crate::ParseBreadthError;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::borrow::Cow;
+use thiserror::Error;
+
+#[derive(Debug, Eq, PartialEq, Clone, Error)]
+pub enum ThemeError {
+    #[error("the theme has no variable `${0}`")]
+    UnknownVariable(String),
+    #[error(transparent)]
+    InvalidValue(#[from] ParseBreadthError),
+}
+
+/// Named lengths shared by the styles of a theme, such as `spacing-2` or `radius`.
+///
+/// Styles registered with [`StyleRegistry::register_themed`](crate::registry::StyleRegistry::register_themed)
+/// read the tokens when they are applied, and are re-applied whenever the tokens change,
+/// so swapping the tokens restyles every themed node.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct ThemeTokens {
+    vals: HashMap<Cow<'static, str>, Val>,
+}
+
+impl ThemeTokens {
+    /// Set the variable `name`, without the leading `$`.
+    pub fn set(&mut self, name: impl Into<Cow<'static, str>>, val: Val) -> &mut Self {
+        self.vals.insert(name.into(), val);
+        self
+    }
+
+    pub fn with(mut self, name: impl Into<Cow<'static, str>>, val: Val) -> Self {
+        self.set(name, val);
+        self
+    }
+
+    /// The value of the variable `name`, with or without the leading `$`.
+    pub fn get(&self, name: &str) -> Option<Val> {
+        self.vals
+            .get(name.strip_prefix('$').unwrap_or(name))
+            .copied()
+    }
+
+    /// The value of the variable `name`, or [`Val::Undefined`] if it isn't set.
+    pub fn val(&self, name: &str) -> Val {
+        self.get(name).unwrap_or(Val::Undefined)
+    }
+
+    /// Parses a value such as `"10px"`, or looks up a variable such as `"$spacing-2"`.
+    pub fn parse_val(&self, s: &str) -> Result<Val, ThemeError> {
+        let value = s.trim();
+        match value.strip_prefix('$') {
+            Some(name) => self
+                .get(name)
+                .ok_or_else(|| ThemeError::UnknownVariable(name.to_string())),
+            None => Ok(parse_val(value)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variables_are_looked_up_by_name() {
+        let tokens = ThemeTokens::default()
+            .with("spacing-2", Val::Px(8.))
+            .with("sidebar", Val::Percent(25.));
+        assert_eq!(tokens.val("$spacing-2"), Val::Px(8.));
+        assert_eq!(tokens.val("sidebar"), Val::Percent(25.));
+        assert_eq!(tokens.val("$missing"), Val::Undefined);
+        assert_eq!(tokens.parse_val(" $sidebar "), Ok(Val::Percent(25.)));
+        assert_eq!(tokens.parse_val("auto"), Ok(Val::Auto));
+        assert_eq!(
+            tokens.parse_val("$gap"),
+            Err(ThemeError::UnknownVariable("gap".to_string()))
+        );
+        assert!(matches!(
+            tokens.parse_val("4em"),
+            Err(ThemeError::InvalidValue(
+                ParseBreadthError::UnknownUnit { .. }
+            ))
+        ));
+    }
+}
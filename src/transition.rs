@@ -0,0 +1,351 @@
+use crate::animation::context_size;
+use crate::easing::Ease;
+use crate::lerp::lerp_color;
+use crate::lerp::lerp_rect;
+use crate::lerp::lerp_size;
+use crate::lerp::lerp_val;
+use crate::spring::Spring;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A group of properties that can be transitioned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    /// Every property without its own entry.
+    All,
+    Size,
+    MinSize,
+    MaxSize,
+    Position,
+    Margin,
+    Padding,
+    Border,
+    /// `flex_grow`, `flex_shrink` and `flex_basis`.
+    Flex,
+    BackgroundColor,
+}
+
+const PROPERTIES: [TransitionProperty; 9] = [
+    TransitionProperty::Size,
+    TransitionProperty::MinSize,
+    TransitionProperty::MaxSize,
+    TransitionProperty::Position,
+    TransitionProperty::Margin,
+    TransitionProperty::Padding,
+    TransitionProperty::Border,
+    TransitionProperty::Flex,
+    TransitionProperty::BackgroundColor,
+];
+
+/// How a transitioned property moves towards its target.
+#[derive(Copy, Clone, Debug)]
+pub enum TransitionTiming {
+    Eased(Duration, Ease),
+    /// Follow a spring released at rest. Springs may overshoot before settling.
+    Spring(Spring),
+}
+
+impl TransitionTiming {
+    /// The time until the property reaches its target.
+    pub fn duration(&self) -> Duration {
+        match self {
+            TransitionTiming::Eased(duration, _) => *duration,
+            TransitionTiming::Spring(spring) => spring.settle_duration(),
+        }
+    }
+
+    /// The progress towards the target after `elapsed`.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self {
+            TransitionTiming::Eased(duration, _) if duration.is_zero() => 1.,
+            TransitionTiming::Eased(duration, ease) => {
+                ease.sample(elapsed.as_secs_f32() / duration.as_secs_f32())
+            }
+            TransitionTiming::Spring(_) if self.duration() <= elapsed => 1.,
+            TransitionTiming::Spring(spring) => spring.progress(elapsed),
+        }
+    }
+}
+
+/// Smoothly animates a node to its new [`Style`] and [`BackgroundColor`] whenever
+/// they are changed, like a CSS `transition`.
+///
+/// Each property can have its own duration and easing, or a [`Spring`]. Properties without an entry,
+/// and discrete properties such as `display`, change immediately.
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleTransition {
+    properties: Vec<(TransitionProperty, TransitionTiming)>,
+    state: Option<TransitionState>,
+}
+
+#[derive(Clone, Debug)]
+struct TransitionState {
+    from: (Style, Option<Color>),
+    to: (Style, Option<Color>),
+    written: (Style, Option<Color>),
+    elapsed: Duration,
+}
+
+impl StyleTransition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Transition every property with the same duration and easing.
+    pub fn all(duration: Duration, ease: Ease) -> Self {
+        Self::new().property(TransitionProperty::All, duration, ease)
+    }
+
+    /// Transition every property with the same spring.
+    pub fn all_spring(spring: Spring) -> Self {
+        Self::new().spring(TransitionProperty::All, spring)
+    }
+
+    /// Set the duration and easing for a property, replacing any previous entry.
+    pub fn property(self, property: TransitionProperty, duration: Duration, ease: Ease) -> Self {
+        self.timed(property, TransitionTiming::Eased(duration, ease))
+    }
+
+    /// Animate a property with a spring, replacing any previous entry.
+    pub fn spring(self, property: TransitionProperty, spring: Spring) -> Self {
+        self.timed(property, TransitionTiming::Spring(spring))
+    }
+
+    /// Set the timing for a property, replacing any previous entry.
+    pub fn timed(mut self, property: TransitionProperty, timing: TransitionTiming) -> Self {
+        self.properties
+            .retain(|(existing, ..)| *existing != property);
+        self.properties.push((property, timing));
+        self
+    }
+
+    fn timing(&self, property: TransitionProperty) -> Option<TransitionTiming> {
+        let find = |property| {
+            self.properties
+                .iter()
+                .find(|(existing, ..)| *existing == property)
+                .map(|(_, timing)| *timing)
+        };
+        find(property).or_else(|| find(TransitionProperty::All))
+    }
+
+    /// The progress of `property` after `elapsed`.
+    pub fn progress(&self, property: TransitionProperty, elapsed: Duration) -> f32 {
+        self.timing(property)
+            .map_or(1., |timing| timing.progress(elapsed))
+    }
+
+    /// Whether every property has reached its target.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        PROPERTIES.iter().all(|property| {
+            self.timing(*property)
+                .map_or(true, |timing| timing.duration() <= elapsed)
+        })
+    }
+
+    /// The style and color `elapsed` into a transition from `from` to `to`.
+    pub fn sample(
+        &self,
+        from: &(Style, Option<Color>),
+        to: &(Style, Option<Color>),
+        elapsed: Duration,
+        context_size: Vec2,
+    ) -> (Style, Option<Color>) {
+        let t = |property| self.progress(property, elapsed);
+        let (a, b) = (&from.0, &to.0);
+        let mut style = b.clone();
+        style.size = lerp_size(a.size, b.size, t(TransitionProperty::Size), context_size);
+        style.min_size = lerp_size(
+            a.min_size,
+            b.min_size,
+            t(TransitionProperty::MinSize),
+            context_size,
+        );
+        style.max_size = lerp_size(
+            a.max_size,
+            b.max_size,
+            t(TransitionProperty::MaxSize),
+            context_size,
+        );
+        style.position = lerp_rect(
+            a.position,
+            b.position,
+            t(TransitionProperty::Position),
+            context_size,
+        );
+        style.margin = lerp_rect(
+            a.margin,
+            b.margin,
+            t(TransitionProperty::Margin),
+            context_size,
+        );
+        style.padding = lerp_rect(
+            a.padding,
+            b.padding,
+            t(TransitionProperty::Padding),
+            context_size,
+        );
+        style.border = lerp_rect(
+            a.border,
+            b.border,
+            t(TransitionProperty::Border),
+            context_size,
+        );
+        let flex = t(TransitionProperty::Flex);
+        if flex < 1. {
+            style.flex_grow = a.flex_grow + (b.flex_grow - a.flex_grow) * flex;
+            style.flex_shrink = a.flex_shrink + (b.flex_shrink - a.flex_shrink) * flex;
+        }
+        style.flex_basis = lerp_val(a.flex_basis, b.flex_basis, flex, context_size.x);
+        let color = match (from.1, to.1) {
+            (Some(a), Some(b)) => Some(lerp_color(
+                a,
+                b,
+                t(TransitionProperty::BackgroundColor).min(1.),
+            )),
+            (_, color) => color,
+        };
+        (style, color)
+    }
+}
+
+pub trait StyleTransitionExt: Bundle + Sized {
+    /// Animate changes to the node's style with `transition`.
+    fn transition(self, transition: StyleTransition) -> (Self, StyleTransition) {
+        (self, transition)
+    }
+}
+
+impl<T: Bundle> StyleTransitionExt for T {}
+
+/// Starts a transition whenever a node's style or color is changed by something else,
+/// and writes the interpolated values while it runs.
+pub fn style_transition_system(
+    time: Res<Time>,
+    windows: Option<Res<Windows>>,
+    mut query: Query<(
+        &mut StyleTransition,
+        &mut Style,
+        Option<&mut BackgroundColor>,
+        Option<&Parent>,
+    )>,
+    nodes: Query<&Node>,
+) {
+    for (mut transition, mut style, mut color, parent) in query.iter_mut() {
+        let transition = transition.bypass_change_detection();
+        let current = (style.clone(), color.as_deref().map(|color| color.0));
+        let Some(mut state) = transition.state.take() else {
+            transition.state = Some(TransitionState {
+                from: current.clone(),
+                to: current.clone(),
+                written: current,
+                elapsed: Duration::ZERO,
+            });
+            continue;
+        };
+        if current != state.written {
+            state.from = std::mem::replace(&mut state.written, current.clone());
+            state.to = current;
+            state.elapsed = Duration::ZERO;
+        } else if state.written != state.to {
+            state.elapsed += time.delta();
+        } else {
+            transition.state = Some(state);
+            continue;
+        }
+        if transition.is_finished(state.elapsed) {
+            state.written = state.to.clone();
+        } else {
+            let context_size = context_size(parent, &nodes, &windows);
+            state.written = transition.sample(&state.from, &state.to, state.elapsed, context_size);
+        }
+        if *style != state.written.0 {
+            *style = state.written.0.clone();
+        }
+        if let (Some(color), Some(written)) = (color.as_mut(), state.written.1) {
+            if color.0 != written {
+                color.0 = written;
+            }
+        }
+        transition.state = Some(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn per_property_durations() {
+        let transition = StyleTransition::new()
+            .property(
+                TransitionProperty::BackgroundColor,
+                Duration::from_secs(1),
+                Ease::Linear,
+            )
+            .property(
+                TransitionProperty::Size,
+                Duration::from_secs(4),
+                Ease::Linear,
+            );
+        let from = (
+            style().width(Val::Px(0.)).height(Val::Px(0.)),
+            Some(Color::BLACK),
+        );
+        let to = (
+            style()
+                .width(Val::Px(40.))
+                .height(Val::Px(40.))
+                .margin(Val::Px(5.)),
+            Some(Color::WHITE),
+        );
+        let (style, color) = transition.sample(&from, &to, Duration::from_secs(1), Vec2::ZERO);
+        assert_eq!(style.size.width, Val::Px(10.));
+        assert_eq!(style.margin, UiRect::all(Val::Px(5.)));
+        assert_eq!(color, Some(Color::WHITE));
+        assert!(!transition.is_finished(Duration::from_secs(2)));
+        assert!(transition.is_finished(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn spring_transitions_overshoot() {
+        let spring = Spring::bouncy();
+        let transition = StyleTransition::all_spring(spring);
+        let from = (style().width(Val::Px(0.)), None);
+        let to = (style().width(Val::Px(100.)), None);
+        let (style, _) = transition.sample(&from, &to, Duration::from_millis(150), Vec2::ZERO);
+        let Val::Px(width) = style.size.width else {
+            panic!("width should be in pixels");
+        };
+        assert!(100. < width);
+        assert!(!transition.is_finished(Duration::from_millis(150)));
+        assert!(transition.is_finished(spring.settle_duration()));
+        assert_eq!(
+            transition.progress(TransitionProperty::Size, spring.settle_duration()),
+            1.
+        );
+    }
+
+    #[test]
+    fn changes_start_transitions() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_system(style_transition_system);
+        let entity = app
+            .world
+            .spawn((
+                node().width(Val::Px(0.)),
+                StyleTransition::all(Duration::from_secs(1), Ease::Linear),
+            ))
+            .id();
+        app.update();
+        app.world.get_mut::<Style>(entity).unwrap().size.width = Val::Px(100.);
+        app.update();
+        // no time has passed, so the change is held back
+        assert_eq!(
+            app.world.get::<Style>(entity).unwrap().size.width,
+            Val::Px(0.)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::fields::set_if_neq;
+use crate::fields::StyleFields;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+/// A length relative to the size of the primary window, in percent.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub enum ViewportUnit {
+    /// A percentage of the window's width.
+    Vw(f32),
+    /// A percentage of the window's height.
+    Vh(f32),
+    /// A percentage of the window's smaller dimension.
+    VMin(f32),
+    /// A percentage of the window's larger dimension.
+    VMax(f32),
+}
+
+impl ViewportUnit {
+    /// The length in pixels in a viewport of `size`.
+    pub fn evaluate(&self, size: Vec2) -> f32 {
+        match *self {
+            ViewportUnit::Vw(value) => size.x * value / 100.,
+            ViewportUnit::Vh(value) => size.y * value / 100.,
+            ViewportUnit::VMin(value) => size.min_element() * value / 100.,
+            ViewportUnit::VMax(value) => size.max_element() * value / 100.,
+        }
+    }
+}
+
+/// A length in viewport units kept between `min` and `max` pixels, like CSS `clamp()`.
+///
+/// If `min` is greater than `max`, `min` wins.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub struct ViewportLength {
+    pub unit: ViewportUnit,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl ViewportLength {
+    /// A percentage of the window's width, clamped to between `min` and `max` pixels.
+    pub fn clamped_vw(percent: f32, min: f32, max: f32) -> Self {
+        ViewportUnit::Vw(percent).clamped(min, max)
+    }
+
+    /// A percentage of the window's height, clamped to between `min` and `max` pixels.
+    pub fn clamped_vh(percent: f32, min: f32, max: f32) -> Self {
+        ViewportUnit::Vh(percent).clamped(min, max)
+    }
+
+    /// The length in pixels in a viewport of `size`.
+    pub fn evaluate(&self, size: Vec2) -> f32 {
+        self.unit.evaluate(size).min(self.max).max(self.min)
+    }
+}
+
+impl ViewportUnit {
+    /// Keep the length between `min` and `max` pixels.
+    pub fn clamped(self, min: f32, max: f32) -> ViewportLength {
+        ViewportLength {
+            unit: self,
+            min,
+            max,
+        }
+    }
+}
+
+impl From<ViewportUnit> for ViewportLength {
+    fn from(unit: ViewportUnit) -> Self {
+        unit.clamped(0., f32::INFINITY)
+    }
+}
+
+/// Sets a node's size in viewport units, which are converted to pixels as the window is resized.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ViewportSize {
+    pub width: Option<ViewportLength>,
+    pub height: Option<ViewportLength>,
+}
+
+pub trait ViewportSizeExt: Bundle + Sized {
+    /// Size the node in viewport units.
+    fn viewport_size(
+        self,
+        width: impl Into<ViewportLength>,
+        height: impl Into<ViewportLength>,
+    ) -> (Self, ViewportSize) {
+        (
+            self,
+            ViewportSize {
+                width: Some(width.into()),
+                height: Some(height.into()),
+            },
+        )
+    }
+
+    /// Set the node's width in viewport units.
+    fn viewport_width(self, width: impl Into<ViewportLength>) -> (Self, ViewportSize) {
+        (
+            self,
+            ViewportSize {
+                width: Some(width.into()),
+                height: None,
+            },
+        )
+    }
+
+    /// Set the node's height in viewport units.
+    fn viewport_height(self, height: impl Into<ViewportLength>) -> (Self, ViewportSize) {
+        (
+            self,
+            ViewportSize {
+                width: None,
+                height: Some(height.into()),
+            },
+        )
+    }
+}
+
+impl<T: Bundle> ViewportSizeExt for T {}
+
+/// The size of the primary window in UI pixels.
+pub(crate) fn viewport_size(windows: &Windows, ui_scale: Option<&UiScale>) -> Option<Vec2> {
+    let window = windows.get_primary()?;
+    let scale = ui_scale.map_or(1., |ui_scale| ui_scale.scale as f32);
+    Some(Vec2::new(window.width(), window.height()) / scale)
+}
+
+/// Converts the [`ViewportSize`] of each node to pixels.
+pub fn viewport_size_system(
+    windows: Option<Res<Windows>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut query: Query<(&ViewportSize, &mut Style)>,
+) {
+    let Some(viewport) = windows.and_then(|windows| viewport_size(&windows, ui_scale.as_deref()))
+    else {
+        return;
+    };
+    for (size, mut style) in query.iter_mut() {
+        if let Some(width) = size.width {
+            set_if_neq(
+                &mut style,
+                Style::width_mut,
+                Val::Px(width.evaluate(viewport)),
+            );
+        }
+        if let Some(height) = size.height {
+            set_if_neq(
+                &mut style,
+                Style::height_mut,
+                Val::Px(height.evaluate(viewport)),
+            );
+        }
+    }
+}
+
+/// Adds the system that resolves viewport units.
+pub struct StyleViewportPlugin;
+
+impl Plugin for StyleViewportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            viewport_size_system.before(UiSystem::Flex),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::window::WindowId;
+
+    #[test]
+    fn units_evaluate_against_the_viewport() {
+        let portrait = Vec2::new(600., 1000.);
+        assert_eq!(ViewportUnit::Vw(50.).evaluate(portrait), 300.);
+        assert_eq!(ViewportUnit::Vh(50.).evaluate(portrait), 500.);
+        assert_eq!(ViewportUnit::VMin(10.).evaluate(portrait), 60.);
+        assert_eq!(ViewportUnit::VMax(10.).evaluate(portrait), 100.);
+    }
+
+    #[test]
+    fn clamped_lengths_stay_in_bounds() {
+        let panel = ViewportLength::clamped_vw(25., 200., 400.);
+        assert_eq!(panel.evaluate(Vec2::new(400., 300.)), 200.);
+        assert_eq!(panel.evaluate(Vec2::new(1200., 800.)), 300.);
+        assert_eq!(panel.evaluate(Vec2::new(4000., 2000.)), 400.);
+        assert_eq!(
+            ViewportUnit::Vh(10.).clamped(50., 20.).evaluate(Vec2::ZERO),
+            50.
+        );
+    }
+
+    #[test]
+    fn size_follows_the_window() {
+        let mut app = App::new();
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor {
+                width: 800.,
+                height: 400.,
+                ..default()
+            },
+            800,
+            400,
+            1.,
+            None,
+            None,
+        ));
+        app.insert_resource(windows)
+            .insert_resource(UiScale { scale: 2. })
+            .add_system(viewport_size_system);
+        let square = app
+            .world
+            .spawn(
+                NodeBundle::default()
+                    .viewport_size(ViewportUnit::VMin(50.), ViewportUnit::VMin(50.)),
+            )
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<Style>(square).unwrap().size,
+            Size::new(Val::Px(100.), Val::Px(100.))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+/// The global z-index of a backdrop spawned with [`backdrop`].
+pub const BACKDROP_Z_INDEX: i32 = 1000;
+
+/// A fullscreen node that dims and blocks interaction with everything behind it.
+///
+/// Spawn the modal, dropdown or menu it belongs to as its child, so that it is drawn above the
+/// backdrop. Clicks that reach the backdrop itself send a [`BackdropDismissed`] event, so the
+/// content should block clicks with [`FocusPolicy::Block`], as buttons do.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Backdrop {
+    /// Whether the backdrop and its children are despawned when it is clicked.
+    pub despawn_on_dismiss: bool,
+}
+
+impl Default for Backdrop {
+    fn default() -> Self {
+        Self {
+            despawn_on_dismiss: true,
+        }
+    }
+}
+
+/// Sent when a [`Backdrop`] is clicked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BackdropDismissed {
+    pub backdrop: Entity,
+}
+
+/// A backdrop dimming the screen with black at `alpha`.
+pub fn backdrop(alpha: f32) -> (NodeBundle, Backdrop, Interaction) {
+    backdrop_with_color(Color::rgba(0., 0., 0., alpha))
+}
+
+/// A backdrop covering the screen with `color`.
+pub fn backdrop_with_color(color: Color) -> (NodeBundle, Backdrop, Interaction) {
+    (
+        NodeBundle {
+            background_color: color.into(),
+            focus_policy: FocusPolicy::Block,
+            z_index: ZIndex::Global(BACKDROP_Z_INDEX),
+            ..default()
+        }
+        .absolute()
+        .left(Val::Px(0.))
+        .top(Val::Px(0.))
+        .size((Val::Percent(100.), Val::Percent(100.)))
+        .justify_content(JustifyContent::Center)
+        .align_items_center(),
+        Backdrop::default(),
+        Interaction::None,
+    )
+}
+
+/// Sends a [`BackdropDismissed`] event when a [`Backdrop`] is clicked.
+pub fn backdrop_system(
+    mut commands: Commands,
+    backdrops: Query<(Entity, &Backdrop, &Interaction), Changed<Interaction>>,
+    mut events: EventWriter<BackdropDismissed>,
+) {
+    for (entity, backdrop, interaction) in backdrops.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        events.send(BackdropDismissed { backdrop: entity });
+        if backdrop.despawn_on_dismiss {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_dismisses() {
+        let mut app = App::new();
+        app.add_event::<BackdropDismissed>()
+            .add_system(backdrop_system);
+        let kept = app
+            .world
+            .spawn(backdrop(0.5))
+            .insert(Backdrop {
+                despawn_on_dismiss: false,
+            })
+            .id();
+        let despawned = app.world.spawn(backdrop(0.5)).id();
+        app.update();
+        for entity in [kept, despawned] {
+            *app.world.get_mut::<Interaction>(entity).unwrap() = Interaction::Clicked;
+        }
+        app.update();
+        let events = app.world.resource::<Events<BackdropDismissed>>();
+        assert_eq!(events.get_reader().iter(events).count(), 2);
+        assert!(app.world.get_entity(kept).is_some());
+        assert!(app.world.get_entity(despawned).is_none());
+    }
+}
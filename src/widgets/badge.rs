@@ -0,0 +1,199 @@
+use crate::fields::StyleFields;
+use crate::Breadth;
+use crate::NodeColorExt;
+use crate::NumRect;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+
+/// The colors of a [`Pill`].
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub struct PillColors {
+    pub background: Color,
+    pub text: Color,
+}
+
+impl PillColors {
+    /// White on red, for counts and notifications.
+    pub const BADGE: Self = Self {
+        background: Color::rgb(0.8, 0.15, 0.15),
+        text: Color::WHITE,
+    };
+    /// Light text on grey, for tags and filters.
+    pub const CHIP: Self = Self {
+        background: Color::rgb(0.3, 0.3, 0.3),
+        text: Color::rgb(0.9, 0.9, 0.9),
+    };
+    /// White on blue.
+    pub const PRIMARY: Self = Self {
+        background: Color::rgb(0.2, 0.4, 0.8),
+        text: Color::WHITE,
+    };
+    /// White on green.
+    pub const SUCCESS: Self = Self {
+        background: Color::rgb(0.2, 0.6, 0.3),
+        text: Color::WHITE,
+    };
+}
+
+/// A small node showing a short text on a colored background.
+///
+/// The text child is rebuilt whenever the component changes.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Pill {
+    pub text: String,
+    pub colors: PillColors,
+    /// The style of the text. Its color is replaced by the text color of [`Pill::colors`].
+    pub text_style: TextStyle,
+}
+
+impl FromWorld for Pill {
+    fn from_world(_: &mut World) -> Self {
+        Self::new("", PillColors::CHIP)
+    }
+}
+
+impl Pill {
+    pub fn new(text: impl Into<String>, colors: PillColors) -> Self {
+        Self {
+            text: text.into(),
+            colors,
+            text_style: TextStyle {
+                font_size: 12.,
+                ..default()
+            },
+        }
+    }
+}
+
+/// A corner of a node's parent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+pub trait CornerExt: StyleBuilderExt {
+    /// Position the node absolutely at `corner` of its parent.
+    fn at_corner(self, corner: Corner) -> Self {
+        self.update_style(|style| {
+            style.position_type = PositionType::Absolute;
+            let (left, right, top, bottom) = match corner {
+                Corner::TopLeft => (Val::Px(0.), Val::Undefined, Val::Px(0.), Val::Undefined),
+                Corner::TopRight => (Val::Undefined, Val::Px(0.), Val::Px(0.), Val::Undefined),
+                Corner::BottomLeft => (Val::Px(0.), Val::Undefined, Val::Undefined, Val::Px(0.)),
+                Corner::BottomRight => (Val::Undefined, Val::Px(0.), Val::Undefined, Val::Px(0.)),
+            };
+            *style.left_mut() = left;
+            *style.right_mut() = right;
+            *style.top_mut() = top;
+            *style.bottom_mut() = bottom;
+        })
+    }
+}
+
+impl<T: StyleBuilderExt> CornerExt for T {}
+
+/// A pill showing `text` with `colors`.
+pub fn pill(text: impl Into<String>, colors: PillColors) -> (NodeBundle, Pill) {
+    (
+        NodeBundle::default()
+            .row()
+            .shrink(0.)
+            .align_items_center()
+            .justify_content(JustifyContent::Center)
+            .padding(NumRect::new(
+                Breadth::Px(5.),
+                Breadth::Px(5.),
+                Breadth::Px(1.),
+                Breadth::Px(1.),
+            ))
+            .background_color(colors.background),
+        Pill::new(text, colors),
+    )
+}
+
+/// A small count or notification marker, such as the number of items in an inventory slot.
+pub fn badge(text: impl Into<String>) -> (NodeBundle, Pill) {
+    pill(text, PillColors::BADGE)
+}
+
+/// A label for a tag or filter.
+pub fn chip(label: impl Into<String>) -> (NodeBundle, Pill) {
+    let (node, mut pill) = pill(label, PillColors::CHIP);
+    pill.text_style.font_size = 14.;
+    (
+        node.padding(NumRect::new(
+            Breadth::Px(8.),
+            Breadth::Px(8.),
+            Breadth::Px(3.),
+            Breadth::Px(3.),
+        ))
+        .margin(Val::Px(2.)),
+        pill,
+    )
+}
+
+/// Rebuilds the text of changed [`Pill`]s and updates their background colors.
+pub fn pill_system(
+    mut commands: Commands,
+    mut pills: Query<(Entity, &Pill, &mut BackgroundColor, Option<&Children>), Changed<Pill>>,
+) {
+    for (entity, pill, mut background, children) in pills.iter_mut() {
+        background.0 = pill.colors.background;
+        if let Some(children) = children {
+            for child in children.iter() {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                pill.text.clone(),
+                TextStyle {
+                    color: pill.colors.text,
+                    ..pill.text_style.clone()
+                },
+            ));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badge_at_corner() {
+        let (node, _) = badge("3").at_corner(Corner::TopRight);
+        assert_eq!(node.style.position_type, PositionType::Absolute);
+        assert_eq!(node.style.position.right, Val::Px(0.));
+        assert_eq!(node.style.position.top, Val::Px(0.));
+        assert_eq!(node.style.position.left, Val::Undefined);
+    }
+
+    #[test]
+    fn text_is_rebuilt_on_change() {
+        let mut app = App::new();
+        app.add_system(pill_system);
+        let entity = app.world.spawn(chip("fire")).id();
+        app.update();
+        let text = |world: &World| {
+            let children = world.get::<Children>(entity).unwrap();
+            assert_eq!(children.len(), 1);
+            world.get::<Text>(children[0]).unwrap().sections[0].clone()
+        };
+        assert_eq!(text(&app.world).value, "fire");
+        let mut pill = app.world.get_mut::<Pill>(entity).unwrap();
+        pill.text = "ice".to_string();
+        pill.colors = PillColors::PRIMARY;
+        app.update();
+        assert_eq!(text(&app.world).value, "ice");
+        assert_eq!(text(&app.world).style.color, Color::WHITE);
+        assert_eq!(
+            app.world.get::<BackgroundColor>(entity).unwrap().0,
+            PillColors::PRIMARY.background
+        );
+    }
+}
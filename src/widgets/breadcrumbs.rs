@@ -0,0 +1,184 @@
+use crate::Breadth;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+/// A row of clickable path segments separated by dividers.
+///
+/// The row's children are rebuilt whenever the component changes, so a new path can be shown by
+/// replacing [`Breadcrumbs::segments`].
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Breadcrumbs {
+    pub segments: Vec<String>,
+    pub divider: String,
+    pub text_style: TextStyle,
+    pub divider_color: Color,
+    /// The color of the last segment, the current location.
+    pub current_color: Color,
+}
+
+impl FromWorld for Breadcrumbs {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+impl Breadcrumbs {
+    pub fn new(segments: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+            divider: "/".to_string(),
+            text_style: TextStyle::default(),
+            divider_color: Color::GRAY,
+            current_color: Color::WHITE,
+        }
+    }
+
+    pub fn with_divider(mut self, divider: impl Into<String>) -> Self {
+        self.divider = divider.into();
+        self
+    }
+
+    pub fn with_text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+}
+
+/// Sent when a segment of a [`Breadcrumbs`] row is clicked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbClicked {
+    pub breadcrumbs: Entity,
+    pub index: usize,
+}
+
+/// A clickable segment of a [`Breadcrumbs`] row.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct BreadcrumbSegment {
+    pub breadcrumbs: Entity,
+    pub index: usize,
+}
+
+impl_entity_links! {
+    BreadcrumbSegment { breadcrumbs; index }
+}
+
+/// A row of breadcrumbs showing `segments`.
+pub fn breadcrumbs(
+    segments: impl IntoIterator<Item = impl Into<String>>,
+) -> (NodeBundle, Breadcrumbs) {
+    (
+        NodeBundle::default().row().align_items_center(),
+        Breadcrumbs::new(segments),
+    )
+}
+
+/// Rebuilds the segments of changed [`Breadcrumbs`].
+pub fn breadcrumbs_system(
+    mut commands: Commands,
+    changed: Query<(Entity, &Breadcrumbs, Option<&Children>), Changed<Breadcrumbs>>,
+) {
+    for (entity, breadcrumbs, children) in changed.iter() {
+        if let Some(children) = children {
+            for child in children.iter() {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        commands.entity(entity).with_children(|row| {
+            let last = breadcrumbs.segments.len().saturating_sub(1);
+            for (index, segment) in breadcrumbs.segments.iter().enumerate() {
+                if 0 < index {
+                    row.spawn(
+                        TextBundle::from_section(
+                            breadcrumbs.divider.clone(),
+                            TextStyle {
+                                color: breadcrumbs.divider_color,
+                                ..breadcrumbs.text_style.clone()
+                            },
+                        )
+                        .margin(UiRect::horizontal(Val::Px(4.))),
+                    );
+                }
+                let mut text_style = breadcrumbs.text_style.clone();
+                if index == last {
+                    text_style.color = breadcrumbs.current_color;
+                }
+                row.spawn((
+                    ButtonBundle {
+                        style: crate::style().padding(Breadth::Px(2.)),
+                        background_color: Color::NONE.into(),
+                        ..default()
+                    },
+                    BreadcrumbSegment {
+                        breadcrumbs: entity,
+                        index,
+                    },
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(segment.clone(), text_style));
+                });
+            }
+        });
+    }
+}
+
+/// Sends a [`BreadcrumbClicked`] event when a segment is clicked.
+pub fn breadcrumb_click_system(
+    segments: Query<(&Interaction, &BreadcrumbSegment), Changed<Interaction>>,
+    mut events: EventWriter<BreadcrumbClicked>,
+) {
+    for (interaction, segment) in segments.iter() {
+        if *interaction == Interaction::Clicked {
+            events.send(BreadcrumbClicked {
+                breadcrumbs: segment.breadcrumbs,
+                index: segment.index,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_and_dividers() {
+        let mut app = App::new();
+        app.add_event::<BreadcrumbClicked>()
+            .add_system(breadcrumbs_system)
+            .add_system(breadcrumb_click_system);
+        let entity = app
+            .world
+            .spawn(breadcrumbs(["Home", "Settings", "Audio"]))
+            .id();
+        app.update();
+        // three segments and two dividers
+        assert_eq!(app.world.get::<Children>(entity).unwrap().len(), 5);
+        let settings = app
+            .world
+            .query::<(Entity, &BreadcrumbSegment)>()
+            .iter(&app.world)
+            .find(|(_, segment)| segment.index == 1)
+            .unwrap()
+            .0;
+        *app.world.get_mut::<Interaction>(settings).unwrap() = Interaction::Clicked;
+        app.update();
+        let events = app.world.resource::<Events<BreadcrumbClicked>>();
+        assert_eq!(
+            events.get_reader().iter(events).next(),
+            Some(&BreadcrumbClicked {
+                breadcrumbs: entity,
+                index: 1
+            })
+        );
+        app.world
+            .get_mut::<Breadcrumbs>(entity)
+            .unwrap()
+            .segments
+            .truncate(2);
+        app.update();
+        assert_eq!(app.world.get::<Children>(entity).unwrap().len(), 3);
+    }
+}
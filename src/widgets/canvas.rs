@@ -0,0 +1,123 @@
+use crate::fields::StyleFields;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+
+/// A container whose children with a [`CanvasPosition`] are placed at explicit coordinates
+/// instead of by flexbox.
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Canvas;
+
+/// The position of the top left corner of a child of a [`Canvas`], relative to the canvas.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CanvasPosition {
+    pub x: Val,
+    pub y: Val,
+}
+
+impl CanvasPosition {
+    pub fn new(x: Val, y: Val) -> Self {
+        Self { x, y }
+    }
+
+    pub fn px(x: f32, y: f32) -> Self {
+        Self::new(Val::Px(x), Val::Px(y))
+    }
+
+    /// A position as percentages of the canvas's width and height.
+    pub fn percent(x: f32, y: f32) -> Self {
+        Self::new(Val::Percent(x), Val::Percent(y))
+    }
+}
+
+impl From<(f32, f32)> for CanvasPosition {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::px(x, y)
+    }
+}
+
+impl From<Vec2> for CanvasPosition {
+    fn from(position: Vec2) -> Self {
+        Self::px(position.x, position.y)
+    }
+}
+
+impl From<(Val, Val)> for CanvasPosition {
+    fn from((x, y): (Val, Val)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+pub trait CanvasPositionExt: Bundle + Sized {
+    /// Place the node at `position` in its parent [`Canvas`].
+    fn at(self, position: impl Into<CanvasPosition>) -> (Self, CanvasPosition) {
+        (self, position.into())
+    }
+}
+
+impl<T: Bundle> CanvasPositionExt for T {}
+
+/// A canvas filling its parent.
+pub fn canvas() -> (NodeBundle, Canvas) {
+    (
+        NodeBundle::default().size((Val::Percent(100.), Val::Percent(100.))),
+        Canvas,
+    )
+}
+
+/// Positions the children of each [`Canvas`] absolutely at their [`CanvasPosition`].
+#[allow(clippy::type_complexity)]
+pub fn canvas_system(
+    canvases: Query<(), With<Canvas>>,
+    mut items: Query<
+        (&CanvasPosition, &Parent, &mut Style),
+        Or<(Changed<CanvasPosition>, Changed<Parent>)>,
+    >,
+) {
+    for (position, parent, mut style) in items.iter_mut() {
+        if !canvases.contains(parent.get()) {
+            continue;
+        }
+        if style.position_type != PositionType::Absolute
+            || style.left_val() != position.x
+            || style.top_val() != position.y
+        {
+            style.position_type = PositionType::Absolute;
+            *style.left_mut() = position.x;
+            *style.top_mut() = position.y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn children_are_placed_at_their_positions() {
+        let mut app = App::new();
+        app.add_system(canvas_system);
+        let canvas = app.world.spawn(canvas()).id();
+        let marker = app.world.spawn(NodeBundle::default().at((10., 20.))).id();
+        let outside = app
+            .world
+            .spawn(NodeBundle::default().at(CanvasPosition::percent(50., 50.)))
+            .id();
+        app.world.entity_mut(canvas).push_children(&[marker]);
+        app.update();
+        let style = app.world.get::<Style>(marker).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.position.left, Val::Px(10.));
+        assert_eq!(style.position.top, Val::Px(20.));
+        assert_eq!(
+            app.world.get::<Style>(outside).unwrap().position_type,
+            PositionType::Relative
+        );
+        *app.world.get_mut::<CanvasPosition>(marker).unwrap() = CanvasPosition::percent(25., 75.);
+        app.update();
+        let style = app.world.get::<Style>(marker).unwrap();
+        assert_eq!(style.position.left, Val::Percent(25.));
+        assert_eq!(style.position.top, Val::Percent(75.));
+    }
+}
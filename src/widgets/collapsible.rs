@@ -0,0 +1,393 @@
+use crate::fields::StyleFields;
+use crate::transition::StyleTransition;
+use crate::transition::TransitionProperty;
+use crate::transition::TransitionTiming;
+use crate::Breadth;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+#[cfg(feature = "serialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+/// The options for a section spawned with [`CollapsibleExt::collapsible`].
+#[derive(Clone, Debug)]
+pub struct CollapsibleOptions {
+    pub title: String,
+    /// The key the open state is stored under in [`CollapsibleStates`]. Defaults to the title.
+    pub key: Option<String>,
+    /// Whether the section starts open, if no state is stored for it.
+    pub open: bool,
+    /// If set, the content's height is animated with the transition system
+    /// instead of the content being hidden immediately.
+    pub animation: Option<TransitionTiming>,
+    pub title_style: TextStyle,
+    pub header_color: Color,
+}
+
+impl CollapsibleOptions {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            key: None,
+            open: false,
+            animation: None,
+            title_style: TextStyle::default(),
+            header_color: Color::rgb(0.25, 0.25, 0.25),
+        }
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn animated(mut self, timing: TransitionTiming) -> Self {
+        self.animation = Some(timing);
+        self
+    }
+
+    pub fn with_title_style(mut self, style: TextStyle) -> Self {
+        self.title_style = style;
+        self
+    }
+}
+
+impl From<&str> for CollapsibleOptions {
+    fn from(title: &str) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<String> for CollapsibleOptions {
+    fn from(title: String) -> Self {
+        Self::new(title)
+    }
+}
+
+/// The open state of every [`Collapsible`], by key.
+///
+/// Sections restore their state from here when spawned, so a section keeps its state when
+/// its UI is rebuilt. With the `serialize` feature the states can be saved between runs.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct CollapsibleStates(pub HashMap<String, bool>);
+
+/// A section with a header that shows and hides its content when clicked.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Collapsible {
+    pub key: String,
+    pub open: bool,
+    animated: bool,
+}
+
+impl Collapsible {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+}
+
+/// The clickable header of a [`Collapsible`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct CollapsibleHeader {
+    pub collapsible: Entity,
+}
+
+/// The node holding the content of a [`Collapsible`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct CollapsibleContent {
+    pub collapsible: Entity,
+}
+
+/// The text in a [`Collapsible`]'s header showing whether it is open.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct CollapsibleIndicator {
+    pub collapsible: Entity,
+}
+
+impl_entity_links! {
+    CollapsibleHeader { collapsible }
+    CollapsibleContent { collapsible }
+    CollapsibleIndicator { collapsible }
+}
+
+fn indicator(open: bool) -> &'static str {
+    if open {
+        "v"
+    } else {
+        ">"
+    }
+}
+
+fn build_collapsible(
+    root: &mut EntityCommands,
+    options: CollapsibleOptions,
+    content: impl FnOnce(&mut ChildBuilder),
+) {
+    let collapsible = root.id();
+    root.insert((
+        NodeBundle::default().column(),
+        Collapsible {
+            key: options.key.unwrap_or_else(|| options.title.clone()),
+            open: options.open,
+            animated: options.animation.is_some(),
+        },
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn((
+                ButtonBundle {
+                    style: crate::style()
+                        .row()
+                        .align_items_center()
+                        .padding(Breadth::Px(4.)),
+                    background_color: options.header_color.into(),
+                    ..default()
+                },
+                CollapsibleHeader { collapsible },
+            ))
+            .with_children(|header| {
+                header.spawn((
+                    TextBundle::from_section(indicator(options.open), options.title_style.clone())
+                        .margin(UiRect::right(Val::Px(4.))),
+                    CollapsibleIndicator { collapsible },
+                ));
+                header.spawn(TextBundle::from_section(options.title, options.title_style));
+            });
+        let mut node = NodeBundle::default().column();
+        if !options.open {
+            node = node.disable();
+        }
+        let mut wrapper = parent.spawn((node, CollapsibleContent { collapsible }));
+        if let Some(timing) = options.animation {
+            wrapper.insert(StyleTransition::new().timed(TransitionProperty::MaxSize, timing));
+        }
+        wrapper.with_children(|wrapper| {
+            wrapper
+                .spawn(NodeBundle::default().column().shrink(0.))
+                .with_children(content);
+        });
+    });
+}
+
+pub trait CollapsibleExt<'w, 's> {
+    /// Spawn a section titled by `options` whose content, added with `content`,
+    /// is shown and hidden by clicking its header.
+    fn collapsible<'a>(
+        &'a mut self,
+        options: impl Into<CollapsibleOptions>,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> CollapsibleExt<'w, 's> for Commands<'w, 's> {
+    fn collapsible<'a>(
+        &'a mut self,
+        options: impl Into<CollapsibleOptions>,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a> {
+        let mut root = self.spawn_empty();
+        build_collapsible(&mut root, options.into(), content);
+        root
+    }
+}
+
+impl<'w, 's, 'b> CollapsibleExt<'w, 's> for ChildBuilder<'w, 's, 'b> {
+    fn collapsible<'a>(
+        &'a mut self,
+        options: impl Into<CollapsibleOptions>,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a> {
+        let mut root = self.spawn_empty();
+        build_collapsible(&mut root, options.into(), content);
+        root
+    }
+}
+
+/// Restores the state of new [`Collapsible`]s, toggles them when their header is clicked,
+/// and records their state in [`CollapsibleStates`].
+pub fn collapsible_system(
+    mut states: ResMut<CollapsibleStates>,
+    mut collapsibles: Query<&mut Collapsible>,
+    headers: Query<(&Interaction, &CollapsibleHeader), Changed<Interaction>>,
+) {
+    for mut collapsible in collapsibles.iter_mut() {
+        if collapsible.is_added() {
+            match states.0.get(&collapsible.key) {
+                Some(&open) if open != collapsible.open => collapsible.open = open,
+                Some(_) => {}
+                None => {
+                    states.0.insert(collapsible.key.clone(), collapsible.open);
+                }
+            }
+        }
+    }
+    for (interaction, header) in headers.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        if let Ok(mut collapsible) = collapsibles.get_mut(header.collapsible) {
+            collapsible.toggle();
+            states.0.insert(collapsible.key.clone(), collapsible.open);
+        }
+    }
+}
+
+/// Shows or hides the content of each [`Collapsible`] to match its state.
+///
+/// The content of an animated section stays displayed and its maximum height is set to either
+/// zero or the height of the content, which the [`StyleTransition`] then animates.
+pub fn update_collapsible_system(
+    collapsibles: Query<&Collapsible>,
+    mut contents: Query<(&CollapsibleContent, &mut Style, &Children)>,
+    mut indicators: Query<(&CollapsibleIndicator, &mut Text)>,
+    nodes: Query<&Node>,
+) {
+    for (content, mut style, children) in contents.iter_mut() {
+        let Ok(collapsible) = collapsibles.get(content.collapsible) else {
+            continue;
+        };
+        let (display, overflow, max_height) = if !collapsible.animated {
+            let display = if collapsible.open {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            (display, style.overflow, style.max_height_val())
+        } else if collapsible.open {
+            let height = children
+                .first()
+                .and_then(|child| nodes.get(*child).ok())
+                .map_or(0., |node| node.size().y);
+            (Display::Flex, Overflow::Hidden, Val::Px(height))
+        } else {
+            (Display::Flex, Overflow::Hidden, Val::Px(0.))
+        };
+        if style.display != display
+            || style.overflow != overflow
+            || style.max_height_val() != max_height
+        {
+            style.display = display;
+            style.overflow = overflow;
+            *style.max_height_mut() = max_height;
+        }
+    }
+    for (indicator_marker, mut text) in indicators.iter_mut() {
+        let Ok(collapsible) = collapsibles.get(indicator_marker.collapsible) else {
+            continue;
+        };
+        let value = indicator(collapsible.open);
+        if text.sections[0].value != value {
+            text.sections[0].value = value.to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::CommandQueue;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<CollapsibleStates>()
+            .add_system(collapsible_system)
+            .add_system(update_collapsible_system.after(collapsible_system));
+        app
+    }
+
+    fn spawn_section(world: &mut World, options: CollapsibleOptions) -> Entity {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let section = commands
+            .collapsible(options, |content| {
+                content.spawn(NodeBundle::default());
+            })
+            .id();
+        queue.apply(world);
+        section
+    }
+
+    fn content_display(world: &mut World, section: Entity) -> Display {
+        let mut contents = world.query::<(&CollapsibleContent, &Style)>();
+        contents
+            .iter(world)
+            .find(|(content, _)| content.collapsible == section)
+            .unwrap()
+            .1
+            .display
+    }
+
+    fn click_header(app: &mut App, section: Entity) {
+        let mut headers = app.world.query::<(&mut Interaction, &CollapsibleHeader)>();
+        for (mut interaction, header) in headers.iter_mut(&mut app.world) {
+            if header.collapsible == section {
+                *interaction = Interaction::Clicked;
+            }
+        }
+        app.update();
+        for (mut interaction, _) in headers.iter_mut(&mut app.world) {
+            *interaction = Interaction::None;
+        }
+    }
+
+    #[test]
+    fn header_toggles_content() {
+        let mut app = test_app();
+        let section = spawn_section(&mut app.world, "Advanced".into());
+        app.update();
+        assert_eq!(content_display(&mut app.world, section), Display::None);
+        click_header(&mut app, section);
+        assert_eq!(content_display(&mut app.world, section), Display::Flex);
+        assert!(app.world.get::<Collapsible>(section).unwrap().open);
+        click_header(&mut app, section);
+        assert_eq!(content_display(&mut app.world, section), Display::None);
+    }
+
+    #[test]
+    fn state_is_restored_by_key() {
+        let mut app = test_app();
+        let section = spawn_section(&mut app.world, "Advanced".into());
+        app.update();
+        click_header(&mut app, section);
+        app.world.entity_mut(section).despawn_recursive();
+        assert_eq!(
+            app.world.resource::<CollapsibleStates>().0.get("Advanced"),
+            Some(&true)
+        );
+        let respawned = spawn_section(&mut app.world, "Advanced".into());
+        let other = spawn_section(&mut app.world, "Other".into());
+        app.update();
+        assert_eq!(content_display(&mut app.world, respawned), Display::Flex);
+        assert_eq!(content_display(&mut app.world, other), Display::None);
+    }
+
+    #[test]
+    fn animated_sections_collapse_to_zero_height() {
+        let mut app = test_app();
+        let section = spawn_section(
+            &mut app.world,
+            CollapsibleOptions::new("Animated")
+                .open(true)
+                .animated(TransitionTiming::Spring(Default::default())),
+        );
+        app.update();
+        click_header(&mut app, section);
+        let mut contents = app.world.query::<(&CollapsibleContent, &Style)>();
+        let (_, style) = contents.single(&app.world);
+        assert_eq!(style.display, Display::Flex);
+        assert_eq!(style.max_size.height, Val::Px(0.));
+    }
+}
@@ -0,0 +1,642 @@
+use crate::widgets::cursor_position;
+use crate::widgets::node_rect;
+use crate::Breadth;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::math::Rect;
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use bevy::reflect::ReflectDeserialize;
+#[cfg(feature = "serialize")]
+use bevy::reflect::ReflectSerialize;
+use bevy::utils::HashMap;
+#[cfg(feature = "serialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+/// The direction in which a [`DockLayout::Split`] divides its area.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum DockSplit {
+    /// Side by side.
+    Horizontal,
+    /// One above the other.
+    Vertical,
+}
+
+/// Where a panel is dropped relative to a tab group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DockZone {
+    /// Add the panel to the group as a new tab.
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DockZone {
+    /// The zone under `point` in `rect`. The edge zones each cover the outer quarter of the rect.
+    pub fn from_point(rect: Rect, point: Vec2) -> Self {
+        let t = (point - rect.min) / rect.size();
+        [
+            (t.x, DockZone::Left),
+            (1. - t.x, DockZone::Right),
+            (t.y, DockZone::Top),
+            (1. - t.y, DockZone::Bottom),
+        ]
+        .into_iter()
+        .filter(|(distance, _)| *distance < 0.25)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or(DockZone::Center, |(_, zone)| zone)
+    }
+}
+
+/// The arrangement of the panels in a [`DockArea`].
+///
+/// Layouts are reflected as values, as their nested splits can't be reflected field by field.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "serialize"), reflect_value(Debug, PartialEq))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect_value(Debug, PartialEq, Serialize, Deserialize)
+)]
+pub enum DockLayout {
+    /// Two layouts divided by `split`, with `ratio` of the space given to `first`.
+    Split {
+        split: DockSplit,
+        ratio: f32,
+        first: Box<DockLayout>,
+        second: Box<DockLayout>,
+    },
+    /// A group of panels shown one at a time, selected by tabs.
+    Tabs { panels: Vec<String>, active: usize },
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self::Tabs {
+            panels: vec![],
+            active: 0,
+        }
+    }
+}
+
+impl DockLayout {
+    pub fn tabs(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    pub fn split(split: DockSplit, ratio: f32, first: DockLayout, second: DockLayout) -> Self {
+        Self::Split {
+            split,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// The ids of every panel in the layout.
+    pub fn panels(&self) -> Vec<&str> {
+        match self {
+            DockLayout::Split { first, second, .. } => {
+                let mut panels = first.panels();
+                panels.extend(second.panels());
+                panels
+            }
+            DockLayout::Tabs { panels, .. } => panels.iter().map(String::as_str).collect(),
+        }
+    }
+
+    pub fn contains(&self, panel: &str) -> bool {
+        self.panels().contains(&panel)
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, DockLayout::Tabs { panels, .. } if panels.is_empty())
+    }
+
+    /// Remove a panel, collapsing any split left with an empty side.
+    pub fn remove_panel(&mut self, panel: &str) -> bool {
+        let (removed, replacement) = match self {
+            DockLayout::Tabs { panels, active } => {
+                let Some(index) = panels.iter().position(|existing| existing == panel) else {
+                    return false;
+                };
+                panels.remove(index);
+                if index < *active || panels.len() <= *active {
+                    *active = active.saturating_sub(1);
+                }
+                return true;
+            }
+            DockLayout::Split { first, second, .. } => {
+                let removed = first.remove_panel(panel) || second.remove_panel(panel);
+                let replacement = if first.is_empty() {
+                    Some(std::mem::take(&mut **second))
+                } else if second.is_empty() {
+                    Some(std::mem::take(&mut **first))
+                } else {
+                    None
+                };
+                (removed, replacement)
+            }
+        };
+        if let Some(replacement) = replacement {
+            *self = replacement;
+        }
+        removed
+    }
+
+    fn find_tabs_mut(&mut self, panel: &str) -> Option<&mut DockLayout> {
+        if matches!(self, DockLayout::Tabs { panels, .. } if panels.iter().any(|existing| existing == panel))
+        {
+            return Some(self);
+        }
+        match self {
+            DockLayout::Split { first, second, .. } => match first.find_tabs_mut(panel) {
+                Some(tabs) => Some(tabs),
+                None => second.find_tabs_mut(panel),
+            },
+            DockLayout::Tabs { .. } => None,
+        }
+    }
+
+    /// Show `panel` in its tab group.
+    pub fn activate(&mut self, panel: &str) -> bool {
+        match self.find_tabs_mut(panel) {
+            Some(DockLayout::Tabs { panels, active }) => {
+                *active = panels
+                    .iter()
+                    .position(|existing| existing == panel)
+                    .unwrap();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move `panel` into the tab group containing `target`, or split that group at `zone`.
+    ///
+    /// `panel` doesn't have to be in the layout already.
+    pub fn dock(&mut self, panel: &str, target: &str, zone: DockZone) -> bool {
+        if panel == target || !self.contains(target) {
+            return false;
+        }
+        self.remove_panel(panel);
+        let Some(tabs) = self.find_tabs_mut(target) else {
+            return false;
+        };
+        let (split, first) = match zone {
+            DockZone::Center => {
+                if let DockLayout::Tabs { panels, active } = tabs {
+                    panels.push(panel.to_string());
+                    *active = panels.len() - 1;
+                }
+                return true;
+            }
+            DockZone::Left => (DockSplit::Horizontal, true),
+            DockZone::Right => (DockSplit::Horizontal, false),
+            DockZone::Top => (DockSplit::Vertical, true),
+            DockZone::Bottom => (DockSplit::Vertical, false),
+        };
+        let old = std::mem::take(tabs);
+        let new = DockLayout::tabs([panel]);
+        *tabs = if first {
+            DockLayout::split(split, 0.5, new, old)
+        } else {
+            DockLayout::split(split, 0.5, old, new)
+        };
+        true
+    }
+}
+
+/// The root of a docking layout.
+///
+/// The area's children are rebuilt from [`DockArea::layout`] whenever it changes. Entities
+/// with a [`DockPanel`] whose id appears in the layout are moved into their tab group. Tabs
+/// can be clicked to show their panel, or dragged onto another group to dock their panel
+/// beside or inside it.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DockArea {
+    pub layout: DockLayout,
+    pub tab_text_style: TextStyle,
+    pub tab_color: Color,
+    pub active_tab_color: Color,
+    pub tab_bar_color: Color,
+    #[reflect(ignore)]
+    built: Option<DockLayout>,
+    #[reflect(ignore)]
+    drag: Option<DockDrag>,
+}
+
+impl FromWorld for DockArea {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(DockLayout::default())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DockDrag {
+    tab: Entity,
+    panel: String,
+    start: Vec2,
+}
+
+impl DockArea {
+    pub fn new(layout: DockLayout) -> Self {
+        Self {
+            layout,
+            tab_text_style: TextStyle::default(),
+            tab_color: Color::rgb(0.15, 0.15, 0.15),
+            active_tab_color: Color::rgb(0.3, 0.3, 0.3),
+            tab_bar_color: Color::rgb(0.1, 0.1, 0.1),
+            built: None,
+            drag: None,
+        }
+    }
+
+    pub fn with_tab_text_style(mut self, style: TextStyle) -> Self {
+        self.tab_text_style = style;
+        self
+    }
+}
+
+/// Marks an entity as the content of the panel with this id in a [`DockLayout`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct DockPanel(pub String);
+
+/// Marks the nodes generated by a [`DockArea`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct DockNode {
+    pub area: Entity,
+}
+
+/// A tab button in a [`DockArea`].
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct DockTab {
+    pub area: Entity,
+    pub panel: String,
+}
+
+/// A tab group in a [`DockArea`], holding the id of its active panel.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct DockGroup {
+    pub area: Entity,
+    pub panel: Option<String>,
+}
+
+impl_entity_links! {
+    DockNode { area }
+    DockTab { area; panel }
+    DockGroup { area; panel }
+}
+
+/// A node filling its parent with a [`DockArea`] built from `layout`.
+pub fn dock_area(layout: DockLayout) -> (NodeBundle, DockArea) {
+    (
+        NodeBundle::default().size((Val::Percent(100.), Val::Percent(100.))),
+        DockArea::new(layout),
+    )
+}
+
+struct DockBuilder<'a> {
+    area_entity: Entity,
+    area: &'a DockArea,
+    panels: &'a HashMap<String, Entity>,
+    visible: Vec<(Entity, bool)>,
+}
+
+impl<'a> DockBuilder<'a> {
+    fn spawn(&mut self, commands: &mut Commands, layout: &DockLayout, size: Size) -> Entity {
+        let marker = DockNode {
+            area: self.area_entity,
+        };
+        match layout {
+            DockLayout::Split {
+                split,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_size, second_size, node) = match split {
+                    DockSplit::Horizontal => (
+                        Size::new(Val::Percent(100. * ratio), Val::Percent(100.)),
+                        Size::new(Val::Percent(100. * (1. - ratio)), Val::Percent(100.)),
+                        NodeBundle::default().row(),
+                    ),
+                    DockSplit::Vertical => (
+                        Size::new(Val::Percent(100.), Val::Percent(100. * ratio)),
+                        Size::new(Val::Percent(100.), Val::Percent(100. * (1. - ratio))),
+                        NodeBundle::default().column(),
+                    ),
+                };
+                let first = self.spawn(commands, first, first_size);
+                let second = self.spawn(commands, second, second_size);
+                commands
+                    .spawn((node.size(size), marker))
+                    .push_children(&[first, second])
+                    .id()
+            }
+            DockLayout::Tabs { panels, active } => {
+                let tabs = panels
+                    .iter()
+                    .enumerate()
+                    .map(|(index, panel)| {
+                        let color = if index == *active {
+                            self.area.active_tab_color
+                        } else {
+                            self.area.tab_color
+                        };
+                        commands
+                            .spawn((
+                                ButtonBundle {
+                                    style: crate::style().padding(Breadth::Px(4.)),
+                                    background_color: color.into(),
+                                    ..default()
+                                },
+                                DockTab {
+                                    area: self.area_entity,
+                                    panel: panel.clone(),
+                                },
+                                marker,
+                            ))
+                            .with_children(|button| {
+                                button.spawn((
+                                    TextBundle::from_section(
+                                        panel.clone(),
+                                        self.area.tab_text_style.clone(),
+                                    ),
+                                    marker,
+                                ));
+                            })
+                            .id()
+                    })
+                    .collect::<Vec<_>>();
+                let tab_bar = commands
+                    .spawn((
+                        NodeBundle::default()
+                            .row()
+                            .shrink(0.)
+                            .background_color(self.area.tab_bar_color),
+                        marker,
+                    ))
+                    .push_children(&tabs)
+                    .id();
+                let mut contents = vec![];
+                for (index, panel) in panels.iter().enumerate() {
+                    if let Some(&entity) = self.panels.get(panel) {
+                        contents.push(entity);
+                        self.visible.push((entity, index == *active));
+                    }
+                }
+                let content = commands
+                    .spawn((NodeBundle::default().grow(1.), marker))
+                    .push_children(&contents)
+                    .id();
+                commands
+                    .spawn((
+                        NodeBundle::default().column().size(size),
+                        DockGroup {
+                            area: self.area_entity,
+                            panel: panels.get(*active).cloned(),
+                        },
+                        marker,
+                    ))
+                    .push_children(&[tab_bar, content])
+                    .id()
+            }
+        }
+    }
+}
+
+/// Rebuilds the nodes of each [`DockArea`] whose layout has changed.
+#[allow(clippy::type_complexity)]
+pub fn dock_layout_system(
+    mut commands: Commands,
+    mut areas: Query<(Entity, &mut DockArea)>,
+    mut panels: Query<(Entity, &DockPanel, Option<&Parent>, &mut Style)>,
+    generated: Query<(Entity, &DockNode, &Parent)>,
+) {
+    for (area_entity, mut area) in areas.iter_mut() {
+        if area.built.as_ref() == Some(&area.layout) {
+            continue;
+        }
+        let area = area.bypass_change_detection();
+        area.built = Some(area.layout.clone());
+        let ids = area.layout.panels();
+        let mut entities = HashMap::default();
+        for (entity, panel, parent, mut style) in panels.iter_mut() {
+            let in_layout = ids.contains(&panel.0.as_str());
+            let docked = parent.is_some_and(|parent| {
+                parent.get() == area_entity
+                    || generated
+                        .get(parent.get())
+                        .is_ok_and(|(_, node, _)| node.area == area_entity)
+            });
+            if in_layout || docked {
+                // keep the panel alive while the generated nodes are replaced
+                commands.entity(area_entity).add_child(entity);
+            }
+            if in_layout {
+                entities.insert(panel.0.clone(), entity);
+            } else if docked {
+                style.display = Display::None;
+            }
+        }
+        for (entity, node, parent) in generated.iter() {
+            if node.area == area_entity && parent.get() == area_entity {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+        let mut builder = DockBuilder {
+            area_entity,
+            area,
+            panels: &entities,
+            visible: vec![],
+        };
+        let root = builder.spawn(
+            &mut commands,
+            &area.layout,
+            Size::new(Val::Percent(100.), Val::Percent(100.)),
+        );
+        commands.entity(area_entity).add_child(root);
+        for (entity, visible) in builder.visible {
+            if let Ok((.., mut style)) = panels.get_mut(entity) {
+                style.display = if visible {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
+}
+
+/// Activates clicked tabs and docks tabs dropped onto tab groups.
+pub fn dock_drag_system(
+    windows: Option<Res<Windows>>,
+    tabs: Query<(Entity, &Interaction, &DockTab)>,
+    groups: Query<(&DockGroup, &Node, &GlobalTransform)>,
+    mut areas: Query<&mut DockArea>,
+) {
+    let cursor = windows.and_then(|windows| cursor_position(&windows));
+    for (tab_entity, interaction, tab) in tabs.iter() {
+        let Ok(mut area) = areas.get_mut(tab.area) else {
+            continue;
+        };
+        if *interaction == Interaction::Clicked && area.drag.is_none() {
+            if let Some(cursor) = cursor {
+                area.bypass_change_detection().drag = Some(DockDrag {
+                    tab: tab_entity,
+                    panel: tab.panel.clone(),
+                    start: cursor,
+                });
+            }
+        }
+    }
+    for mut area in areas.iter_mut() {
+        let Some(drag) = area.drag.clone() else {
+            continue;
+        };
+        if matches!(tabs.get(drag.tab), Ok((_, Interaction::Clicked, _))) {
+            continue;
+        }
+        area.bypass_change_detection().drag = None;
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        if cursor.distance(drag.start) < 5. {
+            area.layout.activate(&drag.panel);
+            continue;
+        }
+        let drop = groups.iter().find_map(|(group, node, transform)| {
+            let rect = node_rect(node, transform);
+            let target = group.panel.as_ref()?;
+            (rect.contains(cursor)).then(|| (target.clone(), DockZone::from_point(rect, cursor)))
+        });
+        if let Some((target, zone)) = drop {
+            let mut layout = area.layout.clone();
+            if layout.dock(&drag.panel, &target, zone) {
+                area.layout = layout;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_layout() -> DockLayout {
+        DockLayout::split(
+            DockSplit::Horizontal,
+            0.25,
+            DockLayout::tabs(["scene", "assets"]),
+            DockLayout::tabs(["viewport"]),
+        )
+    }
+
+    #[test]
+    fn dock_and_remove_panels() {
+        let mut layout = editor_layout();
+        assert!(layout.dock("assets", "viewport", DockZone::Bottom));
+        assert_eq!(
+            layout,
+            DockLayout::split(
+                DockSplit::Horizontal,
+                0.25,
+                DockLayout::tabs(["scene"]),
+                DockLayout::split(
+                    DockSplit::Vertical,
+                    0.5,
+                    DockLayout::tabs(["viewport"]),
+                    DockLayout::tabs(["assets"]),
+                ),
+            )
+        );
+        assert!(layout.dock("scene", "viewport", DockZone::Center));
+        assert_eq!(
+            layout,
+            DockLayout::split(
+                DockSplit::Vertical,
+                0.5,
+                DockLayout::Tabs {
+                    panels: vec!["viewport".into(), "scene".into()],
+                    active: 1,
+                },
+                DockLayout::tabs(["assets"]),
+            )
+        );
+        assert!(layout.activate("viewport"));
+        assert!(!layout.dock("missing", "nowhere", DockZone::Left));
+    }
+
+    #[test]
+    fn dock_zones() {
+        let rect = Rect::new(0., 0., 100., 100.);
+        assert_eq!(
+            DockZone::from_point(rect, Vec2::new(50., 50.)),
+            DockZone::Center
+        );
+        assert_eq!(
+            DockZone::from_point(rect, Vec2::new(10., 50.)),
+            DockZone::Left
+        );
+        assert_eq!(
+            DockZone::from_point(rect, Vec2::new(60., 95.)),
+            DockZone::Bottom
+        );
+    }
+
+    #[test]
+    fn builds_panels_into_tabs() {
+        let mut app = App::new();
+        app.add_system(dock_layout_system);
+        let scene = app
+            .world
+            .spawn((NodeBundle::default(), DockPanel("scene".into())))
+            .id();
+        let assets = app
+            .world
+            .spawn((NodeBundle::default(), DockPanel("assets".into())))
+            .id();
+        let area = app.world.spawn(dock_area(editor_layout())).id();
+        app.update();
+        let scene_parent = app.world.get::<Parent>(scene).unwrap().get();
+        assert_eq!(app.world.get::<DockNode>(scene_parent).unwrap().area, area);
+        assert_eq!(
+            app.world.get::<Style>(scene).unwrap().display,
+            Display::Flex
+        );
+        assert_eq!(
+            app.world.get::<Style>(assets).unwrap().display,
+            Display::None
+        );
+        app.world
+            .get_mut::<DockArea>(area)
+            .unwrap()
+            .layout
+            .remove_panel("scene");
+        app.update();
+        assert_eq!(app.world.get::<Parent>(scene).unwrap().get(), area);
+        assert_eq!(
+            app.world.get::<Style>(scene).unwrap().display,
+            Display::None
+        );
+        assert_eq!(
+            app.world.get::<Style>(assets).unwrap().display,
+            Display::Flex
+        );
+    }
+}
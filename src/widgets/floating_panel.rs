@@ -0,0 +1,300 @@
+use crate::fields::StyleFields;
+use crate::widgets::cursor_position;
+use crate::widgets::node_rect;
+use crate::Breadth;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+/// The options for a panel spawned with [`FloatingPanelCommandsExt::floating_panel`].
+#[derive(Clone, Debug)]
+pub struct FloatingPanelOptions {
+    pub title: String,
+    pub title_style: TextStyle,
+    /// Whether the title bar has a button that despawns the panel.
+    pub closable: bool,
+    /// The initial position of the panel's top left corner.
+    pub position: Vec2,
+    pub color: Color,
+    pub title_bar_color: Color,
+}
+
+impl FloatingPanelOptions {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            title_style: TextStyle::default(),
+            closable: true,
+            position: Vec2::ZERO,
+            color: Color::rgb(0.15, 0.15, 0.15),
+            title_bar_color: Color::rgb(0.25, 0.25, 0.25),
+        }
+    }
+
+    pub fn with_title_style(mut self, style: TextStyle) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    pub fn at(mut self, position: Vec2) -> Self {
+        self.position = position;
+        self
+    }
+}
+
+impl From<&str> for FloatingPanelOptions {
+    fn from(title: &str) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<String> for FloatingPanelOptions {
+    fn from(title: String) -> Self {
+        Self::new(title)
+    }
+}
+
+/// An absolutely positioned panel that can be dragged by its title bar.
+///
+/// Clicking a panel raises it above the other floating panels.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct FloatingPanel {
+    #[reflect(ignore)]
+    grab_offset: Option<Vec2>,
+}
+
+/// The title bar of a [`FloatingPanel`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PanelTitleBar {
+    pub panel: Entity,
+}
+
+/// A button that despawns its [`FloatingPanel`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PanelCloseButton {
+    pub panel: Entity,
+}
+
+impl_entity_links! {
+    PanelTitleBar { panel }
+    PanelCloseButton { panel }
+}
+
+pub trait FloatingPanelCommandsExt<'w, 's> {
+    /// Spawn a floating panel with a title bar, adding its contents with `content`.
+    fn floating_panel<'a>(
+        &'a mut self,
+        options: impl Into<FloatingPanelOptions>,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> FloatingPanelCommandsExt<'w, 's> for Commands<'w, 's> {
+    fn floating_panel<'a>(
+        &'a mut self,
+        options: impl Into<FloatingPanelOptions>,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a> {
+        let options = options.into();
+        let panel = self
+            .spawn((
+                NodeBundle {
+                    z_index: ZIndex::Global(0),
+                    ..default()
+                }
+                .absolute()
+                .column()
+                .left(Val::Px(options.position.x))
+                .top(Val::Px(options.position.y))
+                .background_color(options.color),
+                Interaction::None,
+                FloatingPanel::default(),
+            ))
+            .id();
+        let title_bar = self
+            .spawn((
+                ButtonBundle {
+                    style: crate::style()
+                        .row()
+                        .justify_content_space_between()
+                        .align_items_center()
+                        .padding(Breadth::Px(4.)),
+                    background_color: options.title_bar_color.into(),
+                    ..default()
+                },
+                PanelTitleBar { panel },
+            ))
+            .with_children(|title_bar| {
+                title_bar.spawn(TextBundle::from_section(
+                    options.title,
+                    options.title_style.clone(),
+                ));
+                if options.closable {
+                    title_bar
+                        .spawn((
+                            ButtonBundle {
+                                style: crate::style().margin(UiRect::left(Val::Px(8.))),
+                                background_color: Color::NONE.into(),
+                                ..default()
+                            },
+                            PanelCloseButton { panel },
+                        ))
+                        .with_children(|close| {
+                            close.spawn(TextBundle::from_section("x", options.title_style));
+                        });
+                }
+            })
+            .id();
+        let content = self
+            .spawn(NodeBundle::default().column().padding(Breadth::Px(4.)))
+            .with_children(content)
+            .id();
+        let mut panel = self.entity(panel);
+        panel.push_children(&[title_bar, content]);
+        panel
+    }
+}
+
+fn global_z(z_index: &ZIndex) -> i32 {
+    match z_index {
+        ZIndex::Global(z) => *z,
+        ZIndex::Local(_) => 0,
+    }
+}
+
+/// Drags, raises and closes [`FloatingPanel`]s.
+#[allow(clippy::type_complexity)]
+pub fn floating_panel_system(
+    mut commands: Commands,
+    windows: Option<Res<Windows>>,
+    mut panels: Query<(
+        Entity,
+        &mut FloatingPanel,
+        &mut Style,
+        &mut ZIndex,
+        &Node,
+        &GlobalTransform,
+    )>,
+    clicked_panels: Query<(Entity, &Interaction), (Changed<Interaction>, With<FloatingPanel>)>,
+    title_bars: Query<(&Interaction, &PanelTitleBar)>,
+    close_buttons: Query<(&Interaction, &PanelCloseButton), Changed<Interaction>>,
+) {
+    let cursor = windows.and_then(|windows| cursor_position(&windows));
+    let mut raise = panels
+        .iter_mut()
+        .filter(|(_, panel, ..)| panel.is_added())
+        .map(|(entity, ..)| entity)
+        .collect::<Vec<_>>();
+    raise.extend(
+        clicked_panels
+            .iter()
+            .filter(|(_, interaction)| **interaction == Interaction::Clicked)
+            .map(|(entity, _)| entity),
+    );
+    for (interaction, title_bar) in title_bars.iter() {
+        let Ok((_, mut panel, mut style, _, node, transform)) = panels.get_mut(title_bar.panel)
+        else {
+            continue;
+        };
+        if *interaction != Interaction::Clicked {
+            panel.grab_offset = None;
+            continue;
+        }
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        let grab_offset = match panel.grab_offset {
+            Some(grab_offset) => grab_offset,
+            None => {
+                raise.push(title_bar.panel);
+                let grab_offset = cursor - node_rect(node, transform).min;
+                panel.grab_offset = Some(grab_offset);
+                grab_offset
+            }
+        };
+        let position = cursor - grab_offset;
+        *style.left_mut() = Val::Px(position.x);
+        *style.top_mut() = Val::Px(position.y);
+    }
+    for entity in raise {
+        let top = panels
+            .iter()
+            .filter(|(other, ..)| *other != entity)
+            .map(|(.., z_index, _, _)| global_z(z_index))
+            .max()
+            .unwrap_or(0);
+        if let Ok((.., mut z_index, _, _)) = panels.get_mut(entity) {
+            if global_z(&z_index) <= top {
+                *z_index = ZIndex::Global(top + 1);
+            }
+        }
+    }
+    for (interaction, close) in close_buttons.iter() {
+        if *interaction == Interaction::Clicked {
+            commands.entity(close.panel).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::CommandQueue;
+
+    fn spawn_panel(world: &mut World, options: FloatingPanelOptions) -> Entity {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let panel = commands
+            .floating_panel(options, |content| {
+                content.spawn(NodeBundle::default());
+            })
+            .id();
+        queue.apply(world);
+        panel
+    }
+
+    fn z(world: &World, entity: Entity) -> i32 {
+        global_z(world.get::<ZIndex>(entity).unwrap())
+    }
+
+    #[test]
+    fn clicked_panels_are_raised() {
+        let mut app = App::new();
+        app.add_system(floating_panel_system);
+        let first = spawn_panel(&mut app.world, "first".into());
+        let second = spawn_panel(&mut app.world, "second".into());
+        app.update();
+        assert!(z(&app.world, first) < z(&app.world, second));
+        *app.world.get_mut::<Interaction>(first).unwrap() = Interaction::Clicked;
+        app.update();
+        assert!(z(&app.world, second) < z(&app.world, first));
+    }
+
+    #[test]
+    fn close_button_despawns_panel() {
+        let mut app = App::new();
+        app.add_system(floating_panel_system);
+        let panel = spawn_panel(&mut app.world, FloatingPanelOptions::new("panel"));
+        let closed = spawn_panel(&mut app.world, "fixed".into());
+        app.update();
+        let mut buttons = app.world.query::<(&mut Interaction, &PanelCloseButton)>();
+        for (mut interaction, button) in buttons.iter_mut(&mut app.world) {
+            if button.panel == closed {
+                *interaction = Interaction::Clicked;
+            }
+        }
+        app.update();
+        assert!(app.world.get_entity(closed).is_none());
+        assert!(app.world.get_entity(panel).is_some());
+    }
+}
@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+
+/// What an [`ImageWithFallback`] node shows while its image is loading.
+#[derive(Clone, Debug, PartialEq, Reflect, FromReflect)]
+pub enum Fallback {
+    /// A block of color.
+    Color(Color),
+    /// Another image, which should already be loaded.
+    Image(Handle<Image>),
+}
+
+impl From<Color> for Fallback {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<Handle<Image>> for Fallback {
+    fn from(image: Handle<Image>) -> Self {
+        Self::Image(image)
+    }
+}
+
+/// An image node that shows a [`Fallback`] until `image` is loaded.
+///
+/// The node isn't sized by the fallback, so give it a size to avoid a jump when the image arrives.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ImageWithFallback {
+    pub image: Handle<Image>,
+    pub fallback: Fallback,
+    /// The tint applied to the image once it has loaded.
+    pub tint: Color,
+    #[reflect(ignore)]
+    loaded: bool,
+}
+
+impl FromWorld for ImageWithFallback {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(Handle::default(), Color::NONE)
+    }
+}
+
+impl ImageWithFallback {
+    pub fn new(image: Handle<Image>, fallback: impl Into<Fallback>) -> Self {
+        Self {
+            image,
+            fallback: fallback.into(),
+            tint: Color::WHITE,
+            loaded: false,
+        }
+    }
+
+    /// Whether the image has loaded and is being shown.
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    fn fallback_image(&self) -> (UiImage, Color) {
+        match &self.fallback {
+            Fallback::Color(color) => (UiImage::default(), *color),
+            Fallback::Image(image) => (image.clone().into(), self.tint),
+        }
+    }
+}
+
+/// An image node showing `fallback` until `image` is loaded.
+pub fn image_with_fallback(
+    image: Handle<Image>,
+    fallback: impl Into<Fallback>,
+) -> (ImageBundle, ImageWithFallback) {
+    let image = ImageWithFallback::new(image, fallback);
+    let (ui_image, color) = image.fallback_image();
+    (
+        ImageBundle {
+            image: ui_image,
+            background_color: color.into(),
+            ..default()
+        },
+        image,
+    )
+}
+
+/// Swaps in the image of each [`ImageWithFallback`] once it has loaded,
+/// and restores the fallback when the image is replaced by one that hasn't.
+pub fn image_fallback_system(
+    images: Option<Res<Assets<Image>>>,
+    mut query: Query<(&mut ImageWithFallback, &mut UiImage, &mut BackgroundColor)>,
+) {
+    let Some(images) = images else {
+        return;
+    };
+    for (mut fallback, mut ui_image, mut color) in query.iter_mut() {
+        let loaded = images.contains(&fallback.image);
+        if fallback.is_changed() && !fallback.is_added() && !loaded {
+            let (fallback_image, fallback_color) = fallback.fallback_image();
+            ui_image.0 = fallback_image.0;
+            color.0 = fallback_color;
+            fallback.bypass_change_detection().loaded = false;
+        }
+        if loaded && (!fallback.loaded || fallback.is_changed()) {
+            ui_image.0 = fallback.image.clone();
+            color.0 = fallback.tint;
+            fallback.bypass_change_detection().loaded = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::HandleId;
+
+    #[test]
+    fn image_is_swapped_in_when_loaded() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_system(image_fallback_system);
+        let id = HandleId::random::<Image>();
+        let entity = app
+            .world
+            .spawn(image_with_fallback(Handle::weak(id), Color::GRAY))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<BackgroundColor>(entity).unwrap().0,
+            Color::GRAY
+        );
+        assert!(!app
+            .world
+            .get::<ImageWithFallback>(entity)
+            .unwrap()
+            .is_loaded());
+        app.world
+            .resource_mut::<Assets<Image>>()
+            .set_untracked(id, Image::default());
+        app.update();
+        assert_eq!(
+            app.world.get::<BackgroundColor>(entity).unwrap().0,
+            Color::WHITE
+        );
+        assert_eq!(app.world.get::<UiImage>(entity).unwrap().0.id(), id);
+        assert!(app
+            .world
+            .get::<ImageWithFallback>(entity)
+            .unwrap()
+            .is_loaded());
+        let other = HandleId::random::<Image>();
+        app.world
+            .get_mut::<ImageWithFallback>(entity)
+            .unwrap()
+            .image = Handle::weak(other);
+        app.update();
+        assert_eq!(
+            app.world.get::<BackgroundColor>(entity).unwrap().0,
+            Color::GRAY
+        );
+        assert!(!app
+            .world
+            .get::<ImageWithFallback>(entity)
+            .unwrap()
+            .is_loaded());
+    }
+}
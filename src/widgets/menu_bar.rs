@@ -0,0 +1,542 @@
+use crate::Breadth;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use std::borrow::Cow;
+
+/// An entry in a [`Menu`].
+#[derive(Clone, Debug, Reflect, FromReflect)]
+pub enum MenuItem {
+    /// Sends a [`MenuAction`] with `action` when chosen.
+    Action {
+        label: String,
+        action: Cow<'static, str>,
+    },
+    Submenu(Menu),
+    Separator,
+}
+
+/// A named list of [`MenuItem`]s.
+#[derive(Clone, Debug, Default, Reflect, FromReflect)]
+pub struct Menu {
+    pub label: String,
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            items: vec![],
+        }
+    }
+
+    pub fn item(mut self, label: impl Into<String>, action: impl Into<Cow<'static, str>>) -> Self {
+        self.items.push(MenuItem::Action {
+            label: label.into(),
+            action: action.into(),
+        });
+        self
+    }
+
+    pub fn submenu(mut self, menu: Menu) -> Self {
+        self.items.push(MenuItem::Submenu(menu));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+}
+
+/// Sent when a menu item's action is chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuAction {
+    pub bar: Entity,
+    pub action: Cow<'static, str>,
+}
+
+/// The items listed under `path`, where `path[0]` indexes `menus` and the rest index submenus.
+fn items_at<'a>(menus: &'a [Menu], path: &[usize]) -> Option<&'a [MenuItem]> {
+    let (first, rest) = path.split_first()?;
+    let mut items = menus.get(*first)?.items.as_slice();
+    for index in rest {
+        match items.get(*index)? {
+            MenuItem::Submenu(menu) => items = &menu.items,
+            _ => return None,
+        }
+    }
+    Some(items)
+}
+
+/// Which menus of a [`MenuBar`] are open, and which item is highlighted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Reflect, FromReflect)]
+pub struct MenuState {
+    /// The path of the deepest open menu. Empty when the bar is closed.
+    pub open: Vec<usize>,
+    /// The highlighted item in the deepest open menu.
+    pub highlight: Option<usize>,
+}
+
+impl MenuState {
+    pub fn is_open(&self) -> bool {
+        !self.open.is_empty()
+    }
+
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the item at `path` is open or highlighted.
+    pub fn is_highlighted(&self, path: &[usize]) -> bool {
+        self.open.starts_with(path)
+            || path.split_last().is_some_and(|(last, parent)| {
+                parent == self.open.as_slice() && self.highlight == Some(*last)
+            })
+    }
+
+    fn step(&mut self, menus: &[Menu], forward: bool) {
+        let Some(items) = items_at(menus, &self.open) else {
+            return;
+        };
+        let count = items.len();
+        if count == 0 {
+            return;
+        }
+        let mut index = self
+            .highlight
+            .unwrap_or(if forward { count - 1 } else { 0 });
+        for _ in 0..count {
+            index = if forward {
+                (index + 1) % count
+            } else {
+                (index + count - 1) % count
+            };
+            if !matches!(items[index], MenuItem::Separator) {
+                self.highlight = Some(index);
+                return;
+            }
+        }
+    }
+
+    /// Highlight the next item.
+    pub fn next(&mut self, menus: &[Menu]) {
+        self.step(menus, true);
+    }
+
+    /// Highlight the previous item.
+    pub fn previous(&mut self, menus: &[Menu]) {
+        self.step(menus, false);
+    }
+
+    fn switch_menu(&mut self, menus: &[Menu], forward: bool) {
+        let Some(&top) = self.open.first() else {
+            return;
+        };
+        let count = menus.len();
+        let top = if forward {
+            (top + 1) % count
+        } else {
+            (top + count - 1) % count
+        };
+        self.open = vec![top];
+        self.highlight = None;
+        self.next(menus);
+    }
+
+    /// Open the highlighted submenu, or move to the next menu in the bar.
+    pub fn forward(&mut self, menus: &[Menu]) {
+        let highlighted = self
+            .highlight
+            .and_then(|index| items_at(menus, &self.open)?.get(index));
+        if let (Some(MenuItem::Submenu(_)), Some(index)) = (highlighted, self.highlight) {
+            self.open.push(index);
+            self.highlight = None;
+            self.next(menus);
+        } else {
+            self.switch_menu(menus, true);
+        }
+    }
+
+    /// Close the deepest submenu, or move to the previous menu in the bar.
+    pub fn back(&mut self, menus: &[Menu]) {
+        if 1 < self.open.len() {
+            self.highlight = self.open.pop();
+        } else {
+            self.switch_menu(menus, false);
+        }
+    }
+
+    /// Choose the highlighted item, returning its action if it has one.
+    pub fn activate(&mut self, menus: &[Menu]) -> Option<Cow<'static, str>> {
+        let index = self.highlight?;
+        match items_at(menus, &self.open)?.get(index)? {
+            MenuItem::Action { action, .. } => {
+                let action = action.clone();
+                self.close();
+                Some(action)
+            }
+            MenuItem::Submenu(_) => {
+                self.forward(menus);
+                None
+            }
+            MenuItem::Separator => None,
+        }
+    }
+
+    /// Point at the item at `path`, opening it if it is a menu or submenu.
+    pub fn hover(&mut self, menus: &[Menu], path: &[usize]) {
+        let Some((last, parent)) = path.split_last() else {
+            return;
+        };
+        if parent.is_empty() {
+            self.open = path.to_vec();
+            self.highlight = None;
+            return;
+        }
+        let item = items_at(menus, parent).and_then(|items| items.get(*last));
+        if let Some(MenuItem::Submenu(_)) = item {
+            self.open = path.to_vec();
+            self.highlight = None;
+        } else {
+            self.open = parent.to_vec();
+            self.highlight = Some(*last);
+        }
+    }
+}
+
+/// A strip of menus, opened by clicking and navigated with the mouse or arrow keys.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct MenuBar {
+    pub menus: Vec<Menu>,
+    pub state: MenuState,
+    pub color: Color,
+    pub highlight_color: Color,
+}
+
+/// A menu title in a [`MenuBar`], or an item in one of its menus.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct MenuButton {
+    pub bar: Entity,
+    pub path: Vec<usize>,
+}
+
+/// The popup listing the items of the menu at `path`.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct MenuPopup {
+    pub bar: Entity,
+    pub path: Vec<usize>,
+}
+
+impl_entity_links! {
+    MenuButton { bar; path }
+    MenuPopup { bar; path }
+}
+
+struct MenuSpawner<'a> {
+    bar: Entity,
+    text_style: &'a TextStyle,
+    color: Color,
+}
+
+impl<'a> MenuSpawner<'a> {
+    fn button(&self, commands: &mut Commands, label: String, path: Vec<usize>) -> Entity {
+        commands
+            .spawn((
+                ButtonBundle {
+                    style: crate::style().padding(Breadth::Px(4.)),
+                    background_color: self.color.into(),
+                    ..default()
+                },
+                MenuButton {
+                    bar: self.bar,
+                    path,
+                },
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(label, self.text_style.clone()));
+            })
+            .id()
+    }
+
+    fn popup(&self, commands: &mut Commands, items: &[MenuItem], path: Vec<usize>) -> Entity {
+        let nested = 1 < path.len();
+        let entries = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let mut item_path = path.clone();
+                item_path.push(index);
+                match item {
+                    MenuItem::Action { label, .. } => {
+                        self.button(commands, label.clone(), item_path)
+                    }
+                    MenuItem::Submenu(menu) => {
+                        let button =
+                            self.button(commands, format!("{} >", menu.label), item_path.clone());
+                        let popup = self.popup(commands, &menu.items, item_path);
+                        commands.entity(button).add_child(popup);
+                        button
+                    }
+                    MenuItem::Separator => commands
+                        .spawn(
+                            NodeBundle::default()
+                                .height(Val::Px(1.))
+                                .background_color(self.text_style.color),
+                        )
+                        .id(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let popup = NodeBundle::default()
+            .absolute()
+            .column()
+            .disable()
+            .background_color(self.color);
+        let popup = if nested {
+            popup.left(Val::Percent(100.)).top(Val::Px(0.))
+        } else {
+            popup.left(Val::Px(0.)).top(Val::Percent(100.))
+        };
+        commands
+            .spawn((
+                popup,
+                MenuPopup {
+                    bar: self.bar,
+                    path,
+                },
+            ))
+            .push_children(&entries)
+            .id()
+    }
+}
+
+pub trait MenuBarCommandsExt<'w, 's> {
+    /// Spawn a menu bar with a title for each of `menus`.
+    fn menu_bar<'a>(
+        &'a mut self,
+        menus: Vec<Menu>,
+        text_style: TextStyle,
+    ) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> MenuBarCommandsExt<'w, 's> for Commands<'w, 's> {
+    fn menu_bar<'a>(
+        &'a mut self,
+        menus: Vec<Menu>,
+        text_style: TextStyle,
+    ) -> EntityCommands<'w, 's, 'a> {
+        let color = Color::rgb(0.2, 0.2, 0.2);
+        let bar = self
+            .spawn(NodeBundle::default().row().background_color(color))
+            .id();
+        let spawner = MenuSpawner {
+            bar,
+            text_style: &text_style,
+            color,
+        };
+        let titles = menus
+            .iter()
+            .enumerate()
+            .map(|(index, menu)| {
+                let title = spawner.button(self, menu.label.clone(), vec![index]);
+                let popup = spawner.popup(self, &menu.items, vec![index]);
+                self.entity(title).add_child(popup);
+                title
+            })
+            .collect::<Vec<_>>();
+        let mut bar = self.entity(bar);
+        bar.insert(MenuBar {
+            menus,
+            state: MenuState::default(),
+            color,
+            highlight_color: Color::rgb(0.35, 0.35, 0.35),
+        })
+        .push_children(&titles);
+        bar
+    }
+}
+
+/// Opens menus on click and hover, sends [`MenuAction`]s and handles keyboard navigation.
+pub fn menu_bar_system(
+    keys: Option<Res<Input<KeyCode>>>,
+    buttons: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
+    mut bars: Query<(Entity, &mut MenuBar)>,
+    mut actions: EventWriter<MenuAction>,
+) {
+    for (interaction, button) in buttons.iter() {
+        let Ok((_, mut bar)) = bars.get_mut(button.bar) else {
+            continue;
+        };
+        let bar = bar.as_mut();
+        match interaction {
+            Interaction::Clicked if button.path.len() == 1 && bar.state.is_open() => {
+                bar.state.close();
+            }
+            Interaction::Clicked => {
+                bar.state.hover(&bar.menus, &button.path);
+                if let Some(action) = bar.state.activate(&bar.menus) {
+                    actions.send(MenuAction {
+                        bar: button.bar,
+                        action,
+                    });
+                }
+            }
+            Interaction::Hovered if bar.state.is_open() => {
+                bar.state.hover(&bar.menus, &button.path);
+            }
+            _ => {}
+        }
+    }
+    let Some(keys) = keys else {
+        return;
+    };
+    for (entity, mut bar) in bars.iter_mut() {
+        if !bar.state.is_open() {
+            continue;
+        }
+        let bar = bar.as_mut();
+        let state = &mut bar.state;
+        let menus = &bar.menus;
+        for key in keys.get_just_pressed() {
+            match key {
+                KeyCode::Down => state.next(menus),
+                KeyCode::Up => state.previous(menus),
+                KeyCode::Right => state.forward(menus),
+                KeyCode::Left => state.back(menus),
+                KeyCode::Escape => state.close(),
+                KeyCode::Return | KeyCode::Space => {
+                    if let Some(action) = state.activate(menus) {
+                        actions.send(MenuAction {
+                            bar: entity,
+                            action,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Shows the open menus and highlights the items of each [`MenuBar`].
+pub fn update_menu_bar_system(
+    bars: Query<&MenuBar, Changed<MenuBar>>,
+    mut popups: Query<(&MenuPopup, &mut Style)>,
+    mut buttons: Query<(&MenuButton, &mut BackgroundColor)>,
+) {
+    if bars.is_empty() {
+        return;
+    }
+    for (popup, mut style) in popups.iter_mut() {
+        let Ok(bar) = bars.get(popup.bar) else {
+            continue;
+        };
+        let display = if bar.state.open.starts_with(&popup.path) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if style.display != display {
+            style.display = display;
+        }
+    }
+    for (button, mut color) in buttons.iter_mut() {
+        let Ok(bar) = bars.get(button.bar) else {
+            continue;
+        };
+        let target = if bar.state.is_highlighted(&button.path) {
+            bar.highlight_color
+        } else {
+            bar.color
+        };
+        if color.0 != target {
+            color.0 = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::CommandQueue;
+
+    fn menus() -> Vec<Menu> {
+        vec![
+            Menu::new("File")
+                .item("Open", "open")
+                .submenu(Menu::new("Recent").item("a.scn", "recent-a"))
+                .separator()
+                .item("Quit", "quit"),
+            Menu::new("Edit").item("Undo", "undo"),
+        ]
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let menus = menus();
+        let mut state = MenuState::default();
+        state.hover(&menus, &[0]);
+        state.next(&menus);
+        assert_eq!(state.highlight, Some(0));
+        state.previous(&menus);
+        // the separator is skipped
+        assert_eq!(state.highlight, Some(3));
+        state.next(&menus);
+        state.next(&menus);
+        state.forward(&menus);
+        assert_eq!(state.open, vec![0, 1]);
+        assert_eq!(state.highlight, Some(0));
+        assert!(state.is_highlighted(&[0, 1]));
+        assert_eq!(state.activate(&menus), Some("recent-a".into()));
+        assert!(!state.is_open());
+        state.hover(&menus, &[0]);
+        state.forward(&menus);
+        assert_eq!(state.open, vec![1]);
+        state.back(&menus);
+        assert_eq!(state.open, vec![0]);
+    }
+
+    #[test]
+    fn clicking_items_sends_actions() {
+        let mut app = App::new();
+        app.add_event::<MenuAction>()
+            .add_system(menu_bar_system)
+            .add_system(update_menu_bar_system.after(menu_bar_system));
+        let mut queue = CommandQueue::default();
+        let bar = Commands::new(&mut queue, &app.world)
+            .menu_bar(menus(), TextStyle::default())
+            .id();
+        queue.apply(&mut app.world);
+        app.update();
+        let mut query = app.world.query::<(Entity, &MenuButton)>();
+        let mut find = |world: &mut World, path: &[usize]| {
+            query
+                .iter(world)
+                .find(|(_, button)| button.path == path)
+                .unwrap()
+                .0
+        };
+        let file = find(&mut app.world, &[0]);
+        let quit = find(&mut app.world, &[0, 3]);
+        *app.world.get_mut::<Interaction>(file).unwrap() = Interaction::Clicked;
+        app.update();
+        assert_eq!(app.world.get::<MenuBar>(bar).unwrap().state.open, vec![0]);
+        *app.world.get_mut::<Interaction>(quit).unwrap() = Interaction::Clicked;
+        app.update();
+        assert!(!app.world.get::<MenuBar>(bar).unwrap().state.is_open());
+        let events = app.world.resource::<Events<MenuAction>>();
+        let actions = events
+            .get_reader()
+            .iter(events)
+            .map(|event| event.action.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(actions, vec![Cow::from("quit")]);
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::math::Rect;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::interaction::StyleInteractionPlugin;
+
+/// Implements `FromWorld` and `MapEntities` for widget parts that link to other entities,
+/// so they can be reflected into scenes.
+///
+/// Each entry is `Component { entity fields; other fields }`. The entity fields start
+/// as a placeholder that is replaced when a scene is loaded, and the others as their defaults.
+macro_rules! impl_entity_links {
+    ($($component:ident { $($entity:ident),+ $(; $($field:ident),+)? })*) => {
+        $(
+            impl FromWorld for $component {
+                fn from_world(_: &mut World) -> Self {
+                    Self {
+                        $($entity: Entity::from_raw(u32::MAX),)+
+                        $($($field: Default::default(),)+)?
+                    }
+                }
+            }
+
+            impl bevy::ecs::entity::MapEntities for $component {
+                fn map_entities(
+                    &mut self,
+                    entity_map: &bevy::ecs::entity::EntityMap,
+                ) -> Result<(), bevy::ecs::entity::MapEntitiesError> {
+                    $(self.$entity = entity_map.get(self.$entity)?;)+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+pub mod backdrop;
+pub mod badge;
+pub mod breadcrumbs;
+pub mod canvas;
+pub mod collapsible;
+pub mod dock;
+pub mod floating_panel;
+pub mod image_fallback;
+pub mod menu_bar;
+pub mod pager;
+pub mod reorderable_list;
+pub mod skeleton;
+pub mod spinner;
+pub mod stepper;
+pub mod table;
+
+/// The cursor position in UI coordinates, with the origin at the top left of the primary window.
+pub(crate) fn cursor_position(windows: &Windows) -> Option<Vec2> {
+    let window = windows.get_primary()?;
+    window
+        .cursor_position()
+        .map(|position| Vec2::new(position.x, window.height() - position.y))
+}
+
+/// The rectangle covered by a node in UI coordinates.
+pub(crate) fn node_rect(node: &Node, transform: &GlobalTransform) -> Rect {
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+/// Adds the systems that drive the crate's widgets.
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<collapsible::CollapsibleStates>()
+            .add_event::<backdrop::BackdropDismissed>()
+            .add_event::<reorderable_list::ReorderEvent>()
+            .add_event::<menu_bar::MenuAction>()
+            .add_event::<breadcrumbs::BreadcrumbClicked>()
+            .add_event::<table::TableSortRequested>()
+            .add_event::<stepper::StepperChanged>()
+            .add_system(backdrop::backdrop_system)
+            .add_system(breadcrumbs::breadcrumb_click_system)
+            .add_system(collapsible::collapsible_system)
+            .add_system(dock::dock_drag_system)
+            .add_system(floating_panel::floating_panel_system)
+            .add_system(image_fallback::image_fallback_system)
+            .add_system(menu_bar::menu_bar_system)
+            .add_system(pager::pager_system)
+            .add_system(skeleton::skeleton_system)
+            .add_system(spinner::circular_progress_system)
+            .add_system(stepper::stepper_system)
+            .add_system(table::table_interaction_system)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                badge::pill_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                breadcrumbs::breadcrumbs_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                canvas::canvas_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                collapsible::update_collapsible_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                menu_bar::update_menu_bar_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                pager::update_pager_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                dock::dock_layout_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                reorderable_list::reorderable_list_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                table::table_system.before(UiSystem::Flex),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                stepper::update_stepper_system.before(UiSystem::Flex),
+            );
+        // Widgets are disabled by the `Disabled` marker, so its systems are always needed.
+        if !app.is_plugin_added::<StyleInteractionPlugin>() {
+            app.add_plugin(StyleInteractionPlugin);
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use crate::easing::Ease;
+use crate::fields::set_if_neq;
+use crate::fields::StyleFields;
+use crate::transition::TransitionTiming;
+use crate::widgets::cursor_position;
+use crate::Breadth;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// The fraction of the pager's width a swipe has to cover to change the page.
+const SWIPE_THRESHOLD: f32 = 0.2;
+
+/// Shows one of its pages at a time, sliding between them.
+///
+/// The pages are the children of the pager's [`PagerTrack`] and are sized to fill the pager.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Pager {
+    current: usize,
+    page_count: usize,
+    /// Left at its default when loaded from a scene, as it may hold a custom easing function.
+    #[reflect(ignore)]
+    pub timing: TransitionTiming,
+    /// Whether the pages can be changed by dragging them.
+    pub swipe: bool,
+    pub dot_color: Color,
+    pub active_dot_color: Color,
+    position: f32,
+    from: f32,
+    elapsed: Duration,
+    #[reflect(ignore)]
+    drag: Option<PagerDrag>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct PagerDrag {
+    start_cursor: f32,
+    start_position: f32,
+}
+
+impl Default for Pager {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            page_count: 0,
+            timing: TransitionTiming::Eased(Duration::from_millis(300), Ease::CubicOut),
+            swipe: true,
+            dot_color: Color::GRAY,
+            active_dot_color: Color::WHITE,
+            position: 0.,
+            from: 0.,
+            elapsed: Duration::ZERO,
+            drag: None,
+        }
+    }
+}
+
+impl Pager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timing(mut self, timing: TransitionTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    pub fn with_swipe(mut self, swipe: bool) -> Self {
+        self.swipe = swipe;
+        self
+    }
+
+    /// The index of the page being shown.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// The scroll position of the pages, in pages. Fractional while sliding.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Slide to the page at `index`, clamped to the last page.
+    pub fn go_to(&mut self, index: usize) {
+        self.current = index.min(self.page_count.saturating_sub(1));
+        self.from = self.position;
+        self.elapsed = Duration::ZERO;
+    }
+
+    pub fn next(&mut self) {
+        self.go_to(self.current + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.go_to(self.current.saturating_sub(1));
+    }
+
+    /// Advance the slide towards the current page by `delta`.
+    pub fn step(&mut self, delta: Duration) {
+        if self.drag.is_some() {
+            return;
+        }
+        self.elapsed += delta;
+        let target = self.current as f32;
+        self.position = if self.timing.duration() <= self.elapsed {
+            target
+        } else {
+            self.from + (target - self.from) * self.timing.progress(self.elapsed)
+        };
+    }
+}
+
+/// The node holding the pages of a [`Pager`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerTrack {
+    pub pager: Entity,
+}
+
+/// The clipping node around a [`PagerTrack`] that receives swipes.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerViewport {
+    pub pager: Entity,
+}
+
+/// A button that moves a [`Pager`] back a page.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerPrevious {
+    pub pager: Entity,
+}
+
+/// A button that moves a [`Pager`] forward a page.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerNext {
+    pub pager: Entity,
+}
+
+/// The node holding a [`Pager`]'s page-indicator dots.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerDots {
+    pub pager: Entity,
+}
+
+/// A page-indicator dot that slides its [`Pager`] to its page when clicked.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct PagerDot {
+    pub pager: Entity,
+    pub index: usize,
+}
+
+impl_entity_links! {
+    PagerTrack { pager }
+    PagerViewport { pager }
+    PagerPrevious { pager }
+    PagerNext { pager }
+    PagerDots { pager }
+    PagerDot { pager; index }
+}
+
+fn control_button() -> ButtonBundle {
+    ButtonBundle {
+        style: crate::style().padding(Breadth::Px(4.)),
+        background_color: Color::NONE.into(),
+        ..default()
+    }
+}
+
+fn build_pager(root: &mut EntityCommands, pager: Pager, pages: impl FnOnce(&mut ChildBuilder)) {
+    let entity = root.id();
+    root.insert((
+        NodeBundle::default()
+            .column()
+            .size((Val::Percent(100.), Val::Percent(100.))),
+        pager,
+    ))
+    .with_children(|parent| {
+        parent
+            .spawn((
+                NodeBundle::default().grow(1.).hide_overflow(),
+                Interaction::None,
+                PagerViewport { pager: entity },
+            ))
+            .with_children(|viewport| {
+                viewport
+                    .spawn((
+                        NodeBundle::default().row().width(Val::Percent(100.)),
+                        PagerTrack { pager: entity },
+                    ))
+                    .with_children(pages);
+            });
+        parent
+            .spawn(
+                NodeBundle::default()
+                    .row()
+                    .justify_content(JustifyContent::Center)
+                    .align_items_center(),
+            )
+            .with_children(|controls| {
+                controls
+                    .spawn((control_button(), PagerPrevious { pager: entity }))
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section("<", TextStyle::default()));
+                    });
+                controls.spawn((
+                    NodeBundle::default().row().align_items_center(),
+                    PagerDots { pager: entity },
+                ));
+                controls
+                    .spawn((control_button(), PagerNext { pager: entity }))
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section(">", TextStyle::default()));
+                    });
+            });
+    });
+}
+
+pub trait PagerExt<'w, 's> {
+    /// Spawn a pager whose pages are added with `pages`.
+    fn pager<'a>(
+        &'a mut self,
+        pager: Pager,
+        pages: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> PagerExt<'w, 's> for Commands<'w, 's> {
+    fn pager<'a>(
+        &'a mut self,
+        pager: Pager,
+        pages: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a> {
+        let mut root = self.spawn_empty();
+        build_pager(&mut root, pager, pages);
+        root
+    }
+}
+
+impl<'w, 's, 'b> PagerExt<'w, 's> for ChildBuilder<'w, 's, 'b> {
+    fn pager<'a>(
+        &'a mut self,
+        pager: Pager,
+        pages: impl FnOnce(&mut ChildBuilder),
+    ) -> EntityCommands<'w, 's, 'a> {
+        let mut root = self.spawn_empty();
+        build_pager(&mut root, pager, pages);
+        root
+    }
+}
+
+/// Changes the page of [`Pager`]s when their controls are clicked or their pages are swiped,
+/// and advances their slides.
+pub fn pager_system(
+    time: Res<Time>,
+    windows: Option<Res<Windows>>,
+    mut pagers: Query<&mut Pager>,
+    viewports: Query<(&Interaction, &PagerViewport, &Node)>,
+    previous: Query<(&Interaction, &PagerPrevious), Changed<Interaction>>,
+    next: Query<(&Interaction, &PagerNext), Changed<Interaction>>,
+    dots: Query<(&Interaction, &PagerDot), Changed<Interaction>>,
+) {
+    for (interaction, button) in previous.iter() {
+        if *interaction == Interaction::Clicked {
+            if let Ok(mut pager) = pagers.get_mut(button.pager) {
+                pager.previous();
+            }
+        }
+    }
+    for (interaction, button) in next.iter() {
+        if *interaction == Interaction::Clicked {
+            if let Ok(mut pager) = pagers.get_mut(button.pager) {
+                pager.next();
+            }
+        }
+    }
+    for (interaction, dot) in dots.iter() {
+        if *interaction == Interaction::Clicked {
+            if let Ok(mut pager) = pagers.get_mut(dot.pager) {
+                pager.go_to(dot.index);
+            }
+        }
+    }
+    let cursor = windows.and_then(|windows| cursor_position(&windows));
+    for (interaction, viewport, node) in viewports.iter() {
+        let Ok(mut pager) = pagers.get_mut(viewport.pager) else {
+            continue;
+        };
+        let width = node.size().x;
+        if !pager.swipe || width <= 0. {
+            continue;
+        }
+        match (pager.drag, cursor) {
+            (None, Some(cursor)) if *interaction == Interaction::Clicked => {
+                pager.drag = Some(PagerDrag {
+                    start_cursor: cursor.x,
+                    start_position: pager.position,
+                });
+            }
+            (Some(drag), Some(cursor)) if *interaction == Interaction::Clicked => {
+                let last = pager.page_count.saturating_sub(1) as f32;
+                pager.position =
+                    (drag.start_position + (drag.start_cursor - cursor.x) / width).clamp(0., last);
+            }
+            (Some(drag), _) => {
+                pager.drag = None;
+                let moved = pager.position - drag.start_position;
+                let current = pager.current;
+                if SWIPE_THRESHOLD < moved {
+                    pager.go_to(current + 1);
+                } else if moved < -SWIPE_THRESHOLD {
+                    pager.go_to(current.saturating_sub(1));
+                } else {
+                    pager.go_to(current);
+                }
+            }
+            _ => {}
+        }
+    }
+    for mut pager in pagers.iter_mut() {
+        let target = pager.current as f32;
+        if pager.position != target || pager.drag.is_some() {
+            pager.step(time.delta());
+        }
+    }
+}
+
+/// Positions the pages of each [`Pager`] and keeps its dots in sync with its pages.
+#[allow(clippy::type_complexity)]
+pub fn update_pager_system(
+    mut commands: Commands,
+    mut pagers: Query<&mut Pager>,
+    mut tracks: Query<(&PagerTrack, &mut Style, Option<&Children>)>,
+    mut pages: Query<&mut Style, Without<PagerTrack>>,
+    dot_nodes: Query<(Entity, &PagerDots, Option<&Children>)>,
+    mut dots: Query<(&PagerDot, &mut BackgroundColor)>,
+) {
+    for (track, mut style, children) in tracks.iter_mut() {
+        let Ok(mut pager) = pagers.get_mut(track.pager) else {
+            continue;
+        };
+        let page_count = children.map_or(0, |children| children.len());
+        if pager.page_count != page_count {
+            let pager = pager.bypass_change_detection();
+            pager.page_count = page_count;
+            pager.current = pager.current.min(page_count.saturating_sub(1));
+        }
+        set_if_neq(
+            &mut style,
+            Style::left_mut,
+            Val::Percent(-100. * pager.position),
+        );
+        for child in children.into_iter().flatten() {
+            if let Ok(mut page) = pages.get_mut(*child) {
+                if page.width_val() != Val::Percent(100.) || page.flex_shrink != 0. {
+                    *page.width_mut() = Val::Percent(100.);
+                    page.flex_shrink = 0.;
+                }
+            }
+        }
+    }
+    for (entity, dot_node, children) in dot_nodes.iter() {
+        let Ok(pager) = pagers.get(dot_node.pager) else {
+            continue;
+        };
+        if children.map_or(0, |children| children.len()) == pager.page_count {
+            continue;
+        }
+        if let Some(children) = children {
+            for child in children.iter() {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        commands.entity(entity).with_children(|parent| {
+            for index in 0..pager.page_count {
+                parent.spawn((
+                    ButtonBundle {
+                        style: crate::style()
+                            .size((Val::Px(8.), Val::Px(8.)))
+                            .margin(Val::Px(3.)),
+                        background_color: pager.dot_color.into(),
+                        ..default()
+                    },
+                    PagerDot {
+                        pager: dot_node.pager,
+                        index,
+                    },
+                ));
+            }
+        });
+    }
+    for (dot, mut color) in dots.iter_mut() {
+        let Ok(pager) = pagers.get(dot.pager) else {
+            continue;
+        };
+        let target = if dot.index == pager.current {
+            pager.active_dot_color
+        } else {
+            pager.dot_color
+        };
+        if color.0 != target {
+            color.0 = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeColorExt;
+    use bevy::ecs::system::CommandQueue;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_system(pager_system)
+            .add_system(update_pager_system.after(pager_system));
+        app
+    }
+
+    fn spawn_pager(world: &mut World, pager: Pager) -> Entity {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let entity = commands
+            .pager(pager, |pages| {
+                for _ in 0..3 {
+                    pages.spawn(NodeBundle::default().background_color(Color::RED));
+                }
+            })
+            .id();
+        queue.apply(world);
+        entity
+    }
+
+    #[test]
+    fn slide_eases_towards_the_current_page() {
+        let mut pager = Pager::new().with_timing(TransitionTiming::Eased(
+            Duration::from_secs(1),
+            Ease::Linear,
+        ));
+        pager.page_count = 3;
+        pager.next();
+        pager.step(Duration::from_millis(500));
+        assert_eq!(pager.position(), 0.5);
+        pager.go_to(10);
+        assert_eq!(pager.current(), 2);
+        pager.step(Duration::from_millis(500));
+        assert_eq!(pager.position(), 1.25);
+        pager.step(Duration::from_secs(1));
+        assert_eq!(pager.position(), 2.);
+        pager.previous();
+        assert_eq!(pager.current(), 1);
+    }
+
+    #[test]
+    fn controls_change_page() {
+        let mut app = test_app();
+        let entity = spawn_pager(&mut app.world, Pager::new());
+        app.update();
+        app.update();
+        assert_eq!(app.world.get::<Pager>(entity).unwrap().page_count(), 3);
+        let mut dots = app.world.query::<&PagerDot>();
+        assert_eq!(dots.iter(&app.world).count(), 3);
+        let mut next = app.world.query::<(&mut Interaction, &PagerNext)>();
+        *next.single_mut(&mut app.world).0 = Interaction::Clicked;
+        app.update();
+        assert_eq!(app.world.get::<Pager>(entity).unwrap().current(), 1);
+        let mut dots = app.world.query::<(&mut Interaction, &PagerDot)>();
+        for (mut interaction, dot) in dots.iter_mut(&mut app.world) {
+            if dot.index == 2 {
+                *interaction = Interaction::Clicked;
+            }
+        }
+        app.update();
+        assert_eq!(app.world.get::<Pager>(entity).unwrap().current(), 2);
+        let active = app
+            .world
+            .query::<(&PagerDot, &BackgroundColor)>()
+            .iter(&app.world)
+            .find(|(dot, _)| dot.index == 2)
+            .unwrap()
+            .1
+             .0;
+        assert_eq!(active, Color::WHITE);
+    }
+}
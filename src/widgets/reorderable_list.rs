@@ -0,0 +1,292 @@
+use crate::fields::StyleFields;
+use crate::widgets::cursor_position;
+use crate::widgets::node_rect;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+
+/// A list whose children can be dragged to new positions.
+///
+/// Children need an [`Interaction`] component to be draggable, `ButtonBundle`s have one.
+/// While a child is dragged it follows the cursor as a translucent ghost and a placeholder
+/// holds its slot. On release the child is moved to the placeholder's position in
+/// the list's [`Children`] and a [`ReorderEvent`] is sent.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ReorderableList {
+    /// The color of the placeholder shown where the dragged child will be dropped.
+    pub placeholder_color: Color,
+    /// The alpha multiplier applied to the dragged child.
+    pub ghost_alpha: f32,
+    #[reflect(ignore)]
+    drag: Option<ReorderDrag>,
+}
+
+#[derive(Clone, Debug)]
+struct ReorderDrag {
+    item: Entity,
+    placeholder: Entity,
+    from: usize,
+    to: usize,
+    grab_offset: Vec2,
+    style: Style,
+    color: Option<BackgroundColor>,
+}
+
+impl Default for ReorderableList {
+    fn default() -> Self {
+        Self {
+            placeholder_color: Color::rgba(1., 1., 1., 0.1),
+            ghost_alpha: 0.6,
+            drag: None,
+        }
+    }
+}
+
+impl ReorderableList {
+    pub fn with_placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = color;
+        self
+    }
+
+    pub fn with_ghost_alpha(mut self, alpha: f32) -> Self {
+        self.ghost_alpha = alpha;
+        self
+    }
+
+    /// The child currently being dragged.
+    pub fn dragged(&self) -> Option<Entity> {
+        self.drag.as_ref().map(|drag| drag.item)
+    }
+}
+
+/// Sent when a child of a [`ReorderableList`] is dropped at a new index.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReorderEvent {
+    pub list: Entity,
+    pub item: Entity,
+    pub old_index: usize,
+    pub new_index: usize,
+}
+
+/// A column whose children can be reordered by dragging.
+pub fn reorderable_list() -> (NodeBundle, ReorderableList) {
+    (NodeBundle::default().column(), ReorderableList::default())
+}
+
+/// The index at which to drop an item, given the main axis midpoints of the other items in order.
+pub(crate) fn drop_index(
+    midpoints: impl Iterator<Item = f32>,
+    cursor: f32,
+    reverse: bool,
+) -> usize {
+    midpoints
+        .take_while(|&midpoint| {
+            if reverse {
+                cursor < midpoint
+            } else {
+                midpoint < cursor
+            }
+        })
+        .count()
+}
+
+fn move_child(commands: &mut Commands, parent: Entity, child: Entity, index: usize) {
+    commands
+        .entity(parent)
+        .remove_children(&[child])
+        .insert_children(index, &[child]);
+}
+
+/// Starts, updates and finishes drags in [`ReorderableList`]s.
+#[allow(clippy::type_complexity)]
+pub fn reorderable_list_system(
+    mut commands: Commands,
+    windows: Option<Res<Windows>>,
+    mut lists: Query<(
+        Entity,
+        &mut ReorderableList,
+        &Children,
+        &Node,
+        &GlobalTransform,
+    )>,
+    nodes: Query<(&Node, &GlobalTransform, Option<&Interaction>)>,
+    mut styles: Query<(&mut Style, Option<&mut BackgroundColor>)>,
+    mut events: EventWriter<ReorderEvent>,
+) {
+    let cursor = windows.and_then(|windows| cursor_position(&windows));
+    for (list, mut reorderable, children, list_node, list_transform) in lists.iter_mut() {
+        let list_min = node_rect(list_node, list_transform).min;
+        let Some(drag) = reorderable.drag.as_mut() else {
+            let Some(cursor) = cursor else {
+                continue;
+            };
+            let pressed = children.iter().enumerate().find(|(_, child)| {
+                matches!(nodes.get(**child), Ok((.., Some(Interaction::Clicked))))
+            });
+            let Some((index, &item)) = pressed else {
+                continue;
+            };
+            let Ok((node, transform, _)) = nodes.get(item) else {
+                continue;
+            };
+            let Ok((mut style, mut color)) = styles.get_mut(item) else {
+                continue;
+            };
+            let rect = node_rect(node, transform);
+            let placeholder = commands
+                .spawn(
+                    NodeBundle::default()
+                        .size((Val::Px(rect.width()), Val::Px(rect.height())))
+                        .margin(style.margin)
+                        .shrink(0.)
+                        .background_color(reorderable.placeholder_color),
+                )
+                .id();
+            commands
+                .entity(list)
+                .remove_children(&[item])
+                .insert_children(index, &[placeholder])
+                .push_children(&[item]);
+            let original_style = style.clone();
+            let original_color = color.as_deref().copied();
+            let position = rect.min - list_min;
+            style.position_type = PositionType::Absolute;
+            *style.left_mut() = Val::Px(position.x);
+            *style.top_mut() = Val::Px(position.y);
+            *style.right_mut() = Val::Undefined;
+            *style.bottom_mut() = Val::Undefined;
+            style.set_size(Size::new(Val::Px(rect.width()), Val::Px(rect.height())));
+            if let Some(color) = color.as_mut() {
+                let alpha = color.0.a() * reorderable.ghost_alpha;
+                color.0.set_a(alpha);
+            }
+            reorderable.drag = Some(ReorderDrag {
+                item,
+                placeholder,
+                from: index,
+                to: index,
+                grab_offset: cursor - rect.min,
+                style: original_style,
+                color: original_color,
+            });
+            continue;
+        };
+        if nodes.get(drag.item).is_err() {
+            // the dragged child was despawned
+            commands.entity(drag.placeholder).despawn_recursive();
+            reorderable.drag = None;
+            continue;
+        }
+        let held = matches!(nodes.get(drag.item), Ok((.., Some(Interaction::Clicked))));
+        if !held {
+            commands.entity(list).remove_children(&[drag.placeholder]);
+            commands.entity(drag.placeholder).despawn_recursive();
+            move_child(&mut commands, list, drag.item, drag.to);
+            if let Ok((mut style, color)) = styles.get_mut(drag.item) {
+                *style = drag.style.clone();
+                if let (Some(mut color), Some(original)) = (color, drag.color) {
+                    *color = original;
+                }
+            }
+            if drag.from != drag.to {
+                events.send(ReorderEvent {
+                    list,
+                    item: drag.item,
+                    old_index: drag.from,
+                    new_index: drag.to,
+                });
+            }
+            reorderable.drag = None;
+            continue;
+        }
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        if let Ok((mut style, _)) = styles.get_mut(drag.item) {
+            let position = cursor - drag.grab_offset - list_min;
+            *style.left_mut() = Val::Px(position.x);
+            *style.top_mut() = Val::Px(position.y);
+        }
+        let (horizontal, reverse) = match styles.get(list).map(|(style, _)| style.flex_direction) {
+            Ok(FlexDirection::Row) => (true, false),
+            Ok(FlexDirection::RowReverse) => (true, true),
+            Ok(FlexDirection::ColumnReverse) => (false, true),
+            _ => (false, false),
+        };
+        let axis = |point: Vec2| if horizontal { point.x } else { point.y };
+        let midpoints = children
+            .iter()
+            .filter(|child| **child != drag.item && **child != drag.placeholder)
+            .filter_map(|child| nodes.get(*child).ok())
+            .map(|(node, transform, _)| axis(node_rect(node, transform).center()));
+        let to = drop_index(midpoints, axis(cursor), reverse);
+        if to != drag.to {
+            move_child(&mut commands, list, drag.placeholder, to);
+            drag.to = to;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_indices() {
+        let midpoints = [10., 30., 50.];
+        assert_eq!(drop_index(midpoints.into_iter(), 0., false), 0);
+        assert_eq!(drop_index(midpoints.into_iter(), 35., false), 2);
+        assert_eq!(drop_index(midpoints.into_iter(), 60., false), 3);
+        assert_eq!(drop_index([50., 30., 10.].into_iter(), 35., true), 1);
+    }
+
+    #[test]
+    fn release_restores_item() {
+        let mut app = App::new();
+        app.add_event::<ReorderEvent>()
+            .add_system(reorderable_list_system);
+        let item = app
+            .world
+            .spawn((NodeBundle::default().width(Val::Px(20.)), Interaction::None))
+            .id();
+        let other = app.world.spawn(NodeBundle::default()).id();
+        let placeholder = app.world.spawn(NodeBundle::default()).id();
+        let original = app.world.get::<Style>(item).unwrap().clone();
+        let list = ReorderableList {
+            drag: Some(ReorderDrag {
+                item,
+                placeholder,
+                from: 0,
+                to: 1,
+                grab_offset: Vec2::ZERO,
+                style: original.clone(),
+                color: None,
+            }),
+            ..default()
+        };
+        let list = app.world.spawn((reorderable_list().0, list)).id();
+        app.world
+            .entity_mut(list)
+            .push_children(&[other, placeholder, item]);
+        app.world.get_mut::<Style>(item).unwrap().position_type = PositionType::Absolute;
+        app.update();
+        assert_eq!(app.world.get::<Style>(item).unwrap(), &original);
+        assert_eq!(
+            app.world.get::<Children>(list).unwrap().to_vec(),
+            vec![other, item]
+        );
+        assert!(app.world.get_entity(placeholder).is_none());
+        let events = app.world.resource::<Events<ReorderEvent>>();
+        let event = events.get_reader().iter(events).next().copied();
+        assert_eq!(
+            event,
+            Some(ReorderEvent {
+                list,
+                item,
+                old_index: 0,
+                new_index: 1,
+            })
+        );
+    }
+}
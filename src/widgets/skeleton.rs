@@ -0,0 +1,120 @@
+use crate::fields::StyleFields;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A placeholder block for content that is still loading, with a highlight sweeping across it.
+///
+/// Skeletons shimmer in step with each other, as the highlight is positioned by the elapsed time.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Skeleton {
+    pub highlight: Color,
+    /// The time for the highlight to cross the skeleton.
+    pub period: Duration,
+    /// The width of the highlight, as a percentage of the skeleton's width.
+    pub highlight_width: f32,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self {
+            highlight: Color::rgba(1., 1., 1., 0.08),
+            period: Duration::from_millis(1500),
+            highlight_width: 40.,
+        }
+    }
+}
+
+impl Skeleton {
+    /// The left edge of the highlight `elapsed` into the animation,
+    /// as a percentage of the skeleton's width.
+    pub fn highlight_left(&self, elapsed: Duration) -> f32 {
+        let t = if self.period.is_zero() {
+            0.
+        } else {
+            (elapsed.as_secs_f32() / self.period.as_secs_f32()).fract()
+        };
+        -self.highlight_width + t * (100. + self.highlight_width)
+    }
+}
+
+/// The moving highlight of a [`Skeleton`].
+#[derive(Component, Copy, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SkeletonHighlight;
+
+/// A `width` by `height` skeleton placeholder.
+pub fn skeleton(width: Val, height: Val) -> (NodeBundle, Skeleton) {
+    (
+        NodeBundle::default()
+            .size((width, height))
+            .shrink(0.)
+            .hide_overflow()
+            .background_color(Color::rgb(0.22, 0.22, 0.22)),
+        Skeleton::default(),
+    )
+}
+
+/// Adds the highlight to new [`Skeleton`]s and sweeps it across them.
+pub fn skeleton_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    added: Query<(Entity, &Skeleton), Added<Skeleton>>,
+    skeletons: Query<&Skeleton>,
+    mut highlights: Query<(&Parent, &mut Style, &mut BackgroundColor), With<SkeletonHighlight>>,
+) {
+    for (entity, skeleton) in added.iter() {
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                NodeBundle::default()
+                    .absolute()
+                    .top(Val::Px(0.))
+                    .left(Val::Percent(skeleton.highlight_left(time.elapsed())))
+                    .size((Val::Percent(skeleton.highlight_width), Val::Percent(100.)))
+                    .background_color(skeleton.highlight),
+                SkeletonHighlight,
+            ));
+        });
+    }
+    for (parent, mut style, mut color) in highlights.iter_mut() {
+        let Ok(skeleton) = skeletons.get(parent.get()) else {
+            continue;
+        };
+        *style.left_mut() = Val::Percent(skeleton.highlight_left(time.elapsed()));
+        *style.width_mut() = Val::Percent(skeleton.highlight_width);
+        if color.0 != skeleton.highlight {
+            color.0 = skeleton.highlight;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlight_sweeps_across() {
+        let skeleton = Skeleton {
+            period: Duration::from_secs(1),
+            highlight_width: 50.,
+            ..default()
+        };
+        assert_eq!(skeleton.highlight_left(Duration::ZERO), -50.);
+        assert_eq!(skeleton.highlight_left(Duration::from_millis(500)), 25.);
+        assert_eq!(skeleton.highlight_left(Duration::from_millis(1500)), 25.);
+    }
+
+    #[test]
+    fn highlight_is_added() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_system(skeleton_system);
+        let entity = app.world.spawn(skeleton(Val::Px(100.), Val::Px(16.))).id();
+        app.update();
+        app.update();
+        let children = app.world.get::<Children>(entity).unwrap();
+        assert_eq!(children.len(), 1);
+        assert!(app.world.get::<SkeletonHighlight>(children[0]).is_some());
+    }
+}
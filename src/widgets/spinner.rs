@@ -0,0 +1,203 @@
+use crate::StyleBuilderExt;
+use bevy::prelude::*;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy::utils::HashMap;
+use std::f32::consts::TAU;
+
+/// The width and height in pixels of the generated progress textures.
+pub const PROGRESS_TEXTURE_SIZE: u32 = 64;
+
+/// The number of distinct arcs drawn for determinate progress.
+const PROGRESS_STEPS: f32 = 100.;
+
+/// The thickness of the ring as a fraction of its radius.
+const RING_THICKNESS: f32 = 0.25;
+
+/// The fraction of the ring covered by the tail of an indeterminate spinner.
+const SPINNER_ARC: f32 = 0.75;
+
+/// A ring shaped progress indicator.
+///
+/// Shows an arc filled clockwise from the top by `progress`, or a spinning arc while `progress`
+/// is `None`. The textures are generated on first use and shared between indicators.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct CircularProgress {
+    /// The completed fraction between zero and one, or `None` while the amount is unknown.
+    pub progress: Option<f32>,
+    pub color: Color,
+    /// The turns made each second by an indeterminate spinner.
+    pub speed: f32,
+}
+
+impl Default for CircularProgress {
+    fn default() -> Self {
+        Self {
+            progress: None,
+            color: Color::WHITE,
+            speed: 1.,
+        }
+    }
+}
+
+impl CircularProgress {
+    /// The texture key for the current progress, `None` for the spinner.
+    fn step(&self) -> Option<u32> {
+        self.progress
+            .map(|progress| (progress.clamp(0., 1.) * PROGRESS_STEPS).round() as u32)
+    }
+}
+
+/// A square ring texture `size` pixels wide.
+///
+/// `alpha` gives the opacity of the ring at a fraction of a clockwise turn from the top.
+pub fn ring_image(size: u32, alpha: impl Fn(f32) -> f32) -> Image {
+    let center = size as f32 / 2.;
+    let inner = center * (1. - RING_THICKNESS);
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let offset = Vec2::new(x as f32 + 0.5 - center, y as f32 + 0.5 - center);
+            let radius = offset.length();
+            let coverage =
+                (center - radius + 0.5).clamp(0., 1.) * (radius - inner + 0.5).clamp(0., 1.);
+            let turn = offset.x.atan2(-offset.y).rem_euclid(TAU) / TAU;
+            let a = coverage * alpha(turn).clamp(0., 1.);
+            data.extend_from_slice(&[255, 255, 255, (a * 255.).round() as u8]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+/// A ring filled clockwise from the top by `progress`.
+pub fn arc_image(size: u32, progress: f32) -> Image {
+    ring_image(size, |turn| if turn < progress { 1. } else { 0. })
+}
+
+/// An arc fading in towards its head, for an indeterminate spinner turning clockwise.
+pub fn spinner_image(size: u32) -> Image {
+    ring_image(size, |turn| {
+        if turn < SPINNER_ARC {
+            turn / SPINNER_ARC
+        } else {
+            0.
+        }
+    })
+}
+
+/// The default width and height of a progress indicator.
+const INDICATOR_SIZE: f32 = 32.;
+
+/// An indeterminate spinner.
+pub fn spinner() -> (ImageBundle, CircularProgress) {
+    (
+        ImageBundle::default().size((Val::Px(INDICATOR_SIZE), Val::Px(INDICATOR_SIZE))),
+        CircularProgress::default(),
+    )
+}
+
+/// A ring filled by `progress`.
+pub fn circular_progress(progress: f32) -> (ImageBundle, CircularProgress) {
+    (
+        ImageBundle::default().size((Val::Px(INDICATOR_SIZE), Val::Px(INDICATOR_SIZE))),
+        CircularProgress {
+            progress: Some(progress),
+            ..default()
+        },
+    )
+}
+
+/// Draws each [`CircularProgress`] with the texture for its progress and turns the spinners.
+pub fn circular_progress_system(
+    time: Res<Time>,
+    images: Option<ResMut<Assets<Image>>>,
+    mut textures: Local<HashMap<Option<u32>, Handle<Image>>>,
+    mut query: Query<(
+        &CircularProgress,
+        &mut UiImage,
+        &mut Transform,
+        &mut BackgroundColor,
+    )>,
+) {
+    let Some(mut images) = images else {
+        return;
+    };
+    for (progress, mut image, mut transform, mut color) in query.iter_mut() {
+        let step = progress.step();
+        let texture = textures.entry(step).or_insert_with(|| {
+            images.add(match step {
+                Some(step) => arc_image(PROGRESS_TEXTURE_SIZE, step as f32 / PROGRESS_STEPS),
+                None => spinner_image(PROGRESS_TEXTURE_SIZE),
+            })
+        });
+        if image.0 != *texture {
+            image.0 = texture.clone();
+        }
+        let rotation = match step {
+            Some(_) => Quat::IDENTITY,
+            None => Quat::from_rotation_z(-(time.elapsed_seconds() * progress.speed).fract() * TAU),
+        };
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+        if color.0 != progress.color {
+            color.0 = progress.color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alpha(image: &Image, x: u32, y: u32) -> u8 {
+        image.data[((y * PROGRESS_TEXTURE_SIZE + x) * 4 + 3) as usize]
+    }
+
+    #[test]
+    fn arc_is_filled_clockwise_from_the_top() {
+        let image = arc_image(PROGRESS_TEXTURE_SIZE, 0.3);
+        // On the ring to the right of the center, a quarter turn.
+        assert_eq!(alpha(&image, 60, 32), 255);
+        // On the ring below the center, half a turn.
+        assert_eq!(alpha(&image, 32, 60), 0);
+        // Inside the ring.
+        assert_eq!(alpha(&image, 32, 32), 0);
+        let image = spinner_image(PROGRESS_TEXTURE_SIZE);
+        assert!(alpha(&image, 32, 60) > alpha(&image, 60, 32));
+        assert_eq!(alpha(&image, 12, 12), 0);
+    }
+
+    #[test]
+    fn textures_are_shared() {
+        let mut app = App::new();
+        app.add_plugin(CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .init_resource::<Time>()
+            .add_system(circular_progress_system);
+        let first = app.world.spawn(circular_progress(0.5)).id();
+        let second = app
+            .world
+            .spawn(circular_progress(0.501).size((Val::Px(48.), Val::Px(48.))))
+            .id();
+        let spinner = app.world.spawn(spinner()).id();
+        app.update();
+        let image = |entity| app.world.get::<UiImage>(entity).unwrap().0.clone();
+        assert_ne!(image(first), Handle::default());
+        assert_eq!(image(first), image(second));
+        assert_ne!(image(first), image(spinner));
+        assert_eq!(app.world.resource::<Assets<Image>>().len(), 2);
+    }
+}
@@ -0,0 +1,353 @@
+use crate::interaction::DisabledExt;
+use crate::Breadth;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// A number changed in steps by a pair of buttons on either side of it.
+///
+/// Holding a button down repeats its step, first after `repeat_delay` and then every
+/// `repeat_interval`.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Stepper {
+    value: f32,
+    pub step: f32,
+    pub min: f32,
+    pub max: f32,
+    /// The number of digits shown after the decimal point.
+    pub decimals: usize,
+    pub repeat_delay: Duration,
+    pub repeat_interval: Duration,
+    pub text_style: TextStyle,
+    #[reflect(ignore)]
+    hold: Option<StepperHold>,
+}
+
+impl FromWorld for Stepper {
+    fn from_world(_: &mut World) -> Self {
+        Self::new(0., 1., 0.0..=0.)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct StepperHold {
+    increment: bool,
+    elapsed: Duration,
+    next_repeat: Duration,
+}
+
+/// The fewest decimal places that show multiples of `step` exactly, up to four.
+fn decimals_of(step: f32) -> usize {
+    (0..4)
+        .find(|&decimals| {
+            let scaled = step * 10f32.powi(decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-3
+        })
+        .unwrap_or(4)
+}
+
+impl Stepper {
+    /// A stepper starting at `value`, clamped to `range`. A reversed range is put in order.
+    pub fn new(value: f32, step: f32, range: RangeInclusive<f32>) -> Self {
+        let (start, end) = range.into_inner();
+        let mut stepper = Self {
+            value: 0.,
+            step,
+            min: start.min(end),
+            max: start.max(end),
+            decimals: decimals_of(step),
+            repeat_delay: Duration::from_millis(400),
+            repeat_interval: Duration::from_millis(80),
+            text_style: TextStyle::default(),
+            hold: None,
+        };
+        stepper.set_value(value);
+        stepper
+    }
+
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = text_style;
+        self
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value, clamped to the stepper's range. Returns whether the value changed.
+    ///
+    /// If `min` was set above `max`, the value is set to `max`.
+    pub fn set_value(&mut self, value: f32) -> bool {
+        let value = value.max(self.min).min(self.max);
+        let changed = value != self.value;
+        self.value = value;
+        changed
+    }
+
+    /// Change the value by `steps` steps. Returns whether the value changed.
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        let value = self.value + self.step * steps as f32;
+        let rounding = 10f32.powi(self.decimals as i32);
+        self.set_value((value * rounding).round() / rounding)
+    }
+
+    /// The value formatted with the stepper's decimal places.
+    pub fn text(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+
+    /// Hold down the increment or decrement button for `delta`.
+    /// Returns the number of steps to take, one for a new press plus any repeats that are due.
+    pub fn hold(&mut self, increment: bool, delta: Duration) -> u32 {
+        match &mut self.hold {
+            Some(hold) if hold.increment == increment => {
+                hold.elapsed += delta;
+                let mut steps = 0;
+                while hold.next_repeat <= hold.elapsed {
+                    steps += 1;
+                    hold.next_repeat += self.repeat_interval.max(Duration::from_millis(1));
+                }
+                steps
+            }
+            _ => {
+                self.hold = Some(StepperHold {
+                    increment,
+                    elapsed: Duration::ZERO,
+                    next_repeat: self.repeat_delay,
+                });
+                1
+            }
+        }
+    }
+
+    /// Release the held button.
+    pub fn release(&mut self) {
+        self.hold = None;
+    }
+}
+
+/// A button that steps its [`Stepper`] up or down.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct StepperButton {
+    pub stepper: Entity,
+    pub increment: bool,
+}
+
+/// The text showing the value of a [`Stepper`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct StepperValue {
+    pub stepper: Entity,
+}
+
+impl_entity_links! {
+    StepperButton { stepper; increment }
+    StepperValue { stepper }
+}
+
+/// Sent when a [`Stepper`]'s value is changed by its buttons.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StepperChanged {
+    pub stepper: Entity,
+    pub value: f32,
+}
+
+/// A stepper starting at `value`, changed by `step` within `range`.
+pub fn stepper(value: f32, step: f32, range: RangeInclusive<f32>) -> (NodeBundle, Stepper) {
+    (
+        NodeBundle::default().row().align_items_center(),
+        Stepper::new(value, step, range),
+    )
+}
+
+fn spawn_step_button(
+    parent: &mut ChildBuilder,
+    stepper: Entity,
+    increment: bool,
+    text_style: &TextStyle,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: crate::style()
+                    .padding(Breadth::Px(4.))
+                    .justify_content(JustifyContent::Center),
+                background_color: Color::NONE.into(),
+                ..default()
+            },
+            StepperButton { stepper, increment },
+        ))
+        .with_children(|button| {
+            let label = if increment { "+" } else { "-" };
+            button.spawn(TextBundle::from_section(label, text_style.clone()));
+        });
+}
+
+/// Adds the buttons and value to new [`Stepper`]s, and steps them while their buttons are held.
+pub fn stepper_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut steppers: Query<(Entity, &mut Stepper)>,
+    buttons: Query<(&Interaction, &StepperButton)>,
+    mut changed: EventWriter<StepperChanged>,
+) {
+    for (entity, stepper) in steppers.iter_mut() {
+        if !stepper.is_added() {
+            continue;
+        }
+        commands.entity(entity).with_children(|parent| {
+            spawn_step_button(parent, entity, false, &stepper.text_style);
+            parent.spawn((
+                TextBundle::from_section(stepper.text(), stepper.text_style.clone())
+                    .margin(UiRect::horizontal(Val::Px(8.))),
+                StepperValue { stepper: entity },
+            ));
+            spawn_step_button(parent, entity, true, &stepper.text_style);
+        });
+    }
+    let mut held = HashSet::default();
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        let Ok((_, mut stepper)) = steppers.get_mut(button.stepper) else {
+            continue;
+        };
+        held.insert(button.stepper);
+        let steps = stepper
+            .bypass_change_detection()
+            .hold(button.increment, time.delta()) as i32;
+        let steps = if button.increment { steps } else { -steps };
+        if steps != 0 && stepper.bypass_change_detection().step_by(steps) {
+            stepper.set_changed();
+            changed.send(StepperChanged {
+                stepper: button.stepper,
+                value: stepper.value(),
+            });
+        }
+    }
+    for (entity, mut stepper) in steppers.iter_mut() {
+        if stepper.hold.is_some() && !held.contains(&entity) {
+            stepper.bypass_change_detection().release();
+        }
+    }
+}
+
+/// Shows the value of each changed [`Stepper`] and disables its buttons at the ends of its range.
+pub fn update_stepper_system(
+    mut commands: Commands,
+    steppers: Query<&Stepper, Changed<Stepper>>,
+    mut values: Query<(&StepperValue, &mut Text)>,
+    buttons: Query<(Entity, &StepperButton)>,
+) {
+    for (value, mut text) in values.iter_mut() {
+        let Ok(stepper) = steppers.get(value.stepper) else {
+            continue;
+        };
+        let shown = stepper.text();
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != shown {
+                section.value = shown;
+            }
+        }
+    }
+    for (entity, button) in buttons.iter() {
+        let Ok(stepper) = steppers.get(button.stepper) else {
+            continue;
+        };
+        let at_limit = if button.increment {
+            stepper.max <= stepper.value
+        } else {
+            stepper.value <= stepper.min
+        };
+        commands.entity(entity).disabled_when(at_limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_is_clamped_and_formatted() {
+        let mut stepper = Stepper::new(12., 0.25, 0.0..=10.);
+        assert_eq!(stepper.value(), 10.);
+        assert!(!stepper.step_by(1));
+        assert!(stepper.step_by(-3));
+        assert_eq!(stepper.text(), "9.25");
+        assert_eq!(Stepper::new(3., 1., 0.0..=5.).text(), "3");
+        assert_eq!(Stepper::new(0.1, 0.1, 0.0..=1.).text(), "0.1");
+    }
+
+    #[test]
+    fn reversed_ranges_do_not_panic() {
+        let mut stepper = Stepper::new(12., 1., 10.0..=0.);
+        assert_eq!((stepper.min, stepper.max), (0., 10.));
+        assert_eq!(stepper.value(), 10.);
+        stepper.min = 20.;
+        assert!(!stepper.step_by(-1));
+        assert_eq!(stepper.value(), 10.);
+    }
+
+    #[test]
+    fn holding_repeats_after_a_delay() {
+        let mut stepper = Stepper::new(0., 1., 0.0..=100.);
+        let frame = Duration::from_millis(100);
+        assert_eq!(stepper.hold(true, frame), 1);
+        assert_eq!(stepper.hold(true, frame), 0);
+        assert_eq!(stepper.hold(true, frame), 0);
+        assert_eq!(stepper.hold(true, frame), 0);
+        assert_eq!(stepper.hold(true, frame), 1);
+        assert_eq!(stepper.hold(true, frame), 1);
+        assert_eq!(stepper.hold(true, Duration::from_millis(160)), 2);
+        assert_eq!(stepper.hold(false, frame), 1);
+        stepper.release();
+        assert_eq!(stepper.hold(false, frame), 1);
+    }
+
+    #[test]
+    fn clicking_a_button_steps_the_value() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<StepperChanged>()
+            .add_system(stepper_system)
+            .add_system_to_stage(CoreStage::PostUpdate, update_stepper_system);
+        let entity = app.world.spawn(stepper(1., 1., 0.0..=2.)).id();
+        app.update();
+        let mut buttons = app.world.query::<(&StepperButton, &mut Interaction)>();
+        for (button, mut interaction) in buttons.iter_mut(&mut app.world) {
+            if button.increment {
+                *interaction = Interaction::Clicked;
+            }
+        }
+        app.update();
+        assert_eq!(app.world.get::<Stepper>(entity).unwrap().value(), 2.);
+        let events = app.world.resource::<Events<StepperChanged>>();
+        let sent = events
+            .get_reader()
+            .iter(events)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sent,
+            vec![StepperChanged {
+                stepper: entity,
+                value: 2.
+            }]
+        );
+        let mut values = app.world.query::<(&StepperValue, &Text)>();
+        let (_, text) = values.single(&app.world);
+        assert_eq!(text.sections[0].value, "2");
+        let mut buttons = app
+            .world
+            .query::<(&StepperButton, Option<&crate::interaction::Disabled>)>();
+        for (button, disabled) in buttons.iter(&app.world) {
+            assert_eq!(disabled.is_some(), button.increment);
+        }
+    }
+}
@@ -0,0 +1,582 @@
+use crate::fields::set_if_neq;
+use crate::fields::StyleFields;
+use crate::widgets::cursor_position;
+use crate::Breadth;
+use crate::NodeColorExt;
+use crate::StyleBuilderExt;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use std::sync::Arc;
+
+type SpawnCell = dyn Fn(usize, usize, &mut ChildBuilder) + Send + Sync;
+
+/// The narrowest a column can be resized to, in pixels.
+const MIN_COLUMN_WIDTH: f32 = 16.;
+
+/// The horizontal alignment of the contents of a table column.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CellAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl From<CellAlign> for JustifyContent {
+    fn from(align: CellAlign) -> Self {
+        match align {
+            CellAlign::Start => JustifyContent::FlexStart,
+            CellAlign::Center => JustifyContent::Center,
+            CellAlign::End => JustifyContent::FlexEnd,
+        }
+    }
+}
+
+/// The definition of a column of a [`Table`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    pub header: String,
+    pub width: Breadth,
+    pub align: CellAlign,
+    /// Whether clicking the header sends a [`TableSortRequested`] event.
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    pub fn new(header: impl Into<String>, width: Breadth) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            align: CellAlign::Start,
+            sortable: true,
+        }
+    }
+
+    pub fn align(mut self, align: CellAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Spawns the contents of the cell at a row and column of a [`Table`].
+#[derive(Clone)]
+pub struct CellFactory(Arc<SpawnCell>);
+
+impl CellFactory {
+    pub fn new(factory: impl Fn(usize, usize, &mut ChildBuilder) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(factory))
+    }
+
+    /// Spawn the contents of the cell at `row` and `column` with `cell`.
+    pub fn spawn(&self, row: usize, column: usize, cell: &mut ChildBuilder) {
+        (self.0)(row, column, cell);
+    }
+}
+
+impl std::fmt::Debug for CellFactory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CellFactory").finish_non_exhaustive()
+    }
+}
+
+/// The column a [`Table`] is sorted by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: usize,
+    pub ascending: bool,
+}
+
+/// Sent when a sortable header of a [`Table`] is clicked.
+///
+/// The table doesn't reorder its rows itself, sort the data and replace the rows in response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TableSortRequested {
+    pub table: Entity,
+    pub column: usize,
+    pub ascending: bool,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ColumnResize {
+    column: usize,
+    start_cursor: f32,
+    start_width: f32,
+}
+
+/// A grid of cells with a header row.
+///
+/// The rows are rebuilt after [`Table::rows`] or [`Table::text_rows`] is called, or when the
+/// number of columns changes. Changing a column's width, as when a header edge is dragged,
+/// resizes the cells in place.
+#[derive(Component, Clone, Debug)]
+pub struct Table {
+    pub columns: Vec<TableColumn>,
+    row_count: usize,
+    cells: CellFactory,
+    pub text_style: TextStyle,
+    pub header_color: Color,
+    /// If set, the backgrounds of even and odd rows.
+    pub row_colors: Option<(Color, Color)>,
+    pub sort: Option<TableSort>,
+    built: Option<usize>,
+    resize: Option<ColumnResize>,
+}
+
+impl Table {
+    pub fn new(columns: impl IntoIterator<Item = TableColumn>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            row_count: 0,
+            cells: CellFactory::new(|_, _, _| {}),
+            text_style: TextStyle::default(),
+            header_color: Color::rgb(0.25, 0.25, 0.25),
+            row_colors: None,
+            sort: None,
+            built: None,
+            resize: None,
+        }
+    }
+
+    /// Replace the rows with `count` rows whose cells are spawned by `factory`.
+    pub fn rows(
+        &mut self,
+        count: usize,
+        factory: impl Fn(usize, usize, &mut ChildBuilder) + Send + Sync + 'static,
+    ) {
+        self.row_count = count;
+        self.cells = CellFactory::new(factory);
+        self.built = None;
+    }
+
+    /// Replace the rows with rows of text cells.
+    pub fn text_rows(&mut self, rows: Vec<Vec<String>>) {
+        let style = self.text_style.clone();
+        self.rows(rows.len(), move |row, column, cell| {
+            if let Some(text) = rows[row].get(column) {
+                cell.spawn(TextBundle::from_section(text.clone(), style.clone()));
+            }
+        });
+    }
+
+    pub fn with_rows(
+        mut self,
+        count: usize,
+        factory: impl Fn(usize, usize, &mut ChildBuilder) + Send + Sync + 'static,
+    ) -> Self {
+        self.rows(count, factory);
+        self
+    }
+
+    pub fn with_text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    pub fn with_row_colors(mut self, even: Color, odd: Color) -> Self {
+        self.row_colors = Some((even, odd));
+        self
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Rebuild all the rows on the next update.
+    pub fn rebuild(&mut self) {
+        self.built = None;
+    }
+
+    fn header_text(&self, column: usize) -> String {
+        let header = &self.columns[column].header;
+        match self.sort {
+            Some(TableSort {
+                column: c,
+                ascending,
+            }) if c == column => {
+                format!("{header} {}", if ascending { "^" } else { "v" })
+            }
+            _ => header.clone(),
+        }
+    }
+}
+
+/// A cell of a [`Table`], including the header cells.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct TableCell {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// A header cell of a [`Table`].
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct TableHeader {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// The handle on the right edge of a header that resizes its column when dragged.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct ColumnResizeHandle {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// A row of a [`Table`], not including the header row.
+#[derive(Component, Copy, Clone, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct TableRow {
+    pub table: Entity,
+    pub index: usize,
+}
+
+impl_entity_links! {
+    TableCell { table; column }
+    TableHeader { table; column }
+    ColumnResizeHandle { table; column }
+    TableRow { table; index }
+}
+
+/// A table with `columns` and no rows.
+pub fn table(columns: impl IntoIterator<Item = TableColumn>) -> (NodeBundle, Table) {
+    (NodeBundle::default().column(), Table::new(columns))
+}
+
+fn cell_style(column: &TableColumn) -> Style {
+    crate::style()
+        .width(column.width.into())
+        .shrink(0.)
+        .row()
+        .align_items_center()
+        .justify_content(column.align.into())
+        .padding(Breadth::Px(4.))
+}
+
+fn spawn_table(commands: &mut Commands, entity: Entity, table: &Table) {
+    commands.entity(entity).with_children(|parent| {
+        parent
+            .spawn(
+                NodeBundle::default()
+                    .row()
+                    .background_color(table.header_color),
+            )
+            .with_children(|header_row| {
+                for (index, column) in table.columns.iter().enumerate() {
+                    header_row
+                        .spawn((
+                            ButtonBundle {
+                                style: cell_style(column),
+                                background_color: Color::NONE.into(),
+                                ..default()
+                            },
+                            TableCell {
+                                table: entity,
+                                column: index,
+                            },
+                            TableHeader {
+                                table: entity,
+                                column: index,
+                            },
+                        ))
+                        .with_children(|header| {
+                            header.spawn(TextBundle::from_section(
+                                table.header_text(index),
+                                table.text_style.clone(),
+                            ));
+                            header.spawn((
+                                ButtonBundle {
+                                    style: crate::style()
+                                        .absolute()
+                                        .right(Val::Px(0.))
+                                        .top(Val::Px(0.))
+                                        .size((Val::Px(4.), Val::Percent(100.))),
+                                    background_color: Color::NONE.into(),
+                                    ..default()
+                                },
+                                ColumnResizeHandle {
+                                    table: entity,
+                                    column: index,
+                                },
+                            ));
+                        });
+                }
+            });
+        for row in 0..table.row_count {
+            let mut row_node = NodeBundle::default().row();
+            if let Some((even, odd)) = table.row_colors {
+                row_node = row_node.background_color(if row % 2 == 0 { even } else { odd });
+            }
+            parent
+                .spawn((
+                    row_node,
+                    TableRow {
+                        table: entity,
+                        index: row,
+                    },
+                ))
+                .with_children(|row_node| {
+                    for (index, column) in table.columns.iter().enumerate() {
+                        row_node
+                            .spawn((
+                                NodeBundle {
+                                    style: cell_style(column),
+                                    ..default()
+                                },
+                                TableCell {
+                                    table: entity,
+                                    column: index,
+                                },
+                            ))
+                            .with_children(|cell| table.cells.spawn(row, index, cell));
+                    }
+                });
+        }
+    });
+}
+
+/// Rebuilds the rows of [`Table`]s and updates their column widths and headers.
+#[allow(clippy::type_complexity)]
+pub fn table_system(
+    mut commands: Commands,
+    mut tables: Query<(Entity, &mut Table, Option<&Children>), Changed<Table>>,
+    mut cells: Query<(&TableCell, &mut Style)>,
+    headers: Query<(&TableHeader, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, mut table, children) in tables.iter_mut() {
+        if table.built != Some(table.columns.len()) {
+            let table = table.bypass_change_detection();
+            table.built = Some(table.columns.len());
+            if let Some(children) = children {
+                for child in children.iter() {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+            spawn_table(&mut commands, entity, table);
+            continue;
+        }
+        for (cell, mut style) in cells.iter_mut() {
+            if cell.table != entity {
+                continue;
+            }
+            if let Some(column) = table.columns.get(cell.column) {
+                set_if_neq(&mut style, Style::width_mut, Val::from(column.width));
+            }
+        }
+        for (header, children) in headers.iter() {
+            if header.table != entity {
+                continue;
+            }
+            let value = table.header_text(header.column);
+            for child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(*child) {
+                    if text.sections[0].value != value {
+                        text.sections[0].value = value;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Resizes columns whose header edges are dragged and sends sort requests for clicked headers.
+pub fn table_interaction_system(
+    windows: Option<Res<Windows>>,
+    mut tables: Query<&mut Table>,
+    handles: Query<(&Interaction, &ColumnResizeHandle)>,
+    headers: Query<(&Interaction, &TableHeader, &Node), Changed<Interaction>>,
+    header_nodes: Query<(&TableHeader, &Node)>,
+    mut events: EventWriter<TableSortRequested>,
+) {
+    let cursor = windows.and_then(|windows| cursor_position(&windows));
+    for (interaction, handle) in handles.iter() {
+        let Ok(mut table) = tables.get_mut(handle.table) else {
+            continue;
+        };
+        if *interaction != Interaction::Clicked {
+            if table
+                .resize
+                .is_some_and(|resize| resize.column == handle.column)
+            {
+                table.bypass_change_detection().resize = None;
+            }
+            continue;
+        }
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        let resize = match table.resize {
+            Some(resize) => resize,
+            None => {
+                let Some(start_width) = header_nodes.iter().find_map(|(header, node)| {
+                    (header.table == handle.table && header.column == handle.column)
+                        .then(|| node.size().x)
+                }) else {
+                    continue;
+                };
+                let resize = ColumnResize {
+                    column: handle.column,
+                    start_cursor: cursor.x,
+                    start_width,
+                };
+                table.bypass_change_detection().resize = Some(resize);
+                resize
+            }
+        };
+        let width = Breadth::Px(
+            (resize.start_width + cursor.x - resize.start_cursor).max(MIN_COLUMN_WIDTH),
+        );
+        if table.columns[resize.column].width != width {
+            table.columns[resize.column].width = width;
+        }
+    }
+    for (interaction, header, _) in headers.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        let Ok(mut table) = tables.get_mut(header.table) else {
+            continue;
+        };
+        if !table
+            .columns
+            .get(header.column)
+            .is_some_and(|column| column.sortable)
+        {
+            continue;
+        }
+        let ascending = !table
+            .sort
+            .is_some_and(|sort| sort.column == header.column && sort.ascending);
+        table.sort = Some(TableSort {
+            column: header.column,
+            ascending,
+        });
+        events.send(TableSortRequested {
+            table: header.table,
+            column: header.column,
+            ascending,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_event::<TableSortRequested>()
+            .add_system(table_interaction_system)
+            .add_system(table_system.after(table_interaction_system));
+        app
+    }
+
+    fn spawn_test_table(app: &mut App) -> Entity {
+        let (node, mut table) = table([
+            TableColumn::new("Name", Breadth::Px(100.)),
+            TableColumn::new("Score", Breadth::Px(50.)).align(CellAlign::End),
+        ]);
+        table.text_rows(vec![
+            vec!["a".to_string(), "3".to_string()],
+            vec!["b".to_string(), "1".to_string()],
+            vec!["c".to_string(), "2".to_string()],
+        ]);
+        app.world
+            .spawn((node, table.with_row_colors(Color::BLACK, Color::GRAY)))
+            .id()
+    }
+
+    #[test]
+    fn rows_are_built_with_alternating_colors() {
+        let mut app = test_app();
+        let entity = spawn_test_table(&mut app);
+        app.update();
+        // the header row and three rows
+        assert_eq!(app.world.get::<Children>(entity).unwrap().len(), 4);
+        let mut rows = app.world.query::<(&TableRow, &BackgroundColor)>();
+        let mut colors = rows
+            .iter(&app.world)
+            .map(|(row, color)| (row.index, color.0))
+            .collect::<Vec<_>>();
+        colors.sort_by_key(|(index, _)| *index);
+        assert_eq!(
+            colors,
+            vec![(0, Color::BLACK), (1, Color::GRAY), (2, Color::BLACK)]
+        );
+        app.world
+            .get_mut::<Table>(entity)
+            .unwrap()
+            .text_rows(vec![vec!["d".to_string(), "4".to_string()]]);
+        app.update();
+        assert_eq!(app.world.get::<Children>(entity).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn width_changes_resize_cells() {
+        let mut app = test_app();
+        let entity = spawn_test_table(&mut app);
+        app.update();
+        app.world.get_mut::<Table>(entity).unwrap().columns[1].width = Breadth::Px(80.);
+        app.update();
+        let mut cells = app.world.query::<(&TableCell, &Style)>();
+        let widths = cells
+            .iter(&app.world)
+            .filter(|(cell, _)| cell.column == 1)
+            .map(|(_, style)| style.size.width)
+            .collect::<Vec<_>>();
+        // the header cell and three rows
+        assert_eq!(widths, vec![Val::Px(80.); 4]);
+    }
+
+    #[test]
+    fn clicking_header_requests_sort() {
+        let mut app = test_app();
+        let entity = spawn_test_table(&mut app);
+        app.update();
+        let click = |app: &mut App| {
+            let mut headers = app.world.query::<(&mut Interaction, &TableHeader)>();
+            for (mut interaction, header) in headers.iter_mut(&mut app.world) {
+                *interaction = if header.column == 1 {
+                    Interaction::Clicked
+                } else {
+                    Interaction::None
+                };
+            }
+            app.update();
+            let mut headers = app.world.query::<&mut Interaction>();
+            for mut interaction in headers.iter_mut(&mut app.world) {
+                *interaction = Interaction::None;
+            }
+        };
+        click(&mut app);
+        click(&mut app);
+        let events = app.world.resource::<Events<TableSortRequested>>();
+        let ascending = events
+            .get_reader()
+            .iter(events)
+            .map(|event| (event.column, event.ascending))
+            .collect::<Vec<_>>();
+        assert_eq!(ascending, vec![(1, true), (1, false)]);
+        let header_text = app
+            .world
+            .query::<&Text>()
+            .iter(&app.world)
+            .any(|text| text.sections[0].value == "Score v");
+        assert!(header_text);
+        assert_eq!(
+            app.world.get::<Table>(entity).unwrap().sort,
+            Some(TableSort {
+                column: 1,
+                ascending: false
+            })
+        );
+    }
+}